@@ -1,7 +1,11 @@
+pub mod input;
+pub mod keymap;
 pub mod message;
 pub mod model;
 pub mod update;
 
+pub use input::{TextEdit, TextInput};
+pub use keymap::{load_keymap, Action, KeyMap};
 pub use message::{Command, FetchResult, Message};
-pub use model::App;
+pub use model::{App, Confirmation};
 pub use update::update;
@@ -0,0 +1,138 @@
+/// An edit to a text input, mapped from a key press in `key_to_message`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEdit {
+    Insert(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    DeleteWord,
+    ClearLine,
+    HistoryPrevious,
+    HistoryNext,
+}
+
+/// A single-line text buffer with a cursor and a history of submitted values
+#[derive(Debug, Clone, Default)]
+pub struct TextInput {
+    value: String,
+    cursor: usize, // In chars, not bytes
+    history: Vec<String>,
+    history_index: Option<usize>, // Entry being shown while browsing history
+}
+
+impl TextInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Cursor position, counted in chars from the start
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Empty the buffer, keeping the history
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+        self.history_index = None;
+    }
+
+    /// Record a submitted value so Up can recall it later
+    pub fn push_history(&mut self, entry: &str) {
+        if !entry.is_empty() && self.history.last().map(String::as_str) != Some(entry) {
+            self.history.push(entry.to_string());
+        }
+        self.history_index = None;
+    }
+
+    /// Insert text at the cursor, e.g. from a paste
+    pub fn insert_str(&mut self, text: &str) {
+        let at = self.byte_index(self.cursor);
+        self.value.insert_str(at, text);
+        self.cursor += text.chars().count();
+    }
+
+    pub fn apply(&mut self, edit: TextEdit) {
+        let len = self.value.chars().count();
+        match edit {
+            TextEdit::Insert(c) => {
+                let at = self.byte_index(self.cursor);
+                self.value.insert(at, c);
+                self.cursor += 1;
+            }
+            TextEdit::Backspace => {
+                if self.cursor > 0 {
+                    self.cursor -= 1;
+                    let at = self.byte_index(self.cursor);
+                    self.value.remove(at);
+                }
+            }
+            TextEdit::Delete => {
+                if self.cursor < len {
+                    let at = self.byte_index(self.cursor);
+                    self.value.remove(at);
+                }
+            }
+            TextEdit::Left => self.cursor = self.cursor.saturating_sub(1),
+            TextEdit::Right => self.cursor = (self.cursor + 1).min(len),
+            TextEdit::Home => self.cursor = 0,
+            TextEdit::End => self.cursor = len,
+            TextEdit::DeleteWord => {
+                // Like a shell's Ctrl-W: trailing spaces, then the word before them
+                let chars: Vec<char> = self.value.chars().collect();
+                let mut start = self.cursor;
+                while start > 0 && chars[start - 1].is_whitespace() {
+                    start -= 1;
+                }
+                while start > 0 && !chars[start - 1].is_whitespace() {
+                    start -= 1;
+                }
+                let (from, to) = (self.byte_index(start), self.byte_index(self.cursor));
+                self.value.replace_range(from..to, "");
+                self.cursor = start;
+            }
+            TextEdit::ClearLine => {
+                self.value.clear();
+                self.cursor = 0;
+            }
+            TextEdit::HistoryPrevious => {
+                let index = match self.history_index {
+                    Some(i) => i.saturating_sub(1),
+                    None if self.history.is_empty() => return,
+                    None => self.history.len() - 1,
+                };
+                self.show_history(Some(index));
+            }
+            TextEdit::HistoryNext => match self.history_index {
+                Some(i) if i + 1 < self.history.len() => self.show_history(Some(i + 1)),
+                Some(_) => self.show_history(None),
+                None => {}
+            },
+        }
+    }
+
+    /// Replace the buffer with a history entry, or an empty line past the newest one
+    fn show_history(&mut self, index: Option<usize>) {
+        self.history_index = index;
+        self.value = index.map(|i| self.history[i].clone()).unwrap_or_default();
+        self.cursor = self.value.chars().count();
+    }
+
+    fn byte_index(&self, char_index: usize) -> usize {
+        self.value
+            .char_indices()
+            .nth(char_index)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+}
@@ -0,0 +1,337 @@
+use anyhow::{bail, Context, Result};
+use crossterm::event::KeyCode;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::services::get_config_dir;
+
+/// Main view actions that `keys.toml` can rebind
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    Search,
+    TypeAhead,
+    NextItem,
+    PreviousItem,
+    Open,
+    OpenRepoPulls,
+    CopyHeadSha,
+    CopyGhCommand,
+    CopyTitle,
+    CopyChangelogEntry,
+    ToggleMark,
+    ToggleDone,
+    ToggleHideDone,
+    ClearDone,
+    CopyChecklist,
+    ToggleReadyToMerge,
+    CycleMilestone,
+    CycleSort,
+    ToggleHideApproved,
+    Merge,
+    Preview,
+    Checkout,
+    Refresh,
+    RefreshCi,
+    Help,
+    Labels,
+    SavedSearches,
+    RecentCheckouts,
+    Checks,
+    Workflows,
+    TabMyPrs,
+    TabReviewRequested,
+    TabLabels,
+    TabAll,
+    TabTeam,
+    TabRecent,
+    GrowColumn,
+    ShrinkColumn,
+    CycleResizeColumn,
+    GoToTop,
+    GoToBottom,
+}
+
+/// Every action with its `keys.toml` name, default keys and help text, in help order
+const ACTIONS: &[(Action, &str, &[&str], &str)] = &[
+    (Action::Search, "search", &["/"], "Fuzzy search"),
+    (Action::TypeAhead, "type_ahead", &["'"], "Jump to title"),
+    (Action::TabMyPrs, "tab_my_prs", &["1"], "My Pull Requests"),
+    (
+        Action::TabReviewRequested,
+        "tab_review_requested",
+        &["2"],
+        "Review Requested",
+    ),
+    (Action::TabLabels, "tab_labels", &["3"], "Labels"),
+    (Action::TabAll, "tab_all", &["4"], "All (merged)"),
+    (Action::TabTeam, "tab_team", &["5"], "Team review requests"),
+    (
+        Action::TabRecent,
+        "tab_recent",
+        &["6"],
+        "Recently merged/closed",
+    ),
+    (Action::Labels, "labels", &["l"], "Manage labels"),
+    (
+        Action::SavedSearches,
+        "saved_searches",
+        &["s"],
+        "Saved searches",
+    ),
+    (
+        Action::RecentCheckouts,
+        "recent_checkouts",
+        &["b"],
+        "Recent checkouts",
+    ),
+    (Action::Workflows, "workflows", &["w"], "View Workflows"),
+    (Action::Checks, "checks", &["x"], "Show status checks"),
+    (Action::Preview, "preview", &["Enter", "p"], "Preview PR"),
+    (Action::NextItem, "next_item", &["j", "Down"], "Move down"),
+    (
+        Action::PreviousItem,
+        "previous_item",
+        &["k", "Up"],
+        "Move up",
+    ),
+    (Action::GoToTop, "go_to_top", &["g"], "Go to top"),
+    (Action::GoToBottom, "go_to_bottom", &["G"], "Go to bottom"),
+    (Action::Open, "open", &["o"], "Open PR in browser"),
+    (
+        Action::OpenRepoPulls,
+        "open_repo_pulls",
+        &["H"],
+        "Open repo PRs in browser",
+    ),
+    (
+        Action::CopyHeadSha,
+        "copy_head_sha",
+        &["y"],
+        "Copy head commit SHA",
+    ),
+    (
+        Action::CopyGhCommand,
+        "copy_gh_command",
+        &["Y"],
+        "Copy gh command",
+    ),
+    (Action::CopyTitle, "copy_title", &["t"], "Copy title"),
+    (
+        Action::CopyChangelogEntry,
+        "copy_changelog_entry",
+        &["T"],
+        "Copy as changelog entry",
+    ),
+    (
+        Action::ToggleMark,
+        "toggle_mark",
+        &["v", "Space"],
+        "Mark PR",
+    ),
+    (
+        Action::CopyChecklist,
+        "copy_checklist",
+        &["C"],
+        "Copy marked PRs as checklist",
+    ),
+    (
+        Action::ToggleReadyToMerge,
+        "toggle_ready_to_merge",
+        &["m"],
+        "Toggle ready-to-merge filter",
+    ),
+    (
+        Action::CycleMilestone,
+        "cycle_milestone",
+        &["i"],
+        "Cycle milestone filter",
+    ),
+    (
+        Action::ToggleHideApproved,
+        "toggle_hide_approved",
+        &["A"],
+        "Hide PRs you've approved",
+    ),
+    (
+        Action::ToggleDone,
+        "toggle_done",
+        &["d"],
+        "Mark PR done (locally)",
+    ),
+    (
+        Action::ToggleHideDone,
+        "toggle_hide_done",
+        &["D"],
+        "Hide PRs marked done",
+    ),
+    (
+        Action::ClearDone,
+        "clear_done",
+        &["X"],
+        "Clear all done marks",
+    ),
+    (
+        Action::CycleSort,
+        "cycle_sort",
+        &["S"],
+        "Cycle sort (opened, updated, newest)",
+    ),
+    (Action::Merge, "merge", &["M"], "Merge PR (if enabled)"),
+    (
+        Action::GrowColumn,
+        "grow_column",
+        &[">"],
+        "Widen the column",
+    ),
+    (
+        Action::ShrinkColumn,
+        "shrink_column",
+        &["<"],
+        "Narrow the column",
+    ),
+    (
+        Action::CycleResizeColumn,
+        "cycle_resize_column",
+        &["|"],
+        "Pick the column to resize",
+    ),
+    (Action::Checkout, "checkout", &["c"], "Checkout branch"),
+    (Action::Refresh, "refresh", &["r"], "Refresh"),
+    (
+        Action::RefreshCi,
+        "refresh_ci",
+        &["R"],
+        "Refresh selected PR's CI",
+    ),
+    (Action::Help, "help", &["?"], "Toggle help"),
+    (Action::Quit, "quit", &["q"], "Quit"),
+];
+
+/// Main view key bindings: the defaults, with any actions `keys.toml` rebinds
+#[derive(Debug, Clone)]
+pub struct KeyMap {
+    bindings: HashMap<KeyCode, Action>,
+    /// Each action's keys as written, in help order
+    help: Vec<(String, &'static str)>,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        Self::parse("").expect("valid default keys")
+    }
+}
+
+impl KeyMap {
+    /// The action bound to a key, if any
+    pub fn action(&self, key: KeyCode) -> Option<Action> {
+        self.bindings.get(&key).copied()
+    }
+
+    /// Keys (joined with "/") and description of every bound action, for the help popup
+    pub fn help_rows(&self) -> &[(String, &'static str)] {
+        &self.help
+    }
+
+    /// Parse `keys.toml`: action names mapped to a key or a list of keys, e.g.
+    /// `next_item = ["n", "Down"]`. A listed action loses its default keys.
+    pub fn parse(content: &str) -> Result<Self> {
+        let table: toml::Table = toml::from_str(content)?;
+
+        let mut keys_by_action: Vec<(Action, &str, Vec<String>)> = ACTIONS
+            .iter()
+            .map(|(action, name, keys, _)| {
+                (*action, *name, keys.iter().map(|k| k.to_string()).collect())
+            })
+            .collect();
+        for (name, value) in &table {
+            let Some(entry) = keys_by_action.iter_mut().find(|(_, n, _)| n == name) else {
+                bail!("Unknown action \"{}\"", name);
+            };
+            entry.2 = match value {
+                toml::Value::String(key) => vec![key.clone()],
+                toml::Value::Array(keys) => keys
+                    .iter()
+                    .map(|key| match key {
+                        toml::Value::String(key) => Ok(key.clone()),
+                        _ => bail!("Keys for \"{}\" must be strings", name),
+                    })
+                    .collect::<Result<_>>()?,
+                _ => bail!(
+                    "Keys for \"{}\" must be a string or a list of strings",
+                    name
+                ),
+            };
+        }
+
+        let mut bindings = HashMap::new();
+        let mut bound_to: HashMap<KeyCode, &str> = HashMap::new();
+        for (action, name, keys) in &keys_by_action {
+            for key in keys {
+                let Some(code) = parse_key(key) else {
+                    bail!("Unknown key \"{}\" for \"{}\"", key, name);
+                };
+                if let Some(other) = bound_to.insert(code, name) {
+                    bail!(
+                        "\"{}\" is bound to both \"{}\" and \"{}\"",
+                        key,
+                        other,
+                        name
+                    );
+                }
+                bindings.insert(code, *action);
+            }
+        }
+        let help = keys_by_action
+            .iter()
+            .zip(ACTIONS)
+            .filter(|((_, _, keys), _)| !keys.is_empty())
+            .map(|((_, _, keys), (_, _, _, description))| (keys.join("/"), *description))
+            .collect();
+        Ok(Self { bindings, help })
+    }
+}
+
+pub fn get_keymap_path() -> Option<PathBuf> {
+    get_config_dir().map(|p| p.join("keys.toml"))
+}
+
+/// Load `keys.toml` from the config directory, or the defaults without one
+pub fn load_keymap() -> Result<KeyMap> {
+    let Some(path) = get_keymap_path().filter(|p| p.exists()) else {
+        return Ok(KeyMap::default());
+    };
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    KeyMap::parse(&content).with_context(|| format!("Invalid key bindings in {}", path.display()))
+}
+
+/// A single character ("j", "?") or a named key ("Enter", "Space", "PageDown", "F5")
+fn parse_key(key: &str) -> Option<KeyCode> {
+    let mut chars = key.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Some(KeyCode::Char(c));
+    }
+    let code = match key.to_ascii_lowercase().as_str() {
+        "space" => KeyCode::Char(' '),
+        "enter" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        name => {
+            let n: u8 = name.strip_prefix('f')?.parse().ok()?;
+            (1..=12).contains(&n).then_some(KeyCode::F(n))?
+        }
+    };
+    Some(code)
+}
@@ -1,15 +1,33 @@
-use crate::data::{ActionsData, JobLogs, PrFilter, PreviewData, PullRequest};
+use crate::data::{
+    ActionsData, CheckAnnotation, CheckContext, CiStatus, JobLogs, MergeMethod, PrFilter,
+    PreviewData, PullRequest, RunAction, RunTarget,
+};
+
+use super::input::TextEdit;
 
 /// Result from an async fetch operation
 pub enum FetchResult {
     Success(Vec<PullRequest>, PrFilter),
+    Warning(String), // Some PRs couldn't be read; the rest still load
     Error(String),
+    NetworkError(String, PrFilter), // Couldn't reach GitHub; cached data is still shown
+    RateLimited(String, PrFilter),  // GitHub's rate limit was hit; polling backs off
     ActionsSuccess(ActionsData),
     ActionsError(String),
     JobLogsSuccess(JobLogs),
     JobLogsError(String),
+    AnnotationsSuccess(u64, Vec<CheckAnnotation>), // check_run_id, annotations
+    AnnotationsError(u64, String),                 // check_run_id, error
     PreviewSuccess(PreviewData),
     PreviewError(String),
+    MergeSuccess(u64), // pr_number
+    MergeError(String),
+    CiStatusSuccess(String, String, u64, CiStatus, Option<String>), // owner, repo, pr_number, status, head_sha
+    CiStatusError(String),
+    ChecksSuccess(u64, Vec<CheckContext>), // pr_number, contexts
+    ChecksError(u64, String),              // pr_number, error
+    RunActionSuccess(RunAction),
+    RunActionError(String),
 }
 
 /// Command to be executed after update
@@ -20,8 +38,15 @@ pub enum Command {
     StartActionsFetch(String, String, u64, String), // owner, repo, pr_number, head_sha
     StartJobLogsFetch(String, String, u64, String), // owner, repo, job_id, job_name
     StartCircleCIJobLogsFetch(String, String, u64, String), // owner, repo, job_number, job_name
-    StartPreviewFetch(String, String, u64),         // owner, repo, pr_number
+    StartPreviewFetch(String, String, u64, usize),  // owner, repo, pr_number, max_comments
+    StartAnnotationsFetch(String, String, u64),     // owner, repo, check_run_id
+    StartMerge(String, String, u64, MergeMethod),   // owner, repo, pr_number, method
+    StartCiStatusFetch(String, String, u64),        // owner, repo, pr_number
+    StartChecksFetch(String, String, u64),          // owner, repo, pr_number
+    StartRunAction(String, String, RunTarget, RunAction), // owner, repo, target, action
     OpenInEditor(String, String),                   // content, filename
+    OpenFileAtLine(String, u32),                    // path, line
+    OpenInPager(String),                            // path
 }
 
 /// All possible messages/events in the application
@@ -32,40 +57,101 @@ pub enum Message {
     GoToTop,
     GoToBottom,
 
+    // Type-ahead jump
+    StartTypeAhead,
+    TypeAheadInput(char),
+    CancelTypeAhead,
+
     // Tab switching
     SwitchTab(PrFilter),
+    NextTeamTab,
+
+    // Table layout
+    GrowColumn,
+    ShrinkColumn,
+    CycleResizeColumn,
 
     // Actions
     OpenSelected,
+    OpenRepoPulls,
+    CopyHeadSha,
+    CopyGhCommand,
+    CopyTitle,
+    CopyChangelogEntry,
+    ToggleMarkPr,
+    ToggleDone,
+    PromptClearDone,
+    ConfirmClearDone,
+    CopyChecklist,
     PromptCheckout,
     ConfirmCheckout,
-    CancelCheckout,
+    MergePr,
+    CycleMergeMethod,
+    ConfirmMerge,
+    CancelMerge,
+    MergeResultReceived(FetchResult),
     Refresh,
+    RefreshSelectedCi,
+    CiStatusReceived(FetchResult),
 
     // Search
     EnterSearchMode,
     ExitSearchMode { clear: bool },
-    SearchInput(char),
-    SearchBackspace,
+    SearchEdit(TextEdit),
+    ToggleReadyToMerge,
+    CycleMilestoneFilter,
+    CyclePrSort,
+    ToggleHideApproved,
+    ToggleHideDone,
 
     // Popups
     ToggleHelp,
+    HelpScrollDown,
+    HelpScrollUp,
     DismissHelp,
     DismissError,
     DismissUrlPopup,
+    DismissGlyphWarning { ascii: bool },
 
     // Labels
     OpenLabelsPopup,
     CloseLabelsPopup,
     OpenAddLabelPopup,
     CloseAddLabelPopup,
-    LabelInput(char),
-    LabelBackspace,
+    LabelEdit(TextEdit),
     ToggleLabelScope,
     AddLabel,
     DeleteSelectedLabel,
+    FocusSelectedLabel,
     LabelsNext,
     LabelsPrevious,
+    LabelsEnterSearch,
+    LabelsExitSearch { clear: bool },
+    LabelsSearchEdit(TextEdit),
+
+    // Saved searches
+    OpenSavedSearchesPopup,
+    CloseSavedSearchesPopup,
+    SavedSearchesNext,
+    SavedSearchesPrevious,
+    ActivateSavedSearch,
+    DeleteSelectedSavedSearch,
+    OpenAddSavedSearchPopup,
+    CloseAddSavedSearchPopup,
+    SavedSearchEdit(TextEdit),
+    ToggleSavedSearchField,
+    AddSavedSearch,
+    OpenRecentCheckoutsPopup,
+    CloseRecentCheckoutsPopup,
+    RecentCheckoutsNext,
+    RecentCheckoutsPrevious,
+    CheckoutRecent(usize), // Index into recent_checkouts
+    OpenChecksPopup,
+    CloseChecksPopup,
+    ChecksNext,
+    ChecksPrevious,
+    OpenCheckUrl,
+    ChecksReceived(FetchResult),
 
     // Workflows view
     OpenWorkflowsView,
@@ -74,27 +160,46 @@ pub enum Message {
     RefreshActions,
     ActionsNextJob,
     ActionsPreviousJob,
+    ToggleJobSummary,
+    CycleProviderFilter,
     OpenActionsInBrowser,
+    CopyRunUrl, // Y - copy the selected job's (or run's) URL
+    PromptRerun,
+    ConfirmRerun { failed_only: bool },
+    CancelRerun,
+    PromptCancelRun,
+    ConfirmCancelRun,
+    RunActionResultReceived(FetchResult),
 
     // Job logs
     OpenJobLogs,
     CloseJobLogs,
     JobLogsReceived(FetchResult),
+    AnnotationsReceived(FetchResult),
     JobLogsScrollUp,
     JobLogsScrollDown,
     CopyJobLogs,
     JobLogsNextStep,
     JobLogsPrevStep,
     JobLogsToggleStep,
-    OpenStepInEditor,   // Enter - open step output in $EDITOR
-    CopyTestFailures,   // y - copy test failures from API
-    FullCopyStepOutput, // x - copy full step output
+    JobLogsJumpToFailure, // f - first failed step, or first error line
+    OpenStepInEditor,     // Enter - open step output in $EDITOR
+    CopyTestFailures,     // y - copy test failures from API
+    FullCopyStepOutput,   // x - copy full step output
+    SaveJobLogs,          // s - save logs to a file and open it in the pager
 
     // Annotations view (reviewdog, etc.)
     AnnotationNext,
     AnnotationPrevious,
+    AnnotationNextFile,     // } - first annotation of the next file
+    AnnotationPreviousFile, // { - first annotation of the previous file
     ToggleAnnotationSelection,
+    CycleAnnotationSort,   // s - original / severity / file
+    ToggleAnnotationsView, // a - annotations / raw logs
     CopyAnnotations,
+    CopyAnnotationsGrouped, // Y - copy grouped by file path
+    CopyAnnotationLocation, // p - copy the focused annotation's path:line
+    OpenAnnotationInEditor, // Enter - open the focused annotation's file at its line
 
     // Preview view
     OpenPreviewView,
@@ -106,11 +211,18 @@ pub enum Message {
     PreviewPreviousSection,
     PreviewGoToTop,
     PreviewGoToBottom,
+    PreviewLoadMore,
 
     // Async results
     FetchComplete(FetchResult),
 
+    // Confirmation prompt
+    Confirm,
+    DismissConfirmation,
+
     // System
     Tick,
+    FocusGained,
+    Paste(String),
     Quit,
 }
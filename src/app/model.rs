@@ -1,67 +1,128 @@
 use anyhow::Result;
+use ratatui::text::Line;
 use ratatui::widgets::TableState;
+use std::collections::{HashMap, HashSet};
 use std::sync::mpsc::{self, Receiver, Sender};
 use std::thread;
 use std::time::{Duration, Instant};
 
 use crate::data::{
-    ActionsData, CheckAnnotation, JobLogs, LabelFilter, PrFilter, PreviewData, PullRequest,
-    SPINNER_FRAMES,
+    ActionsData, AnnotationSort, CheckAnnotation, CheckContext, CiSummary, JobLogs, LabelFilter,
+    MergeMethod, PrFilter, PrSort, PrSource, PreviewData, ProviderFilter, PullRequest,
+    RecentCheckout, RunAction, RunTarget, SavedSearch, TableColumn,
 };
 use crate::services::{
-    fetch_actions_for_pr, fetch_circleci_job_logs, fetch_job_logs, fetch_pr_preview,
-    fetch_prs_graphql, load_cache, load_label_filters, save_cache,
+    cancel_circleci_workflow, cancel_workflow_run, fetch_actions_for_pr, fetch_check_contexts,
+    fetch_circleci_job_logs, fetch_job_annotations, fetch_job_logs, fetch_pr_ci_status,
+    fetch_pr_preview, fetch_prs_graphql, is_network_error, is_rate_limited, load_cache_for_display,
+    load_config, load_done_prs, load_global_label_filters, load_label_filters, load_saved_searches,
+    load_state, merge_pr, rerun_circleci_workflow, rerun_failed_jobs, rerun_workflow, save_cache,
+    ColumnWidths, Config, MatchPositions, PrefetchTab,
 };
-use crate::utils::get_current_repo;
+use crate::utils::{get_current_repo, jitter};
+use crate::view::Theme;
 
-use super::message::FetchResult;
+use super::input::TextInput;
+use super::keymap::{load_keymap, KeyMap};
+use super::message::{FetchResult, Message};
 
 pub struct App {
+    // User configuration
+    pub config: Config,
+    pub theme: Theme,
+    pub keymap: KeyMap,
+
     // Data state
     pub my_prs: Vec<PullRequest>,
     pub review_prs: Vec<PullRequest>,
     pub labels_prs: Vec<PullRequest>,
+    pub all_prs: Vec<PullRequest>, // Deduplicated merge of the three lists above
+    pub all_pr_sources: Vec<PrSource>, // Source tab for each entry in all_prs
+    pub search_prs: Vec<PullRequest>, // Results of the last server-side (`:`) search
+    pub team_prs: Vec<PullRequest>, // Review requests for the team tab being viewed
+    pub recent_prs: Vec<PullRequest>, // Recently merged or closed PRs
     pub configured_labels: Vec<LabelFilter>,
+    pub saved_searches: Vec<SavedSearch>,
 
     // Filter/View state
     pub pr_filter: PrFilter,
     pub table_state: TableState,
     pub filtered_indices: Vec<usize>,
+    pub match_positions: HashMap<usize, MatchPositions>, // By PR index; only PRs the search matched
+    pub column_widths: ColumnWidths, // Config widths, with those adjusted with < / > swapped in
+    pub resize_column: TableColumn,  // Column < / > resize, picked with |
 
     // Search state
     pub search_mode: bool,
-    pub search_query: String,
+    pub search_query: TextInput,
+    pub ready_to_merge_only: bool, // Quick filter: green CI, approved or no review required, mergeable
+    pub hide_approved: bool, // Quick filter: drop PRs whose latest review from me is an approval
+    pub hidden_approved: usize, // How many PRs that filter hid in the current list
+    pub done_prs: HashSet<u64>, // PRs in this repo marked done locally, for triage
+    pub hide_done: bool,     // Quick filter: drop PRs marked done
+    pub hidden_done: usize,  // How many PRs that filter hid in the current list
+    pub milestone_filter: Option<String>, // Quick filter: only PRs in this milestone
+    pub pr_sort: PrSort,
+    pub ci_summary: CiSummary, // CI outcomes across the active tab, for the tab bar
+    pub marked_prs: HashSet<(String, String, u64)>, // owner, repo, number of PRs marked for bulk copy
+
+    // Type-ahead jump state (Some while active; expires after a short pause)
+    pub type_ahead: Option<String>,
+    pub type_ahead_time: Instant,
 
     // Loading state
     pub loading_my_prs: bool,
     pub loading_review_prs: bool,
+    pub startup_progress: bool, // Prefetched tabs' progress replaces the separator until they load
+    pub my_prs_fetched: bool, // Fetched this session; tabs left out of prefetch_tabs wait for a visit
+    pub review_prs_fetched: bool,
     pub loading_labels_prs: bool,
+    pub labels_fetched: bool, // Labels tab fetched this session, so an empty list means no PRs
+    pub loading_search_prs: bool,
+    pub loading_team_prs: bool,
+    pub loading_recent_prs: bool,
+    pub recent_fetched: bool, // Recent isn't prefetched; it loads on its first visit
 
     // Popup state
     pub show_help_popup: bool,
-    pub show_checkout_popup: bool,
+    pub help_scroll: u16,     // First help line shown when it doesn't all fit
+    pub terminal_height: u16, // Rows in the last frame drawn
+    pub confirmation: Option<Confirmation>, // Yes/no prompt in front of a destructive action
+    pub show_merge_popup: bool,
     pub show_error_popup: bool,
     pub show_labels_popup: bool,
     pub show_add_label_popup: bool,
+    pub show_saved_searches_popup: bool,
+    pub show_add_saved_search_popup: bool,
+    pub show_recent_checkouts_popup: bool,
+    pub show_checks_popup: bool,
 
     // Workflows view state
     pub show_workflows_view: bool,
     pub actions_data: Option<ActionsData>,
     pub actions_loading: bool,
     pub selected_job_index: usize,
+    pub job_summary_expanded: bool, // Whether the selected job's check output is fully expanded
     pub actions_poll_enabled: bool,
     pub last_actions_poll: Instant,
     pub actions_pending_pr_number: Option<u64>, // PR we're waiting to get head_sha for
     pub workflows_pr_info: Option<(String, u64)>, // (title, number) for display
+    pub workflows_provider_filter: ProviderFilter,
+    pub pending_rerun: Option<(String, RunTarget)>, // Run name and target awaiting confirmation
+    pub pending_cancel: Option<(String, RunTarget)>, // Same, for cancelling a running workflow
 
     // Main page auto-refresh state
     pub last_main_refresh: Instant,
+    pub main_refresh_jitter: Duration, // Random delay added to the next auto-refresh
+    pub actions_poll_jitter: Duration,
+    pub poll_backoff: u32, // Interval multiplier, doubled while GitHub reports a rate limit
 
     // Job logs state
     pub show_job_logs: bool,
     pub job_logs: Option<JobLogs>,
     pub job_logs_loading: bool,
-    pub job_logs_scroll: u16,
+    pub job_logs_scroll: u16,                      // First visible log line
+    pub job_logs_line_count: usize, // Counted once on load so scrolling doesn't rescan the log
     pub job_logs_selected_step: usize, // Currently selected top-level step/container
     pub job_logs_expanded_steps: Vec<bool>, // Which top-level steps are expanded
     pub job_logs_selected_sub_step: Option<usize>, // Currently selected sub-step within a container (None = container itself selected)
@@ -70,8 +131,12 @@ pub struct App {
     // Annotations view state (for reviewdog, etc.)
     pub annotations_view: bool, // true if viewing annotations, false for raw logs
     pub annotations: Vec<CheckAnnotation>, // current annotations being displayed
+    pub annotations_total: usize, // Annotations GitHub reports for the job, which may be more than fetched
     pub selected_annotation_index: usize,
     pub selected_annotations: Vec<usize>, // indices of selected annotations for copying
+    pub annotations_sort: AnnotationSort,
+    pub annotations_unsorted: Vec<CheckAnnotation>, // fetch order, restored by the Original sort
+    pub job_annotations_cache: HashMap<u64, Vec<CheckAnnotation>>, // check_run_id -> annotations, for this session
 
     // Preview view state
     pub show_preview_view: bool,
@@ -82,6 +147,7 @@ pub struct App {
     pub preview_comment_positions: Vec<u16>, // line positions of each comment start
     pub preview_total_lines: u16,
     pub preview_pr_info: Option<(String, u64)>, // (title, number) for display
+    pub preview_comment_limit: usize,           // Comments requested; grows with "load more"
 
     // Clipboard feedback
     pub clipboard_feedback: Option<String>,
@@ -90,16 +156,48 @@ pub struct App {
     // URL popup (for container environments where we can't open browser)
     pub show_url_popup: Option<String>,
 
+    // One-time warning that the terminal may not render Nerd Font glyphs
+    pub show_glyph_warning_popup: bool,
+
     // Error state
     pub error: Option<String>,
+    // The failed PR fetch `error` shows, which the next fetch clears
+    pub fetch_error: Option<String>,
+    pub offline: bool, // PR fetches keep failing to reach GitHub; showing cached data
+    pub network_failures: u32, // PR fetches in a row that couldn't reach GitHub
 
     // Checkout state
     pub pending_checkout_branch: Option<String>,
+    pub pending_checkout_pr: Option<u64>, // PR the branch belongs to, for the recent checkouts list
+    pub pending_checkout_command: Option<String>, // Set in dry-run mode: what checkout would run
+
+    // Merge state
+    pub pending_merge_pr: Option<(String, String, u64)>, // owner, repo, number awaiting the merge popup
+    pub merge_method: MergeMethod,
+    pub merging: bool,
 
     // Label input state
-    pub label_input: String,
+    pub label_input: TextInput,
     pub label_scope_global: bool,
     pub labels_list_state: TableState,
+    pub labels_search_mode: bool,
+    pub labels_search_query: TextInput,
+    pub labels_filtered_indices: Vec<usize>, // Indices into configured_labels, in display order
+    pub focused_label: Option<String>,       // When set, the Labels tab shows only this label's PRs
+
+    // Saved search input state
+    pub saved_searches_list_state: TableState,
+    pub saved_search_name_input: TextInput,
+    pub saved_search_query_input: TextInput,
+    pub saved_search_editing_query: bool, // Which add-popup field has focus
+    pub recent_checkouts: Vec<RecentCheckout>, // Loaded when the popup opens
+    pub recent_checkouts_list_state: TableState,
+
+    // Checks popup state
+    pub checks: Vec<CheckContext>,
+    pub checks_loading: bool,
+    pub checks_pr: Option<u64>, // PR the popup shows, so late results for another are dropped
+    pub checks_list_state: TableState,
 
     // Repository info
     pub repo_owner: Option<String>,
@@ -118,30 +216,72 @@ pub struct App {
     pub job_logs_rx: Receiver<FetchResult>,
 
     // Preview async communication
-    pub preview_tx: Sender<(String, String, u64)>, // owner, repo, pr_number
+    pub preview_tx: Sender<(String, String, u64, usize)>, // owner, repo, pr_number, max_comments
     pub preview_rx: Receiver<FetchResult>,
 
+    // Check run annotations async communication
+    pub annotations_tx: Sender<(String, String, u64)>, // owner, repo, check_run_id
+    pub annotations_rx: Receiver<FetchResult>,
+
     // CircleCI job logs async communication
     pub circleci_logs_tx: Sender<(String, String, u64, String)>, // owner, repo, job_number, job_name
     pub circleci_logs_rx: Receiver<FetchResult>,
 
+    // Merge async communication
+    pub merge_tx: Sender<(String, String, u64, MergeMethod)>, // owner, repo, pr_number, method
+    pub merge_rx: Receiver<FetchResult>,
+
+    // Single-PR CI status async communication
+    pub ci_status_tx: Sender<(String, String, u64)>, // owner, repo, pr_number
+    pub ci_status_rx: Receiver<FetchResult>,
+    pub refreshing_ci: Option<u64>, // PR whose CI status is being re-queried
+    pub checks_tx: Sender<(String, String, u64)>, // owner, repo, pr_number
+    pub checks_rx: Receiver<FetchResult>,
+
+    // Workflow run actions async communication
+    pub run_action_tx: Sender<(String, String, RunTarget, RunAction)>, // owner, repo, target, action
+    pub run_action_rx: Receiver<FetchResult>,
+
+    // Resumed from suspend (SIGCONT); only fed when refresh_on_focus is set
+    pub resume_rx: Receiver<()>,
+
     // Spinner state
     pub spinner_idx: usize,
     pub last_spinner_update: Instant,
 }
 
+/// A yes/no prompt guarding a destructive action. Confirming dispatches `on_confirm`.
+pub struct Confirmation {
+    pub title: String,
+    pub lines: Vec<Line<'static>>,
+    pub on_confirm: Message,
+    pub danger: bool, // Drawn in red, for actions that can't be undone
+}
+
 impl App {
     pub fn new() -> Result<Self> {
+        // Load user config, surfacing parse errors and missing commands at startup
+        let mut error = None;
+        let config = load_config().unwrap_or_else(|e| {
+            error = Some(format!("{:#}", e));
+            Config::default()
+        });
+
         let (fetch_tx, fetch_rx) = mpsc::channel::<PrFilter>();
         let (result_tx, result_rx) = mpsc::channel::<FetchResult>();
 
         // Spawn background thread for fetching PRs
+        let fetch_config = config.clone();
         thread::spawn(move || {
             let rt = tokio::runtime::Runtime::new().unwrap();
             while let Ok(filter) = fetch_rx.recv() {
-                let result = rt.block_on(fetch_prs_graphql(filter.clone()));
+                let result = rt.block_on(fetch_prs_graphql(filter.clone(), &fetch_config));
                 let msg = match result {
-                    Ok(prs) => {
+                    Ok((prs, warnings)) => {
+                        if !warnings.is_empty() {
+                            let warning = format!("GitHub API: {}", warnings.join("; "));
+                            let _ = result_tx.send(FetchResult::Warning(warning));
+                        }
                         // Get owner/repo from the first PR or current repo
                         if let Some((owner, repo)) = prs
                             .first()
@@ -154,6 +294,12 @@ impl App {
                         }
                         FetchResult::Success(prs, filter)
                     }
+                    Err(e) if is_network_error(&e) => {
+                        FetchResult::NetworkError(format!("{}", e), filter)
+                    }
+                    Err(e) if is_rate_limited(&e) => {
+                        FetchResult::RateLimited(format!("{}", e), filter)
+                    }
                     Err(e) => FetchResult::Error(format!("{}", e)),
                 };
                 if result_tx.send(msg).is_err() {
@@ -201,14 +347,14 @@ impl App {
         });
 
         // Channel for preview fetching
-        let (preview_tx, preview_rx_internal) = mpsc::channel::<(String, String, u64)>();
+        let (preview_tx, preview_rx_internal) = mpsc::channel::<(String, String, u64, usize)>();
         let (preview_result_tx, preview_rx) = mpsc::channel::<FetchResult>();
 
         // Spawn background thread for fetching PR preview/comments
         thread::spawn(move || {
             let rt = tokio::runtime::Runtime::new().unwrap();
-            while let Ok((owner, repo, pr_number)) = preview_rx_internal.recv() {
-                let result = rt.block_on(fetch_pr_preview(&owner, &repo, pr_number));
+            while let Ok((owner, repo, pr_number, max_comments)) = preview_rx_internal.recv() {
+                let result = rt.block_on(fetch_pr_preview(&owner, &repo, pr_number, max_comments));
                 let msg = match result {
                     Ok(data) => FetchResult::PreviewSuccess(data),
                     Err(e) => FetchResult::PreviewError(format!("{}", e)),
@@ -219,6 +365,25 @@ impl App {
             }
         });
 
+        // Channel for check run annotations fetching
+        let (annotations_tx, annotations_rx_internal) = mpsc::channel::<(String, String, u64)>();
+        let (annotations_result_tx, annotations_rx) = mpsc::channel::<FetchResult>();
+
+        // Spawn background thread for fetching annotations
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            while let Ok((owner, repo, check_run_id)) = annotations_rx_internal.recv() {
+                let result = rt.block_on(fetch_job_annotations(&owner, &repo, check_run_id));
+                let msg = match result {
+                    Ok(annotations) => FetchResult::AnnotationsSuccess(check_run_id, annotations),
+                    Err(e) => FetchResult::AnnotationsError(check_run_id, format!("{}", e)),
+                };
+                if annotations_result_tx.send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+
         // Channel for CircleCI job logs fetching
         let (circleci_logs_tx, circleci_logs_rx_internal) =
             mpsc::channel::<(String, String, u64, String)>();
@@ -241,29 +406,148 @@ impl App {
             }
         });
 
+        // Channel for merging PRs
+        let (merge_tx, merge_rx_internal) = mpsc::channel::<(String, String, u64, MergeMethod)>();
+        let (merge_result_tx, merge_rx) = mpsc::channel::<FetchResult>();
+
+        // Spawn background thread for merging
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            while let Ok((owner, repo, pr_number, method)) = merge_rx_internal.recv() {
+                let result = rt.block_on(merge_pr(&owner, &repo, pr_number, method));
+                let msg = match result {
+                    Ok(()) => FetchResult::MergeSuccess(pr_number),
+                    Err(e) => FetchResult::MergeError(format!("{}", e)),
+                };
+                if merge_result_tx.send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Channel for re-querying one PR's CI status
+        let (ci_status_tx, ci_status_rx_internal) = mpsc::channel::<(String, String, u64)>();
+        let (ci_status_result_tx, ci_status_rx) = mpsc::channel::<FetchResult>();
+
+        // Spawn background thread for single-PR CI status fetching
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            while let Ok((owner, repo, pr_number)) = ci_status_rx_internal.recv() {
+                let result = rt.block_on(fetch_pr_ci_status(&owner, &repo, pr_number));
+                let msg = match result {
+                    Ok((status, head_sha)) => {
+                        FetchResult::CiStatusSuccess(owner, repo, pr_number, status, head_sha)
+                    }
+                    Err(e) => FetchResult::CiStatusError(format!("{}", e)),
+                };
+                if ci_status_result_tx.send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Channel for the checks popup
+        let (checks_tx, checks_rx_internal) = mpsc::channel::<(String, String, u64)>();
+        let (checks_result_tx, checks_rx) = mpsc::channel::<FetchResult>();
+
+        // Spawn background thread for status check context fetching
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            while let Ok((owner, repo, pr_number)) = checks_rx_internal.recv() {
+                let result = rt.block_on(fetch_check_contexts(&owner, &repo, pr_number));
+                let msg = match result {
+                    Ok(contexts) => FetchResult::ChecksSuccess(pr_number, contexts),
+                    Err(e) => FetchResult::ChecksError(pr_number, format!("{}", e)),
+                };
+                if checks_result_tx.send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+
+        // Channel for workflow run actions (re-run, cancel)
+        let (run_action_tx, run_action_rx_internal) =
+            mpsc::channel::<(String, String, RunTarget, RunAction)>();
+        let (run_action_result_tx, run_action_rx) = mpsc::channel::<FetchResult>();
+
+        // Spawn background thread for workflow run actions
+        thread::spawn(move || {
+            let rt = tokio::runtime::Runtime::new().unwrap();
+            while let Ok((owner, repo, target, action)) = run_action_rx_internal.recv() {
+                let result = rt.block_on(async {
+                    match (target, action) {
+                        (RunTarget::Actions { run_id }, RunAction::Rerun) => {
+                            rerun_workflow(&owner, &repo, run_id).await
+                        }
+                        (RunTarget::Actions { run_id }, RunAction::RerunFailed) => {
+                            rerun_failed_jobs(&owner, &repo, run_id).await
+                        }
+                        (RunTarget::Actions { run_id }, RunAction::Cancel) => {
+                            cancel_workflow_run(&owner, &repo, run_id).await
+                        }
+                        (RunTarget::CircleCi { job_number }, RunAction::Cancel) => {
+                            cancel_circleci_workflow(&owner, &repo, job_number).await
+                        }
+                        (RunTarget::CircleCi { job_number }, rerun) => {
+                            let from_failed = rerun == RunAction::RerunFailed;
+                            rerun_circleci_workflow(&owner, &repo, job_number, from_failed).await
+                        }
+                    }
+                });
+                let msg = match result {
+                    Ok(()) => FetchResult::RunActionSuccess(action),
+                    Err(e) => FetchResult::RunActionError(format!("{}", e)),
+                };
+                if run_action_result_tx.send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+
         // Get repo info for loading cache
         let (owner, repo_name) = get_current_repo().unzip();
 
         // Load caches
         let my_prs = match (&owner, &repo_name) {
-            (Some(o), Some(r)) => load_cache(o, r, PrFilter::MyPrs).unwrap_or_default(),
+            (Some(o), Some(r)) => load_cache_for_display(o, r, PrFilter::MyPrs).unwrap_or_default(),
             _ => Vec::new(),
         };
         let review_prs = match (&owner, &repo_name) {
-            (Some(o), Some(r)) => load_cache(o, r, PrFilter::ReviewRequested).unwrap_or_default(),
+            (Some(o), Some(r)) => {
+                load_cache_for_display(o, r, PrFilter::ReviewRequested).unwrap_or_default()
+            }
             _ => Vec::new(),
         };
         let labels_prs = match (&owner, &repo_name) {
-            (Some(o), Some(r)) => load_cache(o, r, PrFilter::Labels(vec![])).unwrap_or_default(),
+            (Some(o), Some(r)) => {
+                load_cache_for_display(o, r, PrFilter::Labels(vec![])).unwrap_or_default()
+            }
+            _ => Vec::new(),
+        };
+        let recent_prs = match (&owner, &repo_name) {
+            (Some(o), Some(r)) => {
+                load_cache_for_display(o, r, PrFilter::Recent).unwrap_or_default()
+            }
             _ => Vec::new(),
         };
 
         // Load configured labels
         let configured_labels = match (&owner, &repo_name) {
             (Some(o), Some(r)) => load_label_filters(o, r).unwrap_or_default(),
+            _ => load_global_label_filters().unwrap_or_default(),
+        };
+
+        // Load saved searches
+        let saved_searches = match (&owner, &repo_name) {
+            (Some(o), Some(r)) => load_saved_searches(o, r).unwrap_or_default(),
             _ => Vec::new(),
         };
 
+        let done_prs = match (&owner, &repo_name) {
+            (Some(o), Some(r)) => load_done_prs(o, r).unwrap_or_default(),
+            _ => HashSet::new(),
+        };
+
         let mut table_state = TableState::default();
         if !my_prs.is_empty() {
             table_state.select(Some(0));
@@ -271,45 +555,156 @@ impl App {
 
         let filtered_indices: Vec<usize> = (0..my_prs.len()).collect();
 
-        Ok(Self {
+        let config_warning = config
+            .validate()
+            .into_iter()
+            .chain(Theme::validate(&config.theme))
+            .next();
+        // Bad key bindings fall back to the defaults rather than half-applying
+        let keymap = load_keymap().unwrap_or_else(|e| {
+            let message = format!("{:#}", e);
+            error = Some(match error.take() {
+                Some(config_error) => format!("{}\n\n{}", config_error, message),
+                None => message,
+            });
+            KeyMap::default()
+        });
+        let hide_approved = config.hide_approved;
+        if config.ascii {
+            crate::icons::set_ascii(true);
+        }
+        let state = load_state();
+        let show_glyph_warning_popup = !crate::icons::is_ascii()
+            && !config.nerd_font
+            && !crate::icons::glyphs_likely_supported()
+            && !state.glyph_warning_acknowledged;
+
+        let mut column_widths = config.columns.clone();
+        state.columns.apply(&mut column_widths);
+        let offline_start = config.offline_start;
+        // main.rs fetches these tabs right after startup
+        let prefetch = |tab| !offline_start && config.prefetch_tabs.contains(&tab);
+        let (prefetch_my_prs, prefetch_review_prs) = (
+            prefetch(PrefetchTab::MyPrs),
+            prefetch(PrefetchTab::ReviewRequested),
+        );
+        let startup_progress = config.startup_progress && (prefetch_my_prs || prefetch_review_prs);
+        let theme = Theme::from_config(&config.theme, &config.spinner);
+        let merge_method = config.merge.method;
+        let pr_sort = config.sort.for_filter(&PrFilter::MyPrs);
+
+        // Listen for SIGCONT so a resumed session can refresh like a refocused one
+        let (resume_tx, resume_rx) = mpsc::channel::<()>();
+        #[cfg(unix)]
+        if config.refresh_on_focus {
+            thread::spawn(move || {
+                use tokio::signal::unix::{signal, SignalKind};
+                let rt = tokio::runtime::Runtime::new().unwrap();
+                rt.block_on(async {
+                    let Ok(mut sigcont) = signal(SignalKind::from_raw(libc::SIGCONT)) else {
+                        return;
+                    };
+                    while sigcont.recv().await.is_some() {
+                        if resume_tx.send(()).is_err() {
+                            break;
+                        }
+                    }
+                });
+            });
+        }
+        #[cfg(not(unix))]
+        drop(resume_tx);
+
+        let mut app = Self {
+            config,
+            theme,
+            keymap,
             my_prs,
             review_prs,
             labels_prs,
+            all_prs: Vec::new(),
+            all_pr_sources: Vec::new(),
+            search_prs: Vec::new(),
+            team_prs: Vec::new(),
+            recent_prs,
             configured_labels,
+            saved_searches,
             pr_filter: PrFilter::MyPrs,
             table_state,
             filtered_indices,
+            match_positions: HashMap::new(),
+            ci_summary: CiSummary::default(),
+            column_widths,
+            resize_column: TableColumn::default(),
             search_mode: false,
-            search_query: String::new(),
-            loading_my_prs: true,
-            loading_review_prs: true,
+            search_query: TextInput::new(),
+            ready_to_merge_only: false,
+            hide_approved,
+            hidden_approved: 0,
+            done_prs,
+            hide_done: false,
+            hidden_done: 0,
+            milestone_filter: None,
+            pr_sort,
+            marked_prs: HashSet::new(),
+            type_ahead: None,
+            type_ahead_time: Instant::now(),
+            loading_my_prs: prefetch_my_prs,
+            loading_review_prs: prefetch_review_prs,
+            startup_progress,
             loading_labels_prs: false,
+            labels_fetched: false,
+            my_prs_fetched: false,
+            review_prs_fetched: false,
+            loading_search_prs: false,
+            loading_team_prs: false,
+            loading_recent_prs: false,
+            recent_fetched: false,
             show_help_popup: false,
-            show_checkout_popup: false,
-            show_error_popup: false,
+            help_scroll: 0,
+            terminal_height: 0,
+            confirmation: None,
+            show_merge_popup: false,
+            show_error_popup: error.is_some(),
             show_labels_popup: false,
             show_add_label_popup: false,
+            show_saved_searches_popup: false,
+            show_add_saved_search_popup: false,
+            show_recent_checkouts_popup: false,
+            show_checks_popup: false,
             show_workflows_view: false,
             actions_data: None,
             actions_loading: false,
             selected_job_index: 0,
+            job_summary_expanded: false,
             actions_poll_enabled: false,
             last_actions_poll: Instant::now(),
             actions_pending_pr_number: None,
             workflows_pr_info: None,
+            workflows_provider_filter: ProviderFilter::default(),
+            pending_rerun: None,
+            pending_cancel: None,
             last_main_refresh: Instant::now(),
+            main_refresh_jitter: Duration::ZERO,
+            actions_poll_jitter: Duration::ZERO,
+            poll_backoff: 1,
             show_job_logs: false,
             job_logs: None,
             job_logs_loading: false,
             job_logs_scroll: 0,
+            job_logs_line_count: 0,
             job_logs_selected_step: 0,
             job_logs_expanded_steps: Vec::new(),
             job_logs_selected_sub_step: None,
             job_logs_expanded_sub_steps: Vec::new(),
             annotations_view: false,
+            annotations_total: 0,
             annotations: Vec::new(),
             selected_annotation_index: 0,
             selected_annotations: Vec::new(),
+            annotations_sort: AnnotationSort::default(),
+            annotations_unsorted: Vec::new(),
+            job_annotations_cache: HashMap::new(),
             show_preview_view: false,
             preview_data: None,
             preview_loading: false,
@@ -318,14 +713,38 @@ impl App {
             preview_comment_positions: Vec::new(),
             preview_total_lines: 0,
             preview_pr_info: None,
-            clipboard_feedback: None,
+            preview_comment_limit: 0,
+            clipboard_feedback: config_warning,
             clipboard_feedback_time: Instant::now(),
             show_url_popup: None,
-            error: None,
+            show_glyph_warning_popup,
+            error,
+            fetch_error: None,
+            offline: false,
+            network_failures: 0,
             pending_checkout_branch: None,
-            label_input: String::new(),
+            pending_checkout_pr: None,
+            pending_checkout_command: None,
+            pending_merge_pr: None,
+            merge_method,
+            merging: false,
+            label_input: TextInput::new(),
             label_scope_global: false,
             labels_list_state: TableState::default(),
+            labels_search_mode: false,
+            labels_search_query: TextInput::new(),
+            focused_label: None,
+            labels_filtered_indices: Vec::new(),
+            saved_searches_list_state: TableState::default(),
+            saved_search_name_input: TextInput::new(),
+            saved_search_query_input: TextInput::new(),
+            saved_search_editing_query: false,
+            recent_checkouts: Vec::new(),
+            recent_checkouts_list_state: TableState::default(),
+            checks: Vec::new(),
+            checks_loading: false,
+            checks_pr: None,
+            checks_list_state: TableState::default(),
             repo_owner: owner,
             repo_name,
             fetch_tx,
@@ -336,11 +755,26 @@ impl App {
             job_logs_rx,
             preview_tx,
             preview_rx,
+            annotations_tx,
+            annotations_rx,
             circleci_logs_tx,
             circleci_logs_rx,
+            merge_tx,
+            merge_rx,
+            ci_status_tx,
+            ci_status_rx,
+            refreshing_ci: None,
+            checks_tx,
+            checks_rx,
+            run_action_tx,
+            run_action_rx,
+            resume_rx,
             spinner_idx: 0,
             last_spinner_update: Instant::now(),
-        })
+        };
+        app.rebuild_all_prs();
+        app.ci_summary = CiSummary::tally(app.my_prs.iter().map(|pr| pr.ci_status));
+        Ok(app)
     }
 
     // Getters
@@ -350,6 +784,18 @@ impl App {
             PrFilter::MyPrs => &self.my_prs,
             PrFilter::ReviewRequested => &self.review_prs,
             PrFilter::Labels(_) => &self.labels_prs,
+            PrFilter::All => &self.all_prs,
+            PrFilter::Search(_) => &self.search_prs,
+            PrFilter::Team(_) => &self.team_prs,
+            PrFilter::Recent => &self.recent_prs,
+        }
+    }
+
+    /// Whether a tab fetched at startup is still loading
+    pub fn is_prefetch_loading(&self, tab: PrefetchTab) -> bool {
+        match tab {
+            PrefetchTab::MyPrs => self.loading_my_prs,
+            PrefetchTab::ReviewRequested => self.loading_review_prs,
         }
     }
 
@@ -358,16 +804,75 @@ impl App {
             PrFilter::MyPrs => self.loading_my_prs,
             PrFilter::ReviewRequested => self.loading_review_prs,
             PrFilter::Labels(_) => self.loading_labels_prs,
+            PrFilter::All => {
+                self.loading_my_prs || self.loading_review_prs || self.loading_labels_prs
+            }
+            PrFilter::Search(_) => self.loading_search_prs,
+            PrFilter::Team(_) => self.loading_team_prs,
+            PrFilter::Recent => self.loading_recent_prs,
+        }
+    }
+
+    /// Source tab of the PR at `idx` in `all_prs`
+    pub fn pr_source(&self, idx: usize) -> Option<PrSource> {
+        self.all_pr_sources.get(idx).copied()
+    }
+
+    /// Number of distinct PRs across My PRs, Review Requested and Labels. A PR
+    /// showing up in several tabs is only counted once, as in the All tab.
+    pub fn unique_pr_count(&self) -> usize {
+        self.all_prs.len()
+    }
+
+    /// Rebuild the All list from the three fetched lists. Call after any of them change.
+    /// A PR that appears in several tabs keeps the first source (mine, review, label).
+    pub fn rebuild_all_prs(&mut self) {
+        let mut seen = HashSet::new();
+        self.all_prs.clear();
+        self.all_pr_sources.clear();
+
+        let sources = [
+            (PrSource::Mine, &self.my_prs),
+            (PrSource::Review, &self.review_prs),
+            (PrSource::Label, &self.labels_prs),
+        ];
+        for (source, prs) in sources {
+            for pr in prs {
+                if seen.insert((pr.repo_owner.clone(), pr.repo_name.clone(), pr.number)) {
+                    self.all_prs.push(pr.clone());
+                    self.all_pr_sources.push(source);
+                }
+            }
         }
     }
 
     pub fn get_active_labels(&self) -> Vec<String> {
+        // A focused label narrows the tab to just that label while it's still configured
+        if let Some(label) = self.active_focused_label() {
+            return vec![label.to_string()];
+        }
         self.configured_labels
             .iter()
             .map(|lf| lf.label_name.clone())
             .collect()
     }
 
+    /// The focused label, if it hasn't been deleted since it was focused
+    pub fn active_focused_label(&self) -> Option<&str> {
+        self.focused_label.as_deref().filter(|focused| {
+            self.configured_labels
+                .iter()
+                .any(|lf| lf.label_name == *focused)
+        })
+    }
+
+    /// The configured label shown at `idx` in the (possibly filtered) labels popup
+    pub fn visible_label(&self, idx: usize) -> Option<&LabelFilter> {
+        self.labels_filtered_indices
+            .get(idx)
+            .and_then(|&i| self.configured_labels.get(i))
+    }
+
     pub fn visible_prs(&self) -> Vec<&PullRequest> {
         let prs = self.current_prs();
         self.filtered_indices
@@ -376,6 +881,15 @@ impl App {
             .collect()
     }
 
+    pub fn is_done(&self, pr: &PullRequest) -> bool {
+        self.done_prs.contains(&pr.number)
+    }
+
+    pub fn is_marked(&self, pr: &PullRequest) -> bool {
+        self.marked_prs
+            .contains(&(pr.repo_owner.clone(), pr.repo_name.clone(), pr.number))
+    }
+
     pub fn selected_pr(&self) -> Option<&PullRequest> {
         let prs = self.current_prs();
         self.table_state
@@ -385,14 +899,15 @@ impl App {
     }
 
     pub fn spinner(&self) -> &'static str {
-        SPINNER_FRAMES[self.spinner_idx]
+        let frames = self.theme.spinner_frames;
+        frames[self.spinner_idx % frames.len()]
     }
 
     // Spinner update
 
     pub fn update_spinner(&mut self) {
-        if self.last_spinner_update.elapsed() >= Duration::from_millis(80) {
-            self.spinner_idx = (self.spinner_idx + 1) % SPINNER_FRAMES.len();
+        if self.last_spinner_update.elapsed() >= self.config.spinner.interval() {
+            self.spinner_idx = (self.spinner_idx + 1) % self.theme.spinner_frames.len();
             self.last_spinner_update = Instant::now();
         }
     }
@@ -404,13 +919,57 @@ impl App {
             PrFilter::MyPrs => self.loading_my_prs = true,
             PrFilter::ReviewRequested => self.loading_review_prs = true,
             PrFilter::Labels(_) => self.loading_labels_prs = true,
+            PrFilter::Search(_) => self.loading_search_prs = true,
+            PrFilter::Team(_) => self.loading_team_prs = true,
+            PrFilter::Recent => self.loading_recent_prs = true,
+            PrFilter::All => {
+                // The All tab is composed locally, so refresh each underlying list
+                self.start_fetch(PrFilter::MyPrs);
+                self.start_fetch(PrFilter::ReviewRequested);
+                self.start_fetch(PrFilter::Labels(self.get_active_labels()));
+                return;
+            }
+        }
+        // Only clear a failed fetch; startup and other errors stay until dismissed
+        if let Some(fetch_error) = self.fetch_error.take() {
+            if self.error.as_ref() == Some(&fetch_error) {
+                self.error = None;
+                self.show_error_popup = false;
+            }
         }
-        self.error = None;
-        self.show_error_popup = false;
         self.last_main_refresh = Instant::now();
+        self.main_refresh_jitter = jitter(self.config.poll_jitter());
         let _ = self.fetch_tx.send(filter);
     }
 
+    /// Close the confirmation prompt along with the pending state of every
+    /// action it can guard, so a later confirm can't act on a stale target
+    pub fn dismiss_confirmation(&mut self) {
+        self.confirmation = None;
+        self.pending_checkout_branch = None;
+        self.pending_checkout_pr = None;
+        self.pending_checkout_command = None;
+        self.pending_cancel = None;
+    }
+
+    /// Clear the loading flag a fetch for `filter` set, once it has failed
+    pub fn finish_fetch(&mut self, filter: &PrFilter) {
+        match filter {
+            PrFilter::MyPrs => self.loading_my_prs = false,
+            PrFilter::ReviewRequested => self.loading_review_prs = false,
+            PrFilter::Labels(_) => self.loading_labels_prs = false,
+            PrFilter::Search(_) => self.loading_search_prs = false,
+            PrFilter::Team(_) => self.loading_team_prs = false,
+            PrFilter::Recent => self.loading_recent_prs = false,
+            PrFilter::All => {}
+        }
+    }
+
+    /// Whether the process was resumed since the last check
+    pub fn check_resumed(&mut self) -> bool {
+        self.resume_rx.try_iter().count() > 0
+    }
+
     pub fn check_fetch_result(&mut self) -> Option<FetchResult> {
         self.result_rx.try_recv().ok()
     }
@@ -420,6 +979,7 @@ impl App {
     pub fn start_actions_fetch(&mut self, owner: &str, repo: &str, pr_number: u64, head_sha: &str) {
         self.actions_loading = true;
         self.last_actions_poll = Instant::now();
+        self.actions_poll_jitter = jitter(self.config.poll_jitter());
         let _ = self.actions_tx.send((
             owner.to_string(),
             repo.to_string(),
@@ -454,37 +1014,64 @@ impl App {
         self.show_workflows_view
             && self.actions_poll_enabled
             && !self.actions_loading
-            && self.last_actions_poll.elapsed() >= Duration::from_secs(30)
+            && self.last_actions_poll.elapsed()
+                >= self.config.actions_poll_interval() * self.poll_backoff
+                    + self.actions_poll_jitter
     }
 
     pub fn should_refresh_main(&self) -> bool {
         // Only auto-refresh when on the main page (not in any special views or popups)
-        !self.show_workflows_view
+        !self.config.offline_start
+            && !self.show_workflows_view
             && !self.show_preview_view
             && !self.show_help_popup
-            && !self.show_checkout_popup
+            && self.confirmation.is_none()
+            && !self.show_merge_popup
             && !self.show_error_popup
             && !self.show_labels_popup
             && !self.show_add_label_popup
+            && !self.show_saved_searches_popup
+            && !self.show_add_saved_search_popup
+            && !self.show_recent_checkouts_popup
+            && !self.show_checks_popup
             && !self.is_loading()
-            && self.last_main_refresh.elapsed() >= Duration::from_secs(30)
+            && self.last_main_refresh.elapsed()
+                >= self.config.refresh_interval() * self.poll_backoff + self.main_refresh_jitter
     }
 
     // Preview fetch management
 
-    pub fn start_preview_fetch(&mut self, owner: &str, repo: &str, pr_number: u64) {
+    /// Fetch the preview, keeping any current data on screen until it arrives
+    pub fn start_preview_fetch(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        pr_number: u64,
+        max_comments: usize,
+    ) {
         self.preview_loading = true;
-        self.preview_data = None;
-        self.preview_scroll = 0;
-        let _ = self
-            .preview_tx
-            .send((owner.to_string(), repo.to_string(), pr_number));
+        let _ =
+            self.preview_tx
+                .send((owner.to_string(), repo.to_string(), pr_number, max_comments));
     }
 
     pub fn check_preview_result(&mut self) -> Option<FetchResult> {
         self.preview_rx.try_recv().ok()
     }
 
+    // Annotations fetch management
+
+    pub fn start_annotations_fetch(&mut self, owner: &str, repo: &str, check_run_id: u64) {
+        self.job_logs_loading = true;
+        let _ = self
+            .annotations_tx
+            .send((owner.to_string(), repo.to_string(), check_run_id));
+    }
+
+    pub fn check_annotations_result(&mut self) -> Option<FetchResult> {
+        self.annotations_rx.try_recv().ok()
+    }
+
     // CircleCI job logs fetch management
 
     pub fn start_circleci_logs_fetch(
@@ -508,4 +1095,61 @@ impl App {
     pub fn check_circleci_logs_result(&mut self) -> Option<FetchResult> {
         self.circleci_logs_rx.try_recv().ok()
     }
+
+    // Merge management
+
+    pub fn start_merge(&mut self, owner: &str, repo: &str, pr_number: u64, method: MergeMethod) {
+        self.merging = true;
+        let _ = self
+            .merge_tx
+            .send((owner.to_string(), repo.to_string(), pr_number, method));
+    }
+
+    pub fn check_merge_result(&mut self) -> Option<FetchResult> {
+        self.merge_rx.try_recv().ok()
+    }
+
+    // Single-PR CI status management
+
+    pub fn start_ci_status_fetch(&mut self, owner: &str, repo: &str, pr_number: u64) {
+        self.refreshing_ci = Some(pr_number);
+        let _ = self
+            .ci_status_tx
+            .send((owner.to_string(), repo.to_string(), pr_number));
+    }
+
+    pub fn check_ci_status_result(&mut self) -> Option<FetchResult> {
+        self.ci_status_rx.try_recv().ok()
+    }
+
+    // Checks popup management
+
+    pub fn start_checks_fetch(&mut self, owner: &str, repo: &str, pr_number: u64) {
+        self.checks_loading = true;
+        let _ = self
+            .checks_tx
+            .send((owner.to_string(), repo.to_string(), pr_number));
+    }
+
+    pub fn check_checks_result(&mut self) -> Option<FetchResult> {
+        self.checks_rx.try_recv().ok()
+    }
+
+    // Workflow run action management
+
+    pub fn start_run_action(
+        &mut self,
+        owner: &str,
+        repo: &str,
+        target: RunTarget,
+        action: RunAction,
+    ) {
+        let _ = self
+            .run_action_tx
+            .send((owner.to_string(), repo.to_string(), target, action));
+    }
+
+    pub fn check_run_action_result(&mut self) -> Option<FetchResult> {
+        self.run_action_rx.try_recv().ok()
+    }
 }
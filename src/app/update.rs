@@ -1,20 +1,47 @@
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::TableState;
+use std::collections::{BTreeMap, BTreeSet};
 use std::process::Command as ProcessCommand;
+use std::time::Duration;
 
 use crate::data::{
-    AnnotationLevel, CheckAnnotation, JobLogs, PrFilter, WorkflowConclusion, WorkflowJob,
-    WorkflowStatus,
+    ActionsData, AnnotationLevel, AnnotationSort, CheckAnnotation, CiProvider, CiStatus, CiSummary,
+    JobLogs, PrFilter, PrSort, PullRequest, RecentCheckout, ReviewState, RunAction, RunTarget,
+    WorkflowConclusion, WorkflowJob, WorkflowRun, WorkflowStatus,
 };
 use crate::icons;
 use crate::services::{
-    circleci_debug_log as debug_log, delete_label_filter, extract_job_number_from_url, filter_prs,
-    is_circleci_configured, is_circleci_url, load_label_filters, save_label_filter,
+    circleci_debug_log as debug_log, clear_done_prs, delete_label_filter, delete_saved_search,
+    extract_job_number_from_url, filter_labels, filter_prs, get_config_dir, is_circleci_configured,
+    is_circleci_url, load_cache_for_display, load_global_label_filters, load_label_filters,
+    load_recent_checkouts, load_saved_searches, save_done_pr, save_label_filter,
+    save_recent_checkout, save_saved_search, update_state, Config, MatchPositions,
+    MAX_PREVIEW_COMMENTS,
+};
+use crate::utils::{
+    branch_exists_locally, checkout_branch, checkout_command, default_branch, find_repo_root,
+    now_timestamp, uses_jj,
+};
+use crate::view::{
+    calculate_preview_positions, help_max_scroll, job_logs_viewport, spinner_frames,
 };
-use crate::utils::checkout_branch;
-use crate::view::calculate_preview_positions;
 
+use super::input::TextInput;
 use super::message::{Command, FetchResult, Message};
-use super::model::App;
+use super::model::{App, Confirmation};
+
+const MIN_COLUMN_WIDTH: u16 = 6;
+const MAX_COLUMN_WIDTH: u16 = 80;
+/// Minimum time since the last refresh before regaining focus triggers another
+const FOCUS_REFRESH_DEBOUNCE: Duration = Duration::from_secs(5);
+/// Largest multiplier for polling intervals while GitHub reports a rate limit
+const MAX_POLL_BACKOFF: u32 = 16;
+/// Failed fetches in a row before the offline banner shows, so one dropped
+/// request doesn't flip the UI
+const OFFLINE_AFTER_FAILURES: u32 = 2;
+/// Pause between browser launches when opening several URLs
+const OPEN_URL_SPACING: Duration = Duration::from_millis(200);
 
 /// Update the application state based on a message.
 /// Returns an optional command to be executed by the main loop.
@@ -42,9 +69,44 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             None
         }
 
+        // Type-ahead jump
+        Message::StartTypeAhead => {
+            app.type_ahead = Some(String::new());
+            app.type_ahead_time = std::time::Instant::now();
+            None
+        }
+        Message::TypeAheadInput(c) => {
+            type_ahead_input(app, c);
+            None
+        }
+        Message::CancelTypeAhead => {
+            app.type_ahead = None;
+            None
+        }
+
         // Tab switching
         Message::SwitchTab(filter) => {
             switch_filter(app, filter);
+            first_visit_fetch(app)
+        }
+        Message::NextTeamTab => next_team_tab(app),
+
+        // Table layout
+        Message::GrowColumn => {
+            let width = app.column_widths.get(app.resize_column) + 2;
+            resize_column(app, width.min(MAX_COLUMN_WIDTH));
+            None
+        }
+        Message::ShrinkColumn => {
+            let width = app.column_widths.get(app.resize_column).saturating_sub(2);
+            resize_column(app, width.max(MIN_COLUMN_WIDTH));
+            None
+        }
+        Message::CycleResizeColumn => {
+            app.resize_column = app.resize_column.next();
+            app.clipboard_feedback =
+                Some(format!("Resizing the {} column", app.resize_column.label()));
+            app.clipboard_feedback_time = std::time::Instant::now();
             None
         }
 
@@ -53,27 +115,88 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             open_selected(app);
             None
         }
-        Message::PromptCheckout => {
-            prompt_checkout(app);
+        Message::OpenRepoPulls => {
+            open_repo_pulls(app);
+            None
+        }
+        Message::ToggleMarkPr => {
+            toggle_mark_pr(app);
+            None
+        }
+        Message::ToggleDone => {
+            toggle_done(app);
+            None
+        }
+        Message::PromptClearDone => {
+            prompt_clear_done(app);
+            None
+        }
+        Message::ConfirmClearDone => {
+            confirm_clear_done(app);
+            None
+        }
+        Message::CopyChecklist => {
+            copy_checklist(app);
+            None
+        }
+        Message::CopyGhCommand => {
+            copy_gh_command(app);
+            None
+        }
+        Message::CopyHeadSha => {
+            copy_head_sha(app);
+            None
+        }
+        Message::CopyTitle => {
+            copy_title(app, false);
             None
         }
+        Message::CopyChangelogEntry => {
+            copy_title(app, true);
+            None
+        }
+        Message::PromptCheckout => prompt_checkout(app),
         Message::ConfirmCheckout => {
             if confirm_checkout(app) {
                 return Some(Command::ExitAfterCheckout);
             }
             None
         }
-        Message::CancelCheckout => {
-            app.show_checkout_popup = false;
-            app.pending_checkout_branch = None;
+        Message::MergePr => prompt_merge(app),
+        Message::CycleMergeMethod => {
+            app.merge_method = app.merge_method.next();
+            None
+        }
+        Message::ConfirmMerge => confirm_merge(app),
+        Message::CancelMerge => {
+            app.show_merge_popup = false;
+            app.pending_merge_pr = None;
+            None
+        }
+        Message::MergeResultReceived(result) => handle_merge_result(app, result),
+        Message::RefreshSelectedCi => refresh_selected_ci(app),
+        Message::CiStatusReceived(result) => {
+            handle_ci_status_result(app, result);
             None
         }
         Message::Refresh => {
-            if matches!(app.pr_filter, PrFilter::Labels(_)) {
-                let labels = app.get_active_labels();
-                Some(Command::StartFetch(PrFilter::Labels(labels)))
+            // Mashing r would otherwise queue a fetch per press behind the running one
+            if app.is_loading() {
+                app.clipboard_feedback = Some("Already refreshing".to_string());
+                app.clipboard_feedback_time = std::time::Instant::now();
+                return None;
+            }
+            Some(refresh_command(app))
+        }
+        Message::FocusGained => {
+            // Terminals can send a burst of focus events when switching windows
+            if app.config.refresh_on_focus
+                && !app.is_loading()
+                && app.last_main_refresh.elapsed() >= FOCUS_REFRESH_DEBOUNCE
+            {
+                update(app, Message::Refresh)
             } else {
-                Some(Command::StartFetch(app.pr_filter.clone()))
+                None
             }
         }
 
@@ -82,22 +205,56 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             app.search_mode = true;
             None
         }
-        Message::ExitSearchMode { clear } => {
-            exit_search_mode(app, clear);
+        Message::ExitSearchMode { clear } => exit_search_mode(app, clear),
+        Message::SearchEdit(edit) => {
+            edit_search(app, |input| input.apply(edit));
+            None
+        }
+        Message::Paste(text) => {
+            paste(app, &text);
+            None
+        }
+        Message::CyclePrSort => {
+            app.pr_sort = app.pr_sort.next();
+            update_filtered_indices(app);
+            app.table_state = TableState::default();
+            if !app.filtered_indices.is_empty() {
+                app.table_state.select(Some(0));
+            }
+            app.clipboard_feedback = Some(format!("Sort: {}", app.pr_sort.label()));
+            app.clipboard_feedback_time = std::time::Instant::now();
+            None
+        }
+        Message::ToggleHideApproved => {
+            toggle_hide_approved(app);
+            None
+        }
+        Message::ToggleHideDone => {
+            toggle_hide_done(app);
             None
         }
-        Message::SearchInput(c) => {
-            search_push_char(app, c);
+        Message::CycleMilestoneFilter => {
+            cycle_milestone_filter(app);
             None
         }
-        Message::SearchBackspace => {
-            search_pop_char(app);
+        Message::ToggleReadyToMerge => {
+            toggle_ready_to_merge(app);
             None
         }
 
         // Popups
         Message::ToggleHelp => {
             app.show_help_popup = !app.show_help_popup;
+            app.help_scroll = 0;
+            None
+        }
+        Message::HelpScrollDown => {
+            app.help_scroll =
+                (app.help_scroll + 1).min(help_max_scroll(&app.keymap, app.terminal_height));
+            None
+        }
+        Message::HelpScrollUp => {
+            app.help_scroll = app.help_scroll.saturating_sub(1);
             None
         }
         Message::DismissHelp => {
@@ -112,6 +269,19 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             app.show_url_popup = None;
             None
         }
+        Message::DismissGlyphWarning { ascii } => {
+            app.show_glyph_warning_popup = false;
+            if ascii {
+                crate::icons::set_ascii(true);
+                app.theme.spinner_frames = spinner_frames(&app.config.spinner);
+            }
+            // Remember the answer so the warning only shows once
+            if let Err(e) = update_state(|state| state.glyph_warning_acknowledged = true) {
+                app.error = Some(format!("Failed to save setting: {}", e));
+                app.show_error_popup = true;
+            }
+            None
+        }
 
         // Labels
         Message::OpenLabelsPopup => {
@@ -125,7 +295,8 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
         Message::OpenAddLabelPopup => {
             app.show_add_label_popup = true;
             app.label_input.clear();
-            app.label_scope_global = false;
+            // Without a repo there's nothing to scope to, so labels are global
+            app.label_scope_global = app.repo_owner.is_none() || app.repo_name.is_none();
             None
         }
         Message::CloseAddLabelPopup => {
@@ -133,20 +304,19 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             app.label_input.clear();
             None
         }
-        Message::LabelInput(c) => {
-            app.label_input.push(c);
-            None
-        }
-        Message::LabelBackspace => {
-            app.label_input.pop();
+        Message::LabelEdit(edit) => {
+            app.label_input.apply(edit);
             None
         }
         Message::ToggleLabelScope => {
-            app.label_scope_global = !app.label_scope_global;
+            if app.repo_owner.is_some() && app.repo_name.is_some() {
+                app.label_scope_global = !app.label_scope_global;
+            }
             None
         }
         Message::AddLabel => add_label(app),
         Message::DeleteSelectedLabel => delete_selected_label(app),
+        Message::FocusSelectedLabel => focus_selected_label(app),
         Message::LabelsNext => {
             labels_next(app);
             None
@@ -155,6 +325,156 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             labels_previous(app);
             None
         }
+        Message::LabelsEnterSearch => {
+            app.labels_search_mode = true;
+            None
+        }
+        Message::LabelsExitSearch { clear } => {
+            app.labels_search_mode = false;
+            if clear {
+                app.labels_search_query.clear();
+                update_labels_filtered_indices(app);
+            }
+            None
+        }
+        Message::LabelsSearchEdit(edit) => {
+            edit_labels_search(app, |input| input.apply(edit));
+            None
+        }
+
+        // Saved searches
+        Message::OpenSavedSearchesPopup => {
+            app.show_saved_searches_popup = true;
+            app.saved_searches_list_state = TableState::default();
+            if !app.saved_searches.is_empty() {
+                app.saved_searches_list_state.select(Some(0));
+            }
+            None
+        }
+        Message::CloseSavedSearchesPopup => {
+            app.show_saved_searches_popup = false;
+            None
+        }
+        Message::SavedSearchesNext => {
+            if let Some(i) = app.saved_searches_list_state.selected() {
+                let len = app.saved_searches.len();
+                let next = step_next(i, len, app.config.wrap_navigation);
+                app.saved_searches_list_state.select(Some(next));
+            }
+            None
+        }
+        Message::SavedSearchesPrevious => {
+            if let Some(i) = app.saved_searches_list_state.selected() {
+                let len = app.saved_searches.len();
+                let previous = step_previous(i, len, app.config.wrap_navigation);
+                app.saved_searches_list_state.select(Some(previous));
+            }
+            None
+        }
+        Message::ActivateSavedSearch => activate_saved_search(app),
+        Message::DeleteSelectedSavedSearch => {
+            delete_selected_saved_search(app);
+            None
+        }
+        Message::OpenRecentCheckoutsPopup => {
+            if let (Some(owner), Some(repo)) = (&app.repo_owner, &app.repo_name) {
+                app.recent_checkouts = load_recent_checkouts(owner, repo).unwrap_or_default();
+            }
+            app.show_recent_checkouts_popup = true;
+            app.recent_checkouts_list_state = TableState::default();
+            if !app.recent_checkouts.is_empty() {
+                app.recent_checkouts_list_state.select(Some(0));
+            }
+            None
+        }
+        Message::CloseRecentCheckoutsPopup => {
+            app.show_recent_checkouts_popup = false;
+            None
+        }
+        Message::RecentCheckoutsNext => {
+            if let Some(i) = app.recent_checkouts_list_state.selected() {
+                let len = app.recent_checkouts.len();
+                let next = step_next(i, len, app.config.wrap_navigation);
+                app.recent_checkouts_list_state.select(Some(next));
+            }
+            None
+        }
+        Message::RecentCheckoutsPrevious => {
+            if let Some(i) = app.recent_checkouts_list_state.selected() {
+                let len = app.recent_checkouts.len();
+                let previous = step_previous(i, len, app.config.wrap_navigation);
+                app.recent_checkouts_list_state.select(Some(previous));
+            }
+            None
+        }
+        Message::CheckoutRecent(index) => {
+            let checkout = app.recent_checkouts.get(index)?.clone();
+            app.show_recent_checkouts_popup = false;
+            prompt_checkout_branch(app, checkout.branch, checkout.pr_number)
+        }
+        Message::OpenChecksPopup => {
+            let pr = app.selected_pr()?;
+            let (owner, repo, number) = (pr.repo_owner.clone(), pr.repo_name.clone(), pr.number);
+            app.show_checks_popup = true;
+            app.checks.clear();
+            app.checks_pr = Some(number);
+            app.checks_list_state = TableState::default();
+            Some(Command::StartChecksFetch(owner, repo, number))
+        }
+        Message::CloseChecksPopup => {
+            app.show_checks_popup = false;
+            app.checks_pr = None;
+            None
+        }
+        Message::ChecksNext => {
+            if let Some(i) = app.checks_list_state.selected() {
+                let next = step_next(i, app.checks.len(), app.config.wrap_navigation);
+                app.checks_list_state.select(Some(next));
+            }
+            None
+        }
+        Message::ChecksPrevious => {
+            if let Some(i) = app.checks_list_state.selected() {
+                let previous = step_previous(i, app.checks.len(), app.config.wrap_navigation);
+                app.checks_list_state.select(Some(previous));
+            }
+            None
+        }
+        Message::OpenCheckUrl => {
+            open_check_url(app);
+            None
+        }
+        Message::ChecksReceived(result) => {
+            handle_checks_result(app, result);
+            None
+        }
+        Message::OpenAddSavedSearchPopup => {
+            app.show_add_saved_search_popup = true;
+            app.saved_search_name_input.clear();
+            app.saved_search_query_input.clear();
+            app.saved_search_editing_query = false;
+            None
+        }
+        Message::CloseAddSavedSearchPopup => {
+            app.show_add_saved_search_popup = false;
+            None
+        }
+        Message::SavedSearchEdit(edit) => {
+            if app.saved_search_editing_query {
+                app.saved_search_query_input.apply(edit);
+            } else {
+                app.saved_search_name_input.apply(edit);
+            }
+            None
+        }
+        Message::ToggleSavedSearchField => {
+            app.saved_search_editing_query = !app.saved_search_editing_query;
+            None
+        }
+        Message::AddSavedSearch => {
+            add_saved_search(app);
+            None
+        }
 
         // Workflows view
         Message::OpenWorkflowsView => open_workflows_view(app),
@@ -175,10 +495,41 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             actions_previous_job(app);
             None
         }
+        Message::ToggleJobSummary => {
+            app.job_summary_expanded = !app.job_summary_expanded;
+            None
+        }
+        Message::CycleProviderFilter => {
+            cycle_provider_filter(app);
+            None
+        }
+        Message::PromptRerun => prompt_rerun(app),
+        Message::ConfirmRerun { failed_only } => confirm_rerun(app, failed_only),
+        Message::CancelRerun => {
+            app.pending_rerun = None;
+            None
+        }
+        Message::PromptCancelRun => prompt_cancel_run(app),
+        Message::ConfirmCancelRun => confirm_cancel_run(app),
+
+        // Confirmation prompt
+        Message::Confirm => {
+            let confirmation = app.confirmation.take()?;
+            update(app, confirmation.on_confirm)
+        }
+        Message::DismissConfirmation => {
+            app.dismiss_confirmation();
+            None
+        }
+        Message::RunActionResultReceived(result) => handle_run_action_result(app, result),
         Message::OpenActionsInBrowser => {
             open_actions_in_browser(app);
             None
         }
+        Message::CopyRunUrl => {
+            copy_run_url(app);
+            None
+        }
 
         // Job logs
         Message::OpenJobLogs => open_job_logs(app),
@@ -197,7 +548,12 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             None
         }
         Message::JobLogsScrollDown => {
-            app.job_logs_scroll = app.job_logs_scroll.saturating_add(3);
+            // Stop once the last line reaches the bottom of the view
+            let max_scroll = app
+                .job_logs_line_count
+                .saturating_sub(job_logs_viewport(app.terminal_height));
+            let new_scroll = app.job_logs_scroll.saturating_add(3);
+            app.job_logs_scroll = new_scroll.min(u16::try_from(max_scroll).unwrap_or(u16::MAX));
             None
         }
         Message::CopyJobLogs => {
@@ -216,6 +572,10 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             job_logs_toggle_step(app);
             None
         }
+        Message::JobLogsJumpToFailure => {
+            jump_to_first_failure(app);
+            None
+        }
         Message::OpenStepInEditor => open_step_in_editor(app),
         Message::CopyTestFailures => {
             copy_test_failures(app);
@@ -225,6 +585,7 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             full_copy_step_output(app);
             None
         }
+        Message::SaveJobLogs => save_job_logs(app),
 
         // Annotations view
         Message::AnnotationNext => {
@@ -235,6 +596,19 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             annotation_previous(app);
             None
         }
+        Message::AnnotationNextFile => {
+            annotation_next_file(app);
+            None
+        }
+        Message::AnnotationPreviousFile => {
+            annotation_previous_file(app);
+            None
+        }
+        Message::ToggleAnnotationsView => toggle_annotations_view(app),
+        Message::CycleAnnotationSort => {
+            cycle_annotation_sort(app);
+            None
+        }
         Message::ToggleAnnotationSelection => {
             toggle_annotation_selection(app);
             None
@@ -243,6 +617,15 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             copy_annotations(app);
             None
         }
+        Message::CopyAnnotationsGrouped => {
+            copy_annotations_grouped(app);
+            None
+        }
+        Message::OpenAnnotationInEditor => open_annotation_in_editor(app),
+        Message::CopyAnnotationLocation => {
+            copy_annotation_location(app);
+            None
+        }
 
         // Preview view
         Message::OpenPreviewView => open_preview_view(app),
@@ -250,6 +633,7 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             close_preview_view(app);
             None
         }
+        Message::AnnotationsReceived(result) => handle_annotations_result(app, result),
         Message::PreviewDataReceived(result) => {
             handle_preview_result(app, result);
             None
@@ -280,6 +664,7 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             app.preview_section_index = 0;
             None
         }
+        Message::PreviewLoadMore => preview_load_more(app),
         Message::PreviewGoToBottom => {
             // Scroll to show the end of content (leave some visible lines at top)
             let visible_height = 20u16; // Approximate visible height
@@ -301,6 +686,9 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             if app.loading_my_prs
                 || app.loading_review_prs
                 || app.loading_labels_prs
+                || app.loading_search_prs
+                || app.loading_team_prs
+                || app.loading_recent_prs
                 || app.actions_loading
                 || app.job_logs_loading
                 || app.preview_loading
@@ -313,11 +701,23 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
             {
                 app.clipboard_feedback = None;
             }
+            // End type-ahead after a pause in typing
+            if app.type_ahead.is_some()
+                && app.type_ahead_time.elapsed() >= std::time::Duration::from_secs(1)
+            {
+                app.type_ahead = None;
+            }
             None
         }
 
         // Async results
-        Message::FetchComplete(result) => handle_fetch_result(app, result),
+        Message::FetchComplete(result) => {
+            let command = handle_fetch_result(app, result);
+            if !app.loading_my_prs && !app.loading_review_prs {
+                app.startup_progress = false;
+            }
+            command
+        }
 
         // System
         Message::Quit => Some(Command::Quit),
@@ -326,18 +726,34 @@ pub fn update(app: &mut App, msg: Message) -> Option<Command> {
 
 // Helper functions
 
+/// Index after `current` in a non-empty list, staying put at the end unless `wrap` is set
+fn step_next(current: usize, len: usize, wrap: bool) -> usize {
+    if current + 1 < len {
+        current + 1
+    } else if wrap {
+        0
+    } else {
+        current
+    }
+}
+
+/// Index before `current` in a non-empty list, staying put at the start unless `wrap` is set
+fn step_previous(current: usize, len: usize, wrap: bool) -> usize {
+    if current > 0 {
+        current - 1
+    } else if wrap {
+        len.saturating_sub(1)
+    } else {
+        0
+    }
+}
+
 fn next_item(app: &mut App) {
     if app.filtered_indices.is_empty() {
         return;
     }
     let i = match app.table_state.selected() {
-        Some(i) => {
-            if i >= app.filtered_indices.len() - 1 {
-                i
-            } else {
-                i + 1
-            }
-        }
+        Some(i) => step_next(i, app.filtered_indices.len(), app.config.wrap_navigation),
         None => 0,
     };
     app.table_state.select(Some(i));
@@ -348,85 +764,165 @@ fn previous_item(app: &mut App) {
         return;
     }
     let i = match app.table_state.selected() {
-        Some(i) => {
-            if i == 0 {
-                0
-            } else {
-                i - 1
-            }
-        }
+        Some(i) => step_previous(i, app.filtered_indices.len(), app.config.wrap_navigation),
         None => 0,
     };
     app.table_state.select(Some(i));
 }
 
-fn switch_filter(app: &mut App, filter: PrFilter) {
-    if app.pr_filter != filter {
-        app.pr_filter = filter;
-        app.table_state = TableState::default();
-        // Clear search when switching tabs
-        app.search_mode = false;
-        app.search_query.clear();
-        update_filtered_indices(app);
-        if !app.filtered_indices.is_empty() {
-            app.table_state.select(Some(0));
-        }
-    }
-}
+/// Jump to the next visible PR whose title starts with the typed prefix.
+/// Repeating a single character narrows to titles starting with both, or
+/// cycles through titles starting with it when none do.
+fn type_ahead_input(app: &mut App, c: char) {
+    let Some(ref mut buffer) = app.type_ahead else {
+        return;
+    };
+    app.type_ahead_time = std::time::Instant::now();
+
+    let c = c.to_ascii_lowercase();
+    let is_repeat = buffer.chars().count() == 1 && buffer.starts_with(c);
+    let single = buffer.clone();
+    buffer.push(c);
+    let prefix = buffer.clone();
 
-fn update_filtered_indices(app: &mut App) {
     let prs = app.current_prs();
-    app.filtered_indices = filter_prs(prs, &app.search_query);
-}
+    let len = app.filtered_indices.len();
+    let current = app.table_state.selected().unwrap_or(0);
+    let find = |prefix: &str, start: usize| {
+        (0..len).map(|offset| (start + offset) % len).find(|&row| {
+            app.filtered_indices
+                .get(row)
+                .and_then(|&idx| prs.get(idx))
+                .is_some_and(|pr| pr.title.to_lowercase().starts_with(prefix))
+        })
+    };
 
-fn open_selected(app: &mut App) {
-    if let Some(pr) = app.selected_pr() {
-        let url = format!(
-            "https://github.com/{}/{}/pull/{}",
-            pr.repo_owner, pr.repo_name, pr.number
-        );
-        if let Some(display_url) = open_url(&url) {
-            app.show_url_popup = Some(display_url);
-        }
+    // A longer prefix may still match the current row; cycling moves past it
+    let mut found = find(&prefix, current);
+    let cycled = is_repeat && found.is_none();
+    if cycled {
+        found = find(&single, current + 1);
+    }
+    if let Some(row) = found {
+        app.table_state.select(Some(row));
+    }
+    if cycled {
+        app.type_ahead = Some(single);
     }
 }
 
-fn prompt_checkout(app: &mut App) {
-    if let Some(pr) = app.selected_pr() {
-        app.pending_checkout_branch = Some(pr.branch.clone());
-        app.show_checkout_popup = true;
+/// Labels, and tabs left out of `prefetch_tabs`, are only fetched on demand,
+/// so the first visit starts the fetch
+fn first_visit_fetch(app: &App) -> Option<Command> {
+    if app.config.offline_start {
+        return None;
     }
+    let filter = match &app.pr_filter {
+        PrFilter::MyPrs if !app.my_prs_fetched && !app.loading_my_prs => PrFilter::MyPrs,
+        PrFilter::ReviewRequested if !app.review_prs_fetched && !app.loading_review_prs => {
+            PrFilter::ReviewRequested
+        }
+        PrFilter::Labels(_) if !app.labels_fetched && !app.loading_labels_prs => {
+            PrFilter::Labels(app.get_active_labels())
+        }
+        PrFilter::Recent if !app.recent_fetched && !app.loading_recent_prs => PrFilter::Recent,
+        // The All tab is composed from the others, so fill in any that haven't loaded
+        PrFilter::All
+            if (!app.my_prs_fetched || !app.review_prs_fetched)
+                && !app.loading_my_prs
+                && !app.loading_review_prs =>
+        {
+            PrFilter::All
+        }
+        _ => return None,
+    };
+    Some(Command::StartFetch(filter))
 }
 
-fn confirm_checkout(app: &mut App) -> bool {
-    if let Some(branch) = app.pending_checkout_branch.take() {
-        app.show_checkout_popup = false;
+/// Set the width of the column being resized, remembering it for next time
+fn resize_column(app: &mut App, width: u16) {
+    let column = app.resize_column;
+    app.column_widths.set(column, width);
+    if let Err(e) = update_state(|state| state.columns.set(column, width)) {
+        app.error = Some(format!("Failed to save column width: {}", e));
+        app.show_error_popup = true;
+    }
+}
 
-        match checkout_branch(&branch) {
-            Ok(()) => return true,
-            Err(e) => {
-                app.error = Some(e);
-                app.show_error_popup = true;
-            }
-        }
+fn switch_filter(app: &mut App, filter: PrFilter) {
+    if app.pr_filter != filter {
+        app.pr_sort = app.config.sort.for_filter(&filter);
+        app.pr_filter = filter;
+        reset_list_view(app);
     }
-    false
 }
 
-fn exit_search_mode(app: &mut App, clear_query: bool) {
+/// Reset selection and search for a freshly shown list
+fn reset_list_view(app: &mut App) {
+    app.table_state = TableState::default();
+    // Clear search when switching tabs
     app.search_mode = false;
-    if clear_query {
-        app.search_query.clear();
-        update_filtered_indices(app);
-        app.table_state = TableState::default();
-        if !app.filtered_indices.is_empty() {
-            app.table_state.select(Some(0));
-        }
+    app.search_query.clear();
+    app.marked_prs.clear();
+    // Milestones differ between lists, so the filter doesn't carry over
+    app.milestone_filter = None;
+    update_filtered_indices(app);
+    if !app.filtered_indices.is_empty() {
+        app.table_state.select(Some(0));
+    }
+}
+
+fn update_filtered_indices(app: &mut App) {
+    let prs = app.current_prs();
+    // Server-side queries (leading ':') don't filter the local list while typing
+    let query = if app.search_query.value().starts_with(':') {
+        ""
+    } else {
+        app.search_query.value()
+    };
+    let matches = filter_prs(prs, query, &app.config.search_fields);
+    let mut indices: Vec<usize> = matches.iter().map(|(idx, _)| *idx).collect();
+    let match_positions = matches
+        .into_iter()
+        .filter(|(_, positions)| positions != &MatchPositions::default())
+        .collect();
+    if app.ready_to_merge_only {
+        indices.retain(|&idx| prs[idx].is_ready_to_merge());
+    }
+    if let Some(ref milestone) = app.milestone_filter {
+        indices.retain(|&idx| prs[idx].milestone.as_ref() == Some(milestone));
+    }
+    let before_hiding = indices.len();
+    if app.hide_approved {
+        indices.retain(|&idx| prs[idx].my_review_state != ReviewState::Approved);
     }
+    let hidden_approved = before_hiding - indices.len();
+    let before_hiding = indices.len();
+    if app.hide_done {
+        indices.retain(|&idx| !app.done_prs.contains(&prs[idx].number));
+    }
+    let hidden_done = before_hiding - indices.len();
+    // Ascending by key; PRs without a timestamp (e.g. from an old cache) go last
+    let timestamp: Option<fn(&PullRequest) -> Option<i64>> = match app.pr_sort {
+        PrSort::Default => None,
+        PrSort::OpenedAge => Some(|pr| pr.created_at),
+        PrSort::Updated => Some(|pr| pr.updated_at),
+        PrSort::Newest => Some(|pr| pr.updated_at.map(|t| -t)),
+    };
+    // A fuzzy search keeps its best-match-first order
+    if let Some(timestamp) = timestamp.filter(|_| query.is_empty()) {
+        indices.sort_by_key(|&idx| timestamp(&prs[idx]).unwrap_or(i64::MAX));
+    }
+    // Runs whenever the tab or its PRs change, so the tab bar doesn't tally every frame
+    app.ci_summary = CiSummary::tally(prs.iter().map(|pr| pr.ci_status));
+    app.filtered_indices = indices;
+    app.match_positions = match_positions;
+    app.hidden_approved = hidden_approved;
+    app.hidden_done = hidden_done;
 }
 
-fn search_push_char(app: &mut App, c: char) {
-    app.search_query.push(c);
+fn toggle_ready_to_merge(app: &mut App) {
+    app.ready_to_merge_only = !app.ready_to_merge_only;
     update_filtered_indices(app);
     app.table_state = TableState::default();
     if !app.filtered_indices.is_empty() {
@@ -434,36 +930,643 @@ fn search_push_char(app: &mut App, c: char) {
     }
 }
 
-fn search_pop_char(app: &mut App) {
-    app.search_query.pop();
+fn toggle_hide_done(app: &mut App) {
+    app.hide_done = !app.hide_done;
     update_filtered_indices(app);
     app.table_state = TableState::default();
     if !app.filtered_indices.is_empty() {
         app.table_state.select(Some(0));
     }
+    let done_here = app
+        .current_prs()
+        .iter()
+        .filter(|pr| app.is_done(pr))
+        .count();
+    app.clipboard_feedback = Some(if app.hide_done {
+        format!("Hiding {} done", done_here)
+    } else {
+        format!("Showing {} done", done_here)
+    });
+    app.clipboard_feedback_time = std::time::Instant::now();
 }
 
-fn open_labels_popup(app: &mut App) {
-    app.show_labels_popup = true;
-    app.labels_list_state = TableState::default();
-    if !app.configured_labels.is_empty() {
-        app.labels_list_state.select(Some(0));
+fn toggle_hide_approved(app: &mut App) {
+    app.hide_approved = !app.hide_approved;
+    update_filtered_indices(app);
+    app.table_state = TableState::default();
+    if !app.filtered_indices.is_empty() {
+        app.table_state.select(Some(0));
     }
 }
 
-fn add_label(app: &mut App) -> Option<Command> {
-    if app.label_input.trim().is_empty() {
-        return None;
+/// Step the milestone filter through the milestones in the current list, then back to off
+fn cycle_milestone_filter(app: &mut App) {
+    let milestones: BTreeSet<&String> = app
+        .current_prs()
+        .iter()
+        .filter_map(|pr| pr.milestone.as_ref())
+        .collect();
+    if milestones.is_empty() && app.milestone_filter.is_none() {
+        app.clipboard_feedback = Some("No PRs in this list have a milestone".to_string());
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return;
     }
-
-    let label_name = app.label_input.trim().to_string();
-    let (owner, repo) = if app.label_scope_global {
-        (None, None)
-    } else {
-        (app.repo_owner.as_deref(), app.repo_name.as_deref())
+    let next = match app.milestone_filter {
+        Some(ref current) => milestones.into_iter().find(|m| *m > current),
+        None => milestones.first().copied(),
     };
-
-    if let Err(e) = save_label_filter(&label_name, owner, repo) {
+    app.milestone_filter = next.map(|m| m.to_string());
+    update_filtered_indices(app);
+    app.table_state = TableState::default();
+    if !app.filtered_indices.is_empty() {
+        app.table_state.select(Some(0));
+    }
+}
+
+/// Open the marked PRs in the browser, or the selected one if none are marked
+fn open_selected(app: &mut App) {
+    let marked: Vec<&PullRequest> = app
+        .visible_prs()
+        .into_iter()
+        .filter(|pr| app.is_marked(pr))
+        .collect();
+    let prs = if marked.is_empty() {
+        app.selected_pr().into_iter().collect()
+    } else {
+        marked
+    };
+    let urls: Vec<String> = prs
+        .iter()
+        .map(|pr| {
+            format!(
+                "https://github.com/{}/{}/pull/{}",
+                pr.repo_owner, pr.repo_name, pr.number
+            )
+        })
+        .collect();
+    if urls.is_empty() {
+        return;
+    }
+    if let Some(display_urls) = open_urls(&app.config, urls) {
+        app.show_url_popup = Some(display_urls);
+    }
+}
+
+/// Open the repo's pull requests page, filtered like the current tab where possible
+fn open_repo_pulls(app: &mut App) {
+    let (Some(owner), Some(repo)) = (&app.repo_owner, &app.repo_name) else {
+        return;
+    };
+    let mut url = format!("https://github.com/{}/{}/pulls", owner, repo);
+    if let Some(query) = app.pr_filter.web_query() {
+        url.push_str(&format!("?q={}", urlencoding::encode(&query)));
+    }
+    if let Some(display_url) = open_url(&app.config, &url) {
+        app.show_url_popup = Some(display_url);
+    }
+}
+
+/// Copy the selected PR's full head SHA, confirming with the abbreviated form
+fn copy_head_sha(app: &mut App) {
+    let Some(pr) = app.selected_pr() else {
+        return;
+    };
+    let feedback = match (pr.head_sha.as_deref(), pr.short_sha()) {
+        (Some(sha), Some(short)) if copy_to_clipboard(sha) => format!("Copied {}", short),
+        (Some(_), _) => "Failed to copy to clipboard".to_string(),
+        _ if pr.ci_status == CiStatus::NoCommits => format!("#{} has no commits yet", pr.number),
+        _ => "Commit SHA not loaded yet (r to refresh)".to_string(),
+    };
+    app.clipboard_feedback = Some(feedback);
+    app.clipboard_feedback_time = std::time::Instant::now();
+}
+
+/// Copy the selected PR's full title, or a changelog line: "- title (owner/repo#number)"
+fn copy_title(app: &mut App, changelog: bool) {
+    let Some(pr) = app.selected_pr() else {
+        return;
+    };
+    let (text, what) = if changelog {
+        let entry = format!(
+            "- {} ({}/{}#{})",
+            pr.title, pr.repo_owner, pr.repo_name, pr.number
+        );
+        (entry, "changelog entry")
+    } else {
+        (pr.title.clone(), "title")
+    };
+    app.clipboard_feedback = Some(if copy_to_clipboard(&text) {
+        format!("Copied {}", what)
+    } else {
+        "Failed to copy to clipboard".to_string()
+    });
+    app.clipboard_feedback_time = std::time::Instant::now();
+}
+
+fn toggle_mark_pr(app: &mut App) {
+    let Some(pr) = app.selected_pr() else {
+        return;
+    };
+    let key = (pr.repo_owner.clone(), pr.repo_name.clone(), pr.number);
+    if !app.marked_prs.remove(&key) {
+        app.marked_prs.insert(key);
+    }
+}
+
+/// Mark the selected PR done, or not done, for local triage. GitHub isn't touched.
+fn toggle_done(app: &mut App) {
+    let Some(pr) = app.selected_pr() else {
+        return;
+    };
+    let (owner, repo, number) = (pr.repo_owner.clone(), pr.repo_name.clone(), pr.number);
+    let done = !app.done_prs.contains(&number);
+    if let Err(e) = save_done_pr(&owner, &repo, number, done) {
+        app.error = Some(format!("Failed to save done mark: {}", e));
+        app.show_error_popup = true;
+        return;
+    }
+
+    let feedback = if done {
+        app.done_prs.insert(number);
+        format!("#{} marked done", number)
+    } else {
+        app.done_prs.remove(&number);
+        format!("#{} no longer done", number)
+    };
+    app.clipboard_feedback = Some(feedback);
+    app.clipboard_feedback_time = std::time::Instant::now();
+
+    if app.hide_done {
+        // The row disappears, so keep the selection on its neighbour
+        let selected = app.table_state.selected().unwrap_or(0);
+        update_filtered_indices(app);
+        app.table_state.select(match app.filtered_indices.len() {
+            0 => None,
+            len => Some(selected.min(len - 1)),
+        });
+    }
+}
+
+fn prompt_clear_done(app: &mut App) {
+    if app.done_prs.is_empty() {
+        app.clipboard_feedback = Some("No PRs are marked done".to_string());
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return;
+    }
+    app.confirmation = Some(Confirmation {
+        title: "Clear Done".to_string(),
+        lines: vec![Line::from(format!(
+            "Clear the done mark from {} PR{}?",
+            app.done_prs.len(),
+            if app.done_prs.len() == 1 { "" } else { "s" }
+        ))],
+        on_confirm: Message::ConfirmClearDone,
+        danger: false,
+    });
+}
+
+fn confirm_clear_done(app: &mut App) {
+    let (Some(owner), Some(repo)) = (app.repo_owner.clone(), app.repo_name.clone()) else {
+        return;
+    };
+    if let Err(e) = clear_done_prs(&owner, &repo) {
+        app.error = Some(format!("Failed to clear done marks: {}", e));
+        app.show_error_popup = true;
+        return;
+    }
+    let count = app.done_prs.len();
+    app.done_prs.clear();
+    update_filtered_indices(app);
+    if app.table_state.selected().is_none() && !app.filtered_indices.is_empty() {
+        app.table_state.select(Some(0));
+    }
+    app.clipboard_feedback = Some(format!("Cleared {} done marks", count));
+    app.clipboard_feedback_time = std::time::Instant::now();
+}
+
+/// Copy the marked PRs as a markdown checklist, or every visible PR if none are marked
+fn copy_checklist(app: &mut App) {
+    let visible = app.visible_prs();
+    let marked: Vec<_> = visible
+        .iter()
+        .copied()
+        .filter(|pr| app.is_marked(pr))
+        .collect();
+    let prs = if marked.is_empty() { visible } else { marked };
+    if prs.is_empty() {
+        return;
+    }
+
+    let text = prs
+        .iter()
+        .map(|pr| {
+            format!(
+                "- [ ] {}/{}#{} {}",
+                pr.repo_owner, pr.repo_name, pr.number, pr.title
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let count = prs.len();
+    app.clipboard_feedback = Some(if !copy_to_clipboard(&text) {
+        "Failed to copy to clipboard".to_string()
+    } else if count == 1 {
+        "Copied 1 PR as a checklist".to_string()
+    } else {
+        format!("Copied {} PRs as a checklist", count)
+    });
+    app.clipboard_feedback_time = std::time::Instant::now();
+}
+
+fn copy_gh_command(app: &mut App) {
+    let Some(pr) = app.selected_pr() else {
+        return;
+    };
+    let command = app.config.gh_command_for(pr);
+    app.clipboard_feedback = Some(if copy_to_clipboard(&command) {
+        format!("Copied: {}", command)
+    } else {
+        "Failed to copy to clipboard".to_string()
+    });
+    app.clipboard_feedback_time = std::time::Instant::now();
+}
+
+fn prompt_checkout(app: &mut App) -> Option<Command> {
+    let pr = app.selected_pr()?;
+    let (branch, number) = (pr.branch.clone(), pr.number);
+    prompt_checkout_branch(app, branch, number)
+}
+
+/// Ask before checking out a PR's branch, which may not be in the current list
+fn prompt_checkout_branch(app: &mut App, branch: String, pr_number: u64) -> Option<Command> {
+    if branch.is_empty() {
+        app.clipboard_feedback = Some(format!("No branch known for #{} yet", pr_number));
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return None;
+    }
+    let use_jj = app.config.jj.unwrap_or_else(uses_jj);
+    // In dry-run mode the popup shows what would run instead of running it
+    let dry_run_command = app
+        .config
+        .checkout_dry_run
+        .then(|| checkout_command(&branch, use_jj));
+    // A PR from the default branch is usually mislabeled; checking it out moves you off your work
+    let is_default_branch = app.config.confirm_destructive.default_branch_checkout
+        && default_branch().is_some_and(|default| default == branch);
+    app.pending_checkout_branch = Some(branch.clone());
+    app.pending_checkout_pr = Some(pr_number);
+    if dry_run_command.is_none() && !app.config.confirm_destructive.checkout && !is_default_branch {
+        return update(app, Message::ConfirmCheckout);
+    }
+
+    let mut lines = vec![
+        Line::from(format!("Checkout branch: {}", branch)),
+        if branch_exists_locally(&branch, use_jj) {
+            Line::styled("(exists locally)", Style::default().fg(Color::Green))
+        } else {
+            Line::styled(
+                "(new branch from origin)",
+                Style::default().fg(Color::Yellow),
+            )
+        },
+    ];
+    if is_default_branch {
+        lines.push(Line::raw(""));
+        lines.push(Line::styled(
+            format!("Warning: {} is the repo's default branch", branch),
+            Style::default().fg(Color::Red),
+        ));
+    }
+    if let Some(ref cmd) = dry_run_command {
+        lines.push(Line::raw(""));
+        lines.push(Line::from(vec![
+            Span::raw("Would run: "),
+            Span::styled(cmd.clone(), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+    app.pending_checkout_command = dry_run_command;
+    app.confirmation = Some(Confirmation {
+        title: if app.pending_checkout_command.is_some() {
+            "Checkout (dry run)".to_string()
+        } else {
+            "Checkout".to_string()
+        },
+        lines,
+        on_confirm: Message::ConfirmCheckout,
+        danger: is_default_branch,
+    });
+    None
+}
+
+fn confirm_checkout(app: &mut App) -> bool {
+    if let Some(branch) = app.pending_checkout_branch.take() {
+        if app.pending_checkout_command.take().is_some() {
+            app.clipboard_feedback = Some("Dry run: nothing was checked out".to_string());
+            app.clipboard_feedback_time = std::time::Instant::now();
+            return false;
+        }
+
+        let use_jj = app.config.jj.unwrap_or_else(uses_jj);
+        match checkout_branch(&branch, use_jj) {
+            Ok(()) => {
+                record_checkout(app, branch);
+                return true;
+            }
+            Err(e) => {
+                app.error = Some(e);
+                app.show_error_popup = true;
+            }
+        }
+    }
+    false
+}
+
+/// Remember a checked-out branch for the recent checkouts popup
+fn record_checkout(app: &mut App, branch: String) {
+    let (Some(owner), Some(repo), Some(pr_number)) = (
+        app.repo_owner.clone(),
+        app.repo_name.clone(),
+        app.pending_checkout_pr.take(),
+    ) else {
+        return;
+    };
+    let _ = save_recent_checkout(&RecentCheckout {
+        branch,
+        pr_number,
+        repo_owner: owner,
+        repo_name: repo,
+        checked_out_at: now_timestamp(),
+    });
+}
+
+fn prompt_merge(app: &mut App) -> Option<Command> {
+    if !app.config.merge.enabled {
+        app.clipboard_feedback =
+            Some("Merging is disabled; set [merge] enabled = true in config.toml".to_string());
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return None;
+    }
+    if app.merging {
+        return None;
+    }
+    let pr = app.selected_pr()?;
+    if !pr.is_ready_to_merge() {
+        app.clipboard_feedback = Some(format!(
+            "#{} isn't ready to merge: it needs green CI, any required approval and no conflicts",
+            pr.number
+        ));
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return None;
+    }
+    // Multi-repo tabs can list PRs from other repositories than the current one
+    app.pending_merge_pr = Some((pr.repo_owner.clone(), pr.repo_name.clone(), pr.number));
+    app.merge_method = app.config.merge.method;
+    if !app.config.confirm_destructive.merge {
+        return confirm_merge(app);
+    }
+    app.show_merge_popup = true;
+    None
+}
+
+fn confirm_merge(app: &mut App) -> Option<Command> {
+    app.show_merge_popup = false;
+    let (owner, repo, number) = app.pending_merge_pr.take()?;
+    Some(Command::StartMerge(owner, repo, number, app.merge_method))
+}
+
+/// Fetch the current tab again
+fn refresh_command(app: &App) -> Command {
+    if matches!(app.pr_filter, PrFilter::Labels(_)) {
+        Command::StartFetch(PrFilter::Labels(app.get_active_labels()))
+    } else {
+        Command::StartFetch(app.pr_filter.clone())
+    }
+}
+
+fn handle_merge_result(app: &mut App, result: FetchResult) -> Option<Command> {
+    app.merging = false;
+    match result {
+        FetchResult::MergeSuccess(number) => {
+            app.clipboard_feedback = Some(format!("Merged #{}", number));
+            app.clipboard_feedback_time = std::time::Instant::now();
+            // Refresh even if a fetch is running, since it may have started before the merge
+            Some(refresh_command(app))
+        }
+        FetchResult::MergeError(e) => {
+            app.error = Some(e);
+            app.show_error_popup = true;
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Re-query just the selected PR's CI rollup instead of refetching the whole list
+fn refresh_selected_ci(app: &mut App) -> Option<Command> {
+    if app.refreshing_ci.is_some() {
+        return None;
+    }
+    let pr = app.selected_pr()?;
+    let cmd = Command::StartCiStatusFetch(pr.repo_owner.clone(), pr.repo_name.clone(), pr.number);
+    app.clipboard_feedback = Some(format!("Checking CI for #{}...", pr.number));
+    app.clipboard_feedback_time = std::time::Instant::now();
+    Some(cmd)
+}
+
+fn handle_checks_result(app: &mut App, result: FetchResult) {
+    match result {
+        FetchResult::ChecksSuccess(number, contexts) => {
+            // Drop results for a popup that was closed or reopened on another PR
+            if app.checks_pr != Some(number) {
+                return;
+            }
+            app.checks_loading = false;
+            app.checks = contexts;
+            if !app.checks.is_empty() {
+                app.checks_list_state.select(Some(0));
+            }
+        }
+        FetchResult::ChecksError(number, e) => {
+            if app.checks_pr != Some(number) {
+                return;
+            }
+            app.checks_loading = false;
+            app.show_checks_popup = false;
+            app.checks_pr = None;
+            app.error = Some(format!("Failed to fetch checks: {}", e));
+            app.show_error_popup = true;
+        }
+        _ => {}
+    }
+}
+
+/// Open the selected check's details or target URL
+fn open_check_url(app: &mut App) {
+    let Some(check) = app
+        .checks_list_state
+        .selected()
+        .and_then(|i| app.checks.get(i))
+    else {
+        return;
+    };
+    let Some(url) = check.url.clone() else {
+        app.clipboard_feedback = Some(format!("{} has no link", check.name));
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return;
+    };
+    if let Some(display_url) = open_url(&app.config, &url) {
+        app.show_url_popup = Some(display_url);
+    }
+}
+
+fn handle_ci_status_result(app: &mut App, result: FetchResult) {
+    app.refreshing_ci = None;
+    match result {
+        FetchResult::CiStatusSuccess(owner, repo, number, ci_status, head_sha) => {
+            // The PR can be in several lists; update every copy in place
+            let lists = [
+                &mut app.my_prs,
+                &mut app.review_prs,
+                &mut app.labels_prs,
+                &mut app.all_prs,
+                &mut app.search_prs,
+                &mut app.team_prs,
+                &mut app.recent_prs,
+            ];
+            for pr in lists
+                .into_iter()
+                .flatten()
+                .filter(|pr| pr.number == number && pr.repo_owner == owner && pr.repo_name == repo)
+            {
+                pr.ci_status = ci_status;
+                if head_sha.is_some() {
+                    pr.head_sha = head_sha.clone();
+                }
+            }
+            update_filtered_indices(app);
+            if app
+                .table_state
+                .selected()
+                .is_some_and(|i| i >= app.filtered_indices.len())
+            {
+                app.table_state
+                    .select(app.filtered_indices.len().checked_sub(1));
+            }
+            app.clipboard_feedback = Some(format!("#{} CI: {}", number, ci_status.to_str()));
+            app.clipboard_feedback_time = std::time::Instant::now();
+        }
+        FetchResult::CiStatusError(e) => {
+            app.error = Some(format!("Failed to refresh CI status: {}", e));
+            app.show_error_popup = true;
+        }
+        _ => {}
+    }
+}
+
+fn exit_search_mode(app: &mut App, clear_query: bool) -> Option<Command> {
+    app.search_mode = false;
+
+    // A leading ':' sends the rest of the query to GitHub as search qualifiers
+    if !clear_query {
+        if let Some(qualifiers) = app.search_query.value().strip_prefix(':') {
+            let qualifiers = qualifiers.trim().to_string();
+            app.search_query.clear();
+            if qualifiers.is_empty() {
+                update_filtered_indices(app);
+                return None;
+            }
+            return start_search(app, qualifiers);
+        }
+    }
+
+    if clear_query {
+        app.search_query.clear();
+        update_filtered_indices(app);
+        app.table_state = TableState::default();
+        if !app.filtered_indices.is_empty() {
+            app.table_state.select(Some(0));
+        }
+    }
+    None
+}
+
+/// Apply an edit to the search query, re-filtering only if the text changed
+fn edit_search(app: &mut App, edit: impl FnOnce(&mut TextInput)) {
+    let before = app.search_query.value().to_string();
+    edit(&mut app.search_query);
+    if app.search_query.value() == before {
+        return;
+    }
+    update_filtered_indices(app);
+    app.table_state = TableState::default();
+    if !app.filtered_indices.is_empty() {
+        app.table_state.select(Some(0));
+    }
+}
+
+/// Apply an edit to the labels popup search, re-filtering only if the text changed
+fn edit_labels_search(app: &mut App, edit: impl FnOnce(&mut TextInput)) {
+    let before = app.labels_search_query.value().to_string();
+    edit(&mut app.labels_search_query);
+    if app.labels_search_query.value() != before {
+        update_labels_filtered_indices(app);
+    }
+}
+
+/// Insert pasted text into whichever text input is focused. Inputs are
+/// single-line, so line breaks are dropped.
+fn paste(app: &mut App, text: &str) {
+    let text: String = text.chars().filter(|&c| c != '\n' && c != '\r').collect();
+    if app.show_add_label_popup {
+        app.label_input.insert_str(&text);
+    } else if app.show_add_saved_search_popup {
+        if app.saved_search_editing_query {
+            app.saved_search_query_input.insert_str(&text);
+        } else {
+            app.saved_search_name_input.insert_str(&text);
+        }
+    } else if app.show_labels_popup && app.labels_search_mode {
+        edit_labels_search(app, |input| input.insert_str(&text));
+    } else if app.search_mode {
+        edit_search(app, |input| input.insert_str(&text));
+    }
+}
+
+fn open_labels_popup(app: &mut App) {
+    app.show_labels_popup = true;
+    app.labels_search_mode = false;
+    app.labels_search_query.clear();
+    update_labels_filtered_indices(app);
+}
+
+/// Re-filter the labels popup by the search query and reset the selection.
+/// Repo labels are listed before global ones, matching how the popup renders them.
+fn update_labels_filtered_indices(app: &mut App) {
+    let (repo, global): (Vec<usize>, Vec<usize>) =
+        filter_labels(&app.configured_labels, app.labels_search_query.value())
+            .into_iter()
+            .partition(|&idx| !app.configured_labels[idx].is_global());
+    app.labels_filtered_indices = repo.into_iter().chain(global).collect();
+
+    app.labels_list_state = TableState::default();
+    if !app.labels_filtered_indices.is_empty() {
+        app.labels_list_state.select(Some(0));
+    }
+}
+
+fn add_label(app: &mut App) -> Option<Command> {
+    let label_name = app.label_input.value().trim().to_string();
+    if label_name.is_empty() {
+        return None;
+    }
+
+    let (owner, repo) = if app.label_scope_global {
+        (None, None)
+    } else {
+        (app.repo_owner.as_deref(), app.repo_name.as_deref())
+    };
+
+    if let Err(e) = save_label_filter(&label_name, owner, repo) {
         app.error = Some(format!("Failed to save label: {}", e));
         app.show_error_popup = true;
         return None;
@@ -472,6 +1575,7 @@ fn add_label(app: &mut App) -> Option<Command> {
     // Reload labels
     reload_labels(app);
     app.show_add_label_popup = false;
+    app.label_input.push_history(&label_name);
     app.label_input.clear();
 
     // Refresh labels PR list if we're on that tab
@@ -483,9 +1587,36 @@ fn add_label(app: &mut App) -> Option<Command> {
     None
 }
 
+/// Show only the selected label's PRs on the Labels tab; choosing the focused
+/// label again goes back to showing every configured label
+fn focus_selected_label(app: &mut App) -> Option<Command> {
+    let selected = app.labels_list_state.selected()?;
+    let name = app.visible_label(selected)?.label_name.clone();
+
+    let feedback = if app.active_focused_label() == Some(name.as_str()) {
+        app.focused_label = None;
+        "Showing all labels".to_string()
+    } else {
+        let feedback = format!("Showing only '{}'", name);
+        app.focused_label = Some(name);
+        feedback
+    };
+    app.clipboard_feedback = Some(feedback);
+    app.clipboard_feedback_time = std::time::Instant::now();
+
+    // The previous results were for a different label set
+    let filter = PrFilter::Labels(app.get_active_labels());
+    app.show_labels_popup = false;
+    app.labels_prs.clear();
+    app.rebuild_all_prs();
+    app.pr_filter = filter.clone();
+    reset_list_view(app);
+    Some(Command::StartFetch(filter))
+}
+
 fn delete_selected_label(app: &mut App) -> Option<Command> {
     if let Some(selected) = app.labels_list_state.selected() {
-        if let Some(label) = app.configured_labels.get(selected) {
+        if let Some(label) = app.visible_label(selected) {
             let id = label.id;
             if let Err(e) = delete_label_filter(id) {
                 app.error = Some(format!("Failed to delete label: {}", e));
@@ -497,11 +1628,11 @@ fn delete_selected_label(app: &mut App) -> Option<Command> {
             reload_labels(app);
 
             // Adjust selection
-            if app.configured_labels.is_empty() {
+            if app.labels_filtered_indices.is_empty() {
                 app.labels_list_state.select(None);
-            } else if selected >= app.configured_labels.len() {
-                app.labels_list_state
-                    .select(Some(app.configured_labels.len() - 1));
+            } else {
+                let last = app.labels_filtered_indices.len() - 1;
+                app.labels_list_state.select(Some(selected.min(last)));
             }
 
             // Refresh labels PR list if we're on that tab
@@ -514,41 +1645,149 @@ fn delete_selected_label(app: &mut App) -> Option<Command> {
     None
 }
 
-fn reload_labels(app: &mut App) {
+fn activate_saved_search(app: &mut App) -> Option<Command> {
+    let selected = app.saved_searches_list_state.selected()?;
+    let query = app.saved_searches.get(selected)?.query.clone();
+
+    app.show_saved_searches_popup = false;
+    start_search(app, query)
+}
+
+/// Switch to the results of a server-side search, showing cached results for the
+/// same query (if any) while the fresh fetch runs
+fn start_search(app: &mut App, query: String) -> Option<Command> {
+    let filter = PrFilter::Search(query);
+    app.search_prs = match (&app.repo_owner, &app.repo_name) {
+        (Some(owner), Some(repo)) => {
+            load_cache_for_display(owner, repo, filter.clone()).unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    app.pr_filter = filter.clone();
+    reset_list_view(app);
+    Some(Command::StartFetch(filter))
+}
+
+/// Show the next configured team's review requests, starting with the first
+fn next_team_tab(app: &mut App) -> Option<Command> {
+    let teams = app.config.valid_teams();
+    if teams.is_empty() {
+        app.clipboard_feedback = Some("No teams configured (add teams to config.toml)".to_string());
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return None;
+    }
+
+    let next = match &app.pr_filter {
+        PrFilter::Team(current) => teams
+            .iter()
+            .position(|team| team == current)
+            .map_or(0, |i| (i + 1) % teams.len()),
+        _ => 0,
+    };
+    let filter = PrFilter::Team(teams[next].clone());
+    app.pr_sort = app.config.sort.for_filter(&filter);
+    app.team_prs = match (&app.repo_owner, &app.repo_name) {
+        (Some(owner), Some(repo)) => {
+            load_cache_for_display(owner, repo, filter.clone()).unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    app.pr_filter = filter.clone();
+    reset_list_view(app);
+    Some(Command::StartFetch(filter))
+}
+
+fn add_saved_search(app: &mut App) {
+    let name = app.saved_search_name_input.value().trim().to_string();
+    let query = app.saved_search_query_input.value().trim().to_string();
+    if name.is_empty() || query.is_empty() {
+        return;
+    }
+
+    let (Some(owner), Some(repo)) = (app.repo_owner.clone(), app.repo_name.clone()) else {
+        app.error = Some("Saved searches need a GitHub repository".to_string());
+        app.show_error_popup = true;
+        return;
+    };
+
+    if let Err(e) = save_saved_search(&name, &query, &owner, &repo) {
+        app.error = Some(format!("Failed to save search: {}", e));
+        app.show_error_popup = true;
+        return;
+    }
+
+    reload_saved_searches(app);
+    app.show_add_saved_search_popup = false;
+    if let Some(pos) = app.saved_searches.iter().position(|s| s.name == name) {
+        app.saved_searches_list_state.select(Some(pos));
+    }
+}
+
+fn delete_selected_saved_search(app: &mut App) {
+    let Some(selected) = app.saved_searches_list_state.selected() else {
+        return;
+    };
+    let Some(search) = app.saved_searches.get(selected) else {
+        return;
+    };
+
+    if let Err(e) = delete_saved_search(search.id) {
+        app.error = Some(format!("Failed to delete search: {}", e));
+        app.show_error_popup = true;
+        return;
+    }
+
+    reload_saved_searches(app);
+    if app.saved_searches.is_empty() {
+        app.saved_searches_list_state.select(None);
+    } else {
+        let last = app.saved_searches.len() - 1;
+        app.saved_searches_list_state
+            .select(Some(selected.min(last)));
+    }
+}
+
+fn reload_saved_searches(app: &mut App) {
     if let (Some(owner), Some(repo)) = (&app.repo_owner, &app.repo_name) {
-        app.configured_labels = load_label_filters(owner, repo).unwrap_or_default();
+        app.saved_searches = load_saved_searches(owner, repo).unwrap_or_default();
     }
 }
 
+fn reload_labels(app: &mut App) {
+    app.configured_labels = match (&app.repo_owner, &app.repo_name) {
+        (Some(owner), Some(repo)) => load_label_filters(owner, repo),
+        // Outside a repo only global labels can be managed
+        _ => load_global_label_filters(),
+    }
+    .unwrap_or_default();
+    update_labels_filtered_indices(app);
+}
+
 fn labels_next(app: &mut App) {
-    if app.configured_labels.is_empty() {
+    if app.labels_filtered_indices.is_empty() {
         return;
     }
     let i = match app.labels_list_state.selected() {
-        Some(i) => {
-            if i >= app.configured_labels.len() - 1 {
-                i
-            } else {
-                i + 1
-            }
-        }
+        Some(i) => step_next(
+            i,
+            app.labels_filtered_indices.len(),
+            app.config.wrap_navigation,
+        ),
         None => 0,
     };
     app.labels_list_state.select(Some(i));
 }
 
 fn labels_previous(app: &mut App) {
-    if app.configured_labels.is_empty() {
+    if app.labels_filtered_indices.is_empty() {
         return;
     }
     let i = match app.labels_list_state.selected() {
-        Some(i) => {
-            if i == 0 {
-                0
-            } else {
-                i - 1
-            }
-        }
+        Some(i) => step_previous(
+            i,
+            app.labels_filtered_indices.len(),
+            app.config.wrap_navigation,
+        ),
         None => 0,
     };
     app.labels_list_state.select(Some(i));
@@ -557,12 +1796,23 @@ fn labels_previous(app: &mut App) {
 fn handle_fetch_result(app: &mut App, result: FetchResult) -> Option<Command> {
     match result {
         FetchResult::Success(new_prs, filter) => {
-            let is_current_filter = matches!(
-                (&app.pr_filter, &filter),
-                (PrFilter::MyPrs, PrFilter::MyPrs)
-                    | (PrFilter::ReviewRequested, PrFilter::ReviewRequested)
-                    | (PrFilter::Labels(_), PrFilter::Labels(_))
-            );
+            app.offline = false;
+            app.network_failures = 0;
+            app.poll_backoff = 1;
+            let is_current_filter = match (&app.pr_filter, &filter) {
+                (PrFilter::Search(current), PrFilter::Search(fetched)) => current == fetched,
+                (_, PrFilter::Search(_)) => false,
+                (PrFilter::Team(current), PrFilter::Team(fetched)) => current == fetched,
+                (_, PrFilter::Team(_)) => false,
+                (PrFilter::All, _) => true,
+                (current, fetched) => matches!(
+                    (current, fetched),
+                    (PrFilter::MyPrs, PrFilter::MyPrs)
+                        | (PrFilter::ReviewRequested, PrFilter::ReviewRequested)
+                        | (PrFilter::Labels(_), PrFilter::Labels(_))
+                        | (PrFilter::Recent, PrFilter::Recent)
+                ),
+            };
 
             // Check if we're waiting for a PR's head_sha for the actions popup
             let pending_pr_number = app.actions_pending_pr_number;
@@ -581,6 +1831,16 @@ fn handle_fetch_result(app: &mut App, result: FetchResult) -> Option<Command> {
                             pr.number,
                             head_sha.clone(),
                         ));
+                    } else if pr.ci_status == CiStatus::NoCommits {
+                        // Stop waiting for a head SHA that doesn't exist
+                        app.actions_pending_pr_number = None;
+                        app.actions_loading = false;
+                        app.actions_data = Some(ActionsData {
+                            pr_number,
+                            head_sha: None,
+                            workflow_runs: Vec::new(),
+                            error: Some("This PR has no commits yet".to_string()),
+                        });
                     }
                 }
             }
@@ -589,16 +1849,42 @@ fn handle_fetch_result(app: &mut App, result: FetchResult) -> Option<Command> {
                 PrFilter::MyPrs => {
                     app.my_prs = new_prs;
                     app.loading_my_prs = false;
+                    app.my_prs_fetched = true;
                 }
                 PrFilter::ReviewRequested => {
                     app.review_prs = new_prs;
                     app.loading_review_prs = false;
+                    app.review_prs_fetched = true;
                 }
                 PrFilter::Labels(_) => {
                     app.labels_prs = new_prs;
                     app.loading_labels_prs = false;
+                    app.labels_fetched = true;
+                }
+                PrFilter::Search(_) => {
+                    // Drop results for a query the user has since replaced
+                    if !is_current_filter {
+                        return actions_command;
+                    }
+                    app.search_prs = new_prs;
+                    app.loading_search_prs = false;
+                }
+                PrFilter::Team(_) => {
+                    // Drop results for a team the user has since switched away from
+                    if !is_current_filter {
+                        return actions_command;
+                    }
+                    app.team_prs = new_prs;
+                    app.loading_team_prs = false;
+                }
+                PrFilter::Recent => {
+                    app.recent_prs = new_prs;
+                    app.loading_recent_prs = false;
+                    app.recent_fetched = true;
                 }
+                PrFilter::All => {}
             }
+            app.rebuild_all_prs();
 
             // Update filtered indices if viewing this filter
             if is_current_filter {
@@ -610,23 +1896,70 @@ fn handle_fetch_result(app: &mut App, result: FetchResult) -> Option<Command> {
 
             actions_command
         }
+        FetchResult::Warning(warning) => {
+            app.clipboard_feedback = Some(warning);
+            app.clipboard_feedback_time = std::time::Instant::now();
+            None
+        }
+        FetchResult::NetworkError(_, filter) => {
+            // Show the offline banner instead of popping an error on every refresh
+            if app.actions_pending_pr_number.is_some() {
+                app.actions_pending_pr_number = None;
+                app.actions_loading = false;
+            }
+            app.network_failures += 1;
+            if app.network_failures >= OFFLINE_AFTER_FAILURES {
+                app.offline = true;
+            }
+            // Other tabs' fetches may still be in flight
+            app.finish_fetch(&filter);
+            None
+        }
+        FetchResult::RateLimited(_, filter) => {
+            // Poll less often until a refresh gets through, rather than erroring every time
+            if app.actions_pending_pr_number.is_some() {
+                app.actions_pending_pr_number = None;
+                app.actions_loading = false;
+            }
+            app.poll_backoff = (app.poll_backoff * 2).min(MAX_POLL_BACKOFF);
+            // Other tabs' fetches may still be in flight
+            app.finish_fetch(&filter);
+            let interval = app.config.refresh_interval() * app.poll_backoff;
+            app.clipboard_feedback = Some(format!(
+                "GitHub rate limit reached; refreshing every {}s",
+                interval.as_secs()
+            ));
+            app.clipboard_feedback_time = std::time::Instant::now();
+            None
+        }
         FetchResult::Error(e) => {
             // If we were waiting for actions, clear the pending state
             if app.actions_pending_pr_number.is_some() {
                 app.actions_pending_pr_number = None;
                 app.actions_loading = false;
             }
+            app.fetch_error = Some(e.clone());
             app.error = Some(e);
             app.show_error_popup = true;
             app.loading_my_prs = false;
             app.loading_review_prs = false;
             app.loading_labels_prs = false;
+            app.loading_search_prs = false;
+            app.loading_team_prs = false;
+            app.loading_recent_prs = false;
             None
         }
-        // Handled separately by handle_actions_result, handle_job_logs_result, handle_preview_result
+        // Handled separately by handle_actions_result, handle_job_logs_result,
+        // handle_annotations_result, handle_preview_result, handle_merge_result,
+        // handle_ci_status_result, handle_checks_result
         FetchResult::ActionsSuccess(_) | FetchResult::ActionsError(_) => None,
         FetchResult::JobLogsSuccess(_) | FetchResult::JobLogsError(_) => None,
+        FetchResult::AnnotationsSuccess(..) | FetchResult::AnnotationsError(..) => None,
         FetchResult::PreviewSuccess(_) | FetchResult::PreviewError(_) => None,
+        FetchResult::MergeSuccess(_) | FetchResult::MergeError(_) => None,
+        FetchResult::CiStatusSuccess(..) | FetchResult::CiStatusError(_) => None,
+        FetchResult::ChecksSuccess(..) | FetchResult::ChecksError(..) => None,
+        FetchResult::RunActionSuccess(_) | FetchResult::RunActionError(_) => None,
     }
 }
 
@@ -641,13 +1974,22 @@ fn open_workflows_view(app: &mut App) -> Option<Command> {
             pr.number,
             pr.title.clone(),
             pr.head_sha.clone(),
+            pr.ci_status,
         )
     });
 
-    if let Some((owner, repo, number, title, head_sha_opt)) = pr_data {
+    if let Some((owner, repo, number, title, head_sha_opt, ci_status)) = pr_data {
+        // There's nothing to run checks on, and no head SHA will ever arrive
+        if ci_status == CiStatus::NoCommits {
+            app.clipboard_feedback = Some(format!("#{} has no commits yet", number));
+            app.clipboard_feedback_time = std::time::Instant::now();
+            return None;
+        }
+
         app.show_workflows_view = true;
         app.actions_loading = true;
         app.selected_job_index = 0;
+        app.job_summary_expanded = false;
         app.actions_data = None;
         app.workflows_pr_info = Some((title, number));
         app.show_job_logs = false;
@@ -675,6 +2017,7 @@ fn close_workflows_view(app: &mut App) {
     app.actions_data = None;
     app.actions_loading = false;
     app.selected_job_index = 0;
+    app.job_summary_expanded = false;
     app.actions_pending_pr_number = None;
     app.workflows_pr_info = None;
     app.show_job_logs = false;
@@ -697,7 +2040,7 @@ fn handle_actions_result(app: &mut App, result: FetchResult) {
             // Find the first failed job and select it
             let mut first_failed_index: Option<usize> = None;
             let mut current_idx = 0;
-            for run in &data.workflow_runs {
+            for run in data.visible_runs(app.workflows_provider_filter) {
                 for job in &run.jobs {
                     if matches!(
                         job.conclusion,
@@ -754,55 +2097,306 @@ fn refresh_actions(app: &mut App) -> Option<Command> {
     None
 }
 
-fn actions_next_job(app: &mut App) {
-    if let Some(ref data) = app.actions_data {
-        let total_jobs: usize = data.workflow_runs.iter().map(|r| r.jobs.len()).sum();
-        if app.selected_job_index < total_jobs.saturating_sub(1) {
-            app.selected_job_index += 1;
-        }
+fn visible_job_count(app: &App) -> usize {
+    app.actions_data.as_ref().map_or(0, |data| {
+        data.visible_runs(app.workflows_provider_filter)
+            .map(|r| r.jobs.len())
+            .sum()
+    })
+}
+
+fn actions_next_job(app: &mut App) {
+    let total_jobs = visible_job_count(app);
+    if total_jobs == 0 {
+        return;
+    }
+    let next = step_next(
+        app.selected_job_index,
+        total_jobs,
+        app.config.wrap_navigation,
+    );
+    if next != app.selected_job_index {
+        app.selected_job_index = next;
+        app.job_summary_expanded = false;
+    }
+}
+
+/// Cycle the provider filter; the selection resets since job indices shift
+fn cycle_provider_filter(app: &mut App) {
+    app.workflows_provider_filter = app.workflows_provider_filter.next();
+    app.selected_job_index = 0;
+    app.job_summary_expanded = false;
+}
+
+fn actions_previous_job(app: &mut App) {
+    let total_jobs = visible_job_count(app);
+    if total_jobs == 0 {
+        return;
+    }
+    let previous = step_previous(
+        app.selected_job_index,
+        total_jobs,
+        app.config.wrap_navigation,
+    );
+    if previous != app.selected_job_index {
+        app.selected_job_index = previous;
+        app.job_summary_expanded = false;
+    }
+}
+
+fn open_actions_in_browser(app: &mut App) {
+    if let Some(url) = selected_job_url(app) {
+        if let Some(display_url) = open_url(&app.config, &url) {
+            app.show_url_popup = Some(display_url);
+        }
+    }
+}
+
+/// Copy the link `o` would open: the selected job's details URL, or its run's
+fn copy_run_url(app: &mut App) {
+    let Some(url) = selected_job_url(app) else {
+        return;
+    };
+    let feedback = if copy_to_clipboard(&url) {
+        "Copied run URL".to_string()
+    } else {
+        "Failed to copy to clipboard".to_string()
+    };
+    app.clipboard_feedback = Some(feedback);
+    app.clipboard_feedback_time = std::time::Instant::now();
+}
+
+/// The selected job's details URL, falling back to its run's page, or the
+/// first run's when nothing is selected
+fn selected_job_url(app: &App) -> Option<String> {
+    if let Some(ref data) = app.actions_data {
+        // Find the currently selected job and open its details URL if available
+        let mut current_idx = 0;
+        let mut found_url: Option<String> = None;
+        'outer: for run in data.visible_runs(app.workflows_provider_filter) {
+            for job in &run.jobs {
+                if current_idx == app.selected_job_index {
+                    // Try job-specific URL first, then fall back to run URL
+                    if let Some(ref url) = job.details_url {
+                        found_url = Some(url.clone());
+                    } else if !run.html_url.is_empty() {
+                        found_url = Some(run.html_url.clone());
+                    }
+                    break 'outer;
+                }
+                current_idx += 1;
+            }
+        }
+        // If no jobs or selection is out of range, open the first run
+        found_url.or_else(|| {
+            data.visible_runs(app.workflows_provider_filter)
+                .next()
+                .filter(|run| !run.html_url.is_empty())
+                .map(|run| run.html_url.clone())
+        })
+    } else {
+        None
+    }
+}
+
+/// The run containing the selected job
+fn selected_run(app: &App) -> Option<&WorkflowRun> {
+    let data = app.actions_data.as_ref()?;
+    let mut first_job_idx = 0;
+    for run in data.visible_runs(app.workflows_provider_filter) {
+        if app.selected_job_index < first_job_idx + run.jobs.len() {
+            return Some(run);
+        }
+        first_job_idx += run.jobs.len();
+    }
+    None
+}
+
+/// The selected job's run and how to address it for a re-run or cancel,
+/// or a message explaining why its provider can't be acted on
+fn selected_run_target(app: &App) -> Result<(&WorkflowRun, RunTarget), &'static str> {
+    let run = selected_run(app).ok_or("No workflow run selected")?;
+    let target = match run.provider {
+        CiProvider::GitHubActions => RunTarget::Actions {
+            run_id: run
+                .id
+                .ok_or("GitHub didn't report this run's ID; open it in the browser")?,
+        },
+        CiProvider::CircleCi if !is_circleci_configured() => {
+            return Err("Set CIRCLECI_TOKEN to manage CircleCI workflows")
+        }
+        CiProvider::CircleCi => get_selected_job(app)
+            .and_then(|(_, _, job)| job.details_url)
+            .and_then(|url| extract_job_number_from_url(&url))
+            .map(|job_number| RunTarget::CircleCi { job_number })
+            .ok_or("Can't find the CircleCI job for this workflow")?,
+        CiProvider::Other => return Err("Re-running and cancelling aren't supported here"),
+    };
+    Ok((run, target))
+}
+
+/// Ask to re-run the selected job's workflow, if its provider supports it
+fn prompt_rerun(app: &mut App) -> Option<Command> {
+    match selected_run_target(app) {
+        Ok((run, target)) => app.pending_rerun = Some((run.name.clone(), target)),
+        Err(msg) => {
+            app.clipboard_feedback = Some(msg.to_string());
+            app.clipboard_feedback_time = std::time::Instant::now();
+            return None;
+        }
+    }
+    // Without the prompt there's no choice to make; take the cheaper one
+    if !app.config.confirm_destructive.rerun {
+        return confirm_rerun(app, true);
+    }
+    None
+}
+
+/// Ask to cancel the selected job's workflow, if it's still running
+fn prompt_cancel_run(app: &mut App) -> Option<Command> {
+    let prompt = selected_run_target(app).and_then(|(run, target)| {
+        if is_cancelable(run.status) {
+            Ok((run.name.clone(), target))
+        } else {
+            Err("This workflow isn't running")
+        }
+    });
+    let run_name = match prompt {
+        Ok((run_name, target)) => {
+            app.pending_cancel = Some((run_name.clone(), target));
+            run_name
+        }
+        Err(msg) => {
+            app.clipboard_feedback = Some(msg.to_string());
+            app.clipboard_feedback_time = std::time::Instant::now();
+            return None;
+        }
+    };
+    if !app.config.confirm_destructive.cancel_run {
+        return confirm_cancel_run(app);
+    }
+    app.confirmation = Some(Confirmation {
+        title: "Cancel Run".to_string(),
+        lines: vec![Line::from(format!("Cancel {}?", run_name))],
+        on_confirm: Message::ConfirmCancelRun,
+        danger: true,
+    });
+    None
+}
+
+fn is_cancelable(status: WorkflowStatus) -> bool {
+    matches!(
+        status,
+        WorkflowStatus::InProgress
+            | WorkflowStatus::Queued
+            | WorkflowStatus::Pending
+            | WorkflowStatus::Waiting
+            | WorkflowStatus::Requested
+    )
+}
+
+/// Whether `run` is the one a re-run or cancel of `target` applies to. Runs
+/// can share a name, e.g. the same workflow triggered by push and pull_request.
+fn is_target_run(run: &WorkflowRun, target: RunTarget) -> bool {
+    match target {
+        RunTarget::Actions { run_id } => run.id == Some(run_id),
+        RunTarget::CircleCi { job_number } => run.jobs.iter().any(|job| {
+            job.details_url
+                .as_deref()
+                .and_then(extract_job_number_from_url)
+                == Some(job_number)
+        }),
     }
 }
 
-fn actions_previous_job(app: &mut App) {
-    if app.selected_job_index > 0 {
-        app.selected_job_index -= 1;
+/// Trigger the cancel and optimistically mark unfinished jobs as cancelled
+fn confirm_cancel_run(app: &mut App) -> Option<Command> {
+    let (_, target) = app.pending_cancel.take()?;
+    let (owner, repo) = app
+        .selected_pr()
+        .map(|pr| (pr.repo_owner.clone(), pr.repo_name.clone()))?;
+
+    if let Some(ref mut data) = app.actions_data {
+        for run in data
+            .workflow_runs
+            .iter_mut()
+            .filter(|r| is_target_run(r, target))
+        {
+            run.status = WorkflowStatus::Completed;
+            run.conclusion = Some(WorkflowConclusion::Cancelled);
+            for job in run.jobs.iter_mut().filter(|job| is_cancelable(job.status)) {
+                job.status = WorkflowStatus::Completed;
+                job.conclusion = Some(WorkflowConclusion::Cancelled);
+            }
+        }
     }
+
+    Some(Command::StartRunAction(
+        owner,
+        repo,
+        target,
+        RunAction::Cancel,
+    ))
 }
 
-fn open_actions_in_browser(app: &mut App) {
-    let url_to_open = if let Some(ref data) = app.actions_data {
-        // Find the currently selected job and open its details URL if available
-        let mut current_idx = 0;
-        let mut found_url: Option<String> = None;
-        'outer: for run in &data.workflow_runs {
-            for job in &run.jobs {
-                if current_idx == app.selected_job_index {
-                    // Try job-specific URL first, then fall back to run URL
-                    if let Some(ref url) = job.details_url {
-                        found_url = Some(url.clone());
-                    } else if !run.html_url.is_empty() {
-                        found_url = Some(run.html_url.clone());
-                    }
-                    break 'outer;
-                }
-                current_idx += 1;
+/// Trigger the re-run and optimistically mark the affected jobs as queued
+fn confirm_rerun(app: &mut App, failed_only: bool) -> Option<Command> {
+    let (_, target) = app.pending_rerun.take()?;
+    let (owner, repo) = app
+        .selected_pr()
+        .map(|pr| (pr.repo_owner.clone(), pr.repo_name.clone()))?;
+
+    if let Some(ref mut data) = app.actions_data {
+        for run in data
+            .workflow_runs
+            .iter_mut()
+            .filter(|r| is_target_run(r, target))
+        {
+            run.status = WorkflowStatus::Queued;
+            run.conclusion = None;
+            for job in run.jobs.iter_mut().filter(|job| {
+                !failed_only
+                    || matches!(
+                        job.conclusion,
+                        Some(WorkflowConclusion::Failure)
+                            | Some(WorkflowConclusion::Cancelled)
+                            | Some(WorkflowConclusion::TimedOut)
+                            | Some(WorkflowConclusion::StartupFailure)
+                    )
+            }) {
+                job.status = WorkflowStatus::Queued;
+                job.conclusion = None;
             }
         }
-        // If no jobs or selection is out of range, open the first run
-        found_url.or_else(|| {
-            data.workflow_runs
-                .first()
-                .filter(|run| !run.html_url.is_empty())
-                .map(|run| run.html_url.clone())
-        })
+    }
+    app.actions_poll_enabled = true;
+
+    let action = if failed_only {
+        RunAction::RerunFailed
     } else {
-        None
+        RunAction::Rerun
     };
+    Some(Command::StartRunAction(owner, repo, target, action))
+}
 
-    if let Some(url) = url_to_open {
-        if let Some(display_url) = open_url(&url) {
-            app.show_url_popup = Some(display_url);
+fn handle_run_action_result(app: &mut App, result: FetchResult) -> Option<Command> {
+    match result {
+        FetchResult::RunActionSuccess(action) => {
+            let feedback = match action {
+                RunAction::Rerun | RunAction::RerunFailed => "Re-run requested",
+                RunAction::Cancel => "Cancel requested",
+            };
+            app.clipboard_feedback = Some(feedback.to_string());
+            app.clipboard_feedback_time = std::time::Instant::now();
+            None
+        }
+        FetchResult::RunActionError(e) => {
+            app.error = Some(e);
+            app.show_error_popup = true;
+            // Undo the optimistic status change
+            refresh_actions(app)
         }
+        _ => None,
     }
 }
 
@@ -816,7 +2410,7 @@ fn get_selected_job(app: &App) -> Option<(String, String, WorkflowJob)> {
 
     if let Some(ref data) = app.actions_data {
         let mut current_idx = 0;
-        for run in &data.workflow_runs {
+        for run in data.visible_runs(app.workflows_provider_filter) {
             for job in &run.jobs {
                 if current_idx == app.selected_job_index {
                     return Some((owner, repo, job.clone()));
@@ -859,9 +2453,9 @@ fn format_annotations(
         content.push_str("=== Annotations ===\n\n");
         for ann in annotations {
             let level_str = match ann.level {
-                AnnotationLevel::Failure => icons::ANNOTATION_FAILURE_LABEL,
-                AnnotationLevel::Warning => icons::ANNOTATION_WARNING_LABEL,
-                AnnotationLevel::Notice => icons::ANNOTATION_NOTICE_LABEL,
+                AnnotationLevel::Failure => icons::annotation_failure_label(),
+                AnnotationLevel::Warning => icons::annotation_warning_label(),
+                AnnotationLevel::Notice => icons::annotation_notice_label(),
             };
 
             let line_range = if ann.start_line == ann.end_line {
@@ -873,14 +2467,14 @@ fn format_annotations(
             content.push_str(&format!("{}\n", level_str));
             content.push_str(&format!(
                 "  {} {}{}\n",
-                icons::EMOJI_FILE,
+                icons::emoji_file(),
                 ann.path,
                 line_range
             ));
             if let Some(ref title) = ann.title {
-                content.push_str(&format!("  {} {}\n", icons::EMOJI_PIN, title));
+                content.push_str(&format!("  {} {}\n", icons::emoji_pin(), title));
             }
-            content.push_str(&format!("  {} {}\n\n", icons::EMOJI_MESSAGE, ann.message));
+            content.push_str(&format!("  {} {}\n\n", icons::emoji_message(), ann.message));
         }
     }
 
@@ -892,15 +2486,32 @@ fn format_annotations(
 }
 
 fn open_job_logs(app: &mut App) -> Option<Command> {
-    if let Some((owner, repo, job)) = get_selected_job(app) {
+    if let Some((owner, repo, mut job)) = get_selected_job(app) {
         app.show_job_logs = true;
         app.job_logs_scroll = 0;
 
-        // Check if we have annotations from GraphQL (reviewdog, etc.)
+        // Annotations are fetched lazily the first time a job is opened, then cached
+        if job.annotations.is_empty() && job.annotations_count > 0 {
+            match app.job_annotations_cache.get(&job.id) {
+                Some(cached) => job.annotations = cached.clone(),
+                None => {
+                    app.annotations_view = false;
+                    app.annotations.clear();
+                    app.job_logs = None;
+                    app.job_logs_loading = true;
+                    return Some(Command::StartAnnotationsFetch(owner, repo, job.id));
+                }
+            }
+        }
+
+        // Check if we have annotations (reviewdog, etc.)
         if !job.annotations.is_empty() {
             // Use annotations view for structured display
             app.annotations_view = true;
             app.annotations = job.annotations.clone();
+            app.annotations_total = job.annotations_count.max(job.annotations.len());
+            app.annotations_unsorted = job.annotations.clone();
+            app.annotations_sort = AnnotationSort::Original;
             app.selected_annotation_index = 0;
             app.job_logs = Some(JobLogs {
                 job_id: job.id,
@@ -930,7 +2541,7 @@ fn open_job_logs(app: &mut App) -> Option<Command> {
                 job_name: job.name.clone(),
                 content: format!(
                     "{} No issues found.\n\nPress 'o' to view details in browser.",
-                    icons::STATUS_SUCCESS
+                    icons::status_success()
                 ),
                 steps: None,
                 test_results: None,
@@ -962,74 +2573,106 @@ fn open_job_logs(app: &mut App) -> Option<Command> {
         // No annotations or summary, fetch logs
         app.annotations_view = false;
         app.annotations.clear();
-        app.job_logs_loading = true;
-        app.job_logs = None;
+        return fetch_raw_job_logs(app, owner, repo, job);
+    }
+    None
+}
+
+/// Switch between a job's annotations and its raw logs, fetching the logs the first time
+fn toggle_annotations_view(app: &mut App) -> Option<Command> {
+    if app.annotations.is_empty() {
+        return None;
+    }
+    app.annotations_view = !app.annotations_view;
+    if app.annotations_view || app.job_logs_loading {
+        return None;
+    }
+    // The annotations view leaves an empty placeholder in place of the logs
+    let has_logs = app
+        .job_logs
+        .as_ref()
+        .is_some_and(|logs| !logs.content.is_empty() || logs.steps.is_some());
+    if has_logs {
+        return None;
+    }
+    let (owner, repo, job) = get_selected_job(app)?;
+    fetch_raw_job_logs(app, owner, repo, job)
+}
 
-        debug_log("========================================");
+/// Start fetching a job's logs from CircleCI or GitHub
+fn fetch_raw_job_logs(
+    app: &mut App,
+    owner: String,
+    repo: String,
+    job: WorkflowJob,
+) -> Option<Command> {
+    app.job_logs_loading = true;
+    app.job_logs = None;
+
+    debug_log("========================================");
+    debug_log(&format!(
+        "fetch_raw_job_logs: job.name={}, job.id={}, details_url={:?}",
+        job.name, job.id, job.details_url
+    ));
+
+    // Check if this is a CircleCI job
+    if let Some(ref details_url) = job.details_url {
+        debug_log(&format!("  Checking if CircleCI URL: {}", details_url));
         debug_log(&format!(
-            "open_job_logs: job.name={}, job.id={}, details_url={:?}",
-            job.name, job.id, job.details_url
+            "  is_circleci_url={}, is_circleci_configured={}",
+            is_circleci_url(details_url),
+            is_circleci_configured()
         ));
 
-        // Check if this is a CircleCI job
-        if let Some(ref details_url) = job.details_url {
-            debug_log(&format!("  Checking if CircleCI URL: {}", details_url));
-            debug_log(&format!(
-                "  is_circleci_url={}, is_circleci_configured={}",
-                is_circleci_url(details_url),
-                is_circleci_configured()
-            ));
-
-            if is_circleci_url(details_url) {
-                // CircleCI job detected - check if token is configured
-                if !is_circleci_configured() {
-                    debug_log("  -> CircleCI job but no token configured");
-                    app.job_logs_loading = false;
-                    app.job_logs = Some(JobLogs {
-                        job_id: job.id,
-                        job_name: job.name.clone(),
-                        content: format!(
-                            "{} CircleCI Token Required\n\n\
-                            To view CircleCI job logs, set the CIRCLECI_TOKEN environment variable:\n\n\
-                            1. Go to CircleCI → User Settings → Personal API Tokens\n\
-                            2. Create a new token\n\
-                            3. Export it: export CIRCLECI_TOKEN=your_token\n\n\
-                            Press 'o' to open this job in your browser instead.",
-                            icons::STATUS_ACTION_REQUIRED
-                        ),
-                        steps: None,
-                        test_results: None,
-                    });
-                    return None;
-                }
+        if is_circleci_url(details_url) {
+            // CircleCI job detected - check if token is configured
+            if !is_circleci_configured() {
+                debug_log("  -> CircleCI job but no token configured");
+                app.job_logs_loading = false;
+                app.job_logs = Some(JobLogs {
+                    job_id: job.id,
+                    job_name: job.name.clone(),
+                    content: format!(
+                        "{} CircleCI Token Required\n\n\
+                        To view CircleCI job logs, set the CIRCLECI_TOKEN environment variable:\n\n\
+                        1. Go to CircleCI {arrow} User Settings {arrow} Personal API Tokens\n\
+                        2. Create a new token\n\
+                        3. Export it: export CIRCLECI_TOKEN=your_token\n\n\
+                        Press 'o' to open this job in your browser instead.",
+                        icons::status_action_required(),
+                        arrow = icons::arrow_right()
+                    ),
+                    steps: None,
+                    test_results: None,
+                });
+                return None;
+            }
 
-                let job_number = extract_job_number_from_url(details_url);
-                debug_log(&format!("  Extracted job_number: {:?}", job_number));
-
-                if let Some(job_number) = job_number {
-                    debug_log(&format!(
-                        "  -> Using CircleCI fetch for job_number={}",
-                        job_number
-                    ));
-                    return Some(Command::StartCircleCIJobLogsFetch(
-                        owner, repo, job_number, job.name,
-                    ));
-                } else {
-                    debug_log("  -> No job_number extracted, falling back to GitHub CLI");
-                }
+            let job_number = extract_job_number_from_url(details_url);
+            debug_log(&format!("  Extracted job_number: {:?}", job_number));
+
+            if let Some(job_number) = job_number {
+                debug_log(&format!(
+                    "  -> Using CircleCI fetch for job_number={}",
+                    job_number
+                ));
+                return Some(Command::StartCircleCIJobLogsFetch(
+                    owner, repo, job_number, job.name,
+                ));
+            } else {
+                debug_log("  -> No job_number extracted, falling back to GitHub CLI");
             }
-        } else {
-            debug_log("  No details_url, falling back to GitHub CLI");
         }
-
-        // Fall back to GitHub logs via gh CLI
-        debug_log(&format!(
-            "  -> Using GitHub CLI fetch for job.id={}",
-            job.id
-        ));
-        return Some(Command::StartJobLogsFetch(owner, repo, job.id, job.name));
+    } else {
+        debug_log("  No details_url, falling back to GitHub CLI");
     }
-    None
+
+    // Fall back to GitHub logs via gh CLI
+    debug_log(&format!(
+        "  -> Using GitHub CLI fetch for job.id={}",
+        job.id
+    ));
+    Some(Command::StartJobLogsFetch(owner, repo, job.id, job.name))
 }
 
 fn close_job_logs(app: &mut App) {
@@ -1043,6 +2686,8 @@ fn close_job_logs(app: &mut App) {
     app.job_logs_expanded_sub_steps.clear();
     app.annotations_view = false;
     app.annotations.clear();
+    app.annotations_total = 0;
+    app.annotations_unsorted.clear();
     app.selected_annotation_index = 0;
     app.selected_annotations.clear();
 }
@@ -1090,6 +2735,7 @@ fn handle_job_logs_result(app: &mut App, result: FetchResult) {
                 app.job_logs_selected_step = 0;
                 app.job_logs_selected_sub_step = None;
             }
+            app.job_logs_line_count = logs.content.lines().count();
             app.job_logs = Some(logs);
             app.job_logs_loading = false;
             app.job_logs_scroll = 0;
@@ -1103,6 +2749,56 @@ fn handle_job_logs_result(app: &mut App, result: FetchResult) {
     }
 }
 
+/// Jump to the first failed step, or in plain logs scroll to the first line that looks like an error
+fn jump_to_first_failure(app: &mut App) {
+    let Some(ref logs) = app.job_logs else {
+        return;
+    };
+    let found = match logs.steps {
+        Some(ref steps) => match steps.iter().position(|step| step.is_failed) {
+            Some(idx) => {
+                let failed_sub_step = steps[idx]
+                    .sub_steps
+                    .as_ref()
+                    .and_then(|sub_steps| sub_steps.iter().position(|s| s.is_failed));
+                if let Some(expanded) = app.job_logs_expanded_steps.get_mut(idx) {
+                    *expanded |= failed_sub_step.is_some();
+                }
+                app.job_logs_selected_step = idx;
+                app.job_logs_selected_sub_step = failed_sub_step;
+                true
+            }
+            None => false,
+        },
+        None => match logs.content.lines().position(is_failure_line) {
+            Some(line) => {
+                app.job_logs_scroll = u16::try_from(line).unwrap_or(u16::MAX);
+                true
+            }
+            None => false,
+        },
+    };
+    if !found {
+        app.clipboard_feedback = Some("No failures found in these logs".to_string());
+        app.clipboard_feedback_time = std::time::Instant::now();
+    }
+}
+
+/// Whether a log line looks like the error that failed the job
+fn is_failure_line(line: &str) -> bool {
+    if line.contains("FAILED") || line.contains('✗') || line.to_lowercase().contains("error") {
+        return true;
+    }
+    // "Process completed with exit code 1", "exited with exit code 2", ...
+    line.split_once("exit code").is_some_and(|(_, rest)| {
+        rest.trim_start_matches([' ', ':'])
+            .split(|c: char| !c.is_ascii_digit())
+            .next()
+            .and_then(|code| code.parse::<i32>().ok())
+            .is_some_and(|code| code != 0)
+    })
+}
+
 fn job_logs_next_step(app: &mut App) {
     if let Some(ref logs) = app.job_logs {
         if let Some(ref steps) = logs.steps {
@@ -1266,6 +2962,98 @@ fn open_step_in_editor(app: &mut App) -> Option<Command> {
     Some(Command::OpenInEditor(output, filename))
 }
 
+/// Format job logs for writing to a file, expanding structured steps when available
+fn format_job_logs_for_file(logs: &JobLogs) -> String {
+    let steps = match logs.steps {
+        Some(ref steps) if !steps.is_empty() => steps,
+        _ => return logs.content.clone(),
+    };
+
+    let mut content = String::new();
+    for step in steps {
+        content.push_str(&format!("=== {} [{}] ===\n", step.name, step.status));
+        if let Some(ref sub_steps) = step.sub_steps {
+            for sub_step in sub_steps {
+                content.push_str(&format!(
+                    "\n--- {} [{}] ---\n{}\n",
+                    sub_step.name, sub_step.status, sub_step.output
+                ));
+            }
+        } else {
+            content.push_str(&step.output);
+            content.push('\n');
+        }
+        content.push('\n');
+    }
+    content
+}
+
+/// Write `content` to a new `<name>.log` under the config dir's `logs/`, adding
+/// a counter rather than overwriting (or following a link at) an existing path
+fn write_new_log_file(name: &str, content: &str) -> std::io::Result<std::path::PathBuf> {
+    use std::io::Write;
+
+    let dir = get_config_dir()
+        .ok_or_else(|| std::io::Error::other("no config directory"))?
+        .join("logs");
+    std::fs::create_dir_all(&dir)?;
+
+    let mut n = 0;
+    loop {
+        let path = match n {
+            0 => dir.join(format!("{}.log", name)),
+            n => dir.join(format!("{}_{}.log", name, n)),
+        };
+        n += 1;
+        match std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(mut file) => {
+                file.write_all(content.as_bytes())?;
+                return Ok(path);
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => continue,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Save the current job logs under the config dir and open them in the pager
+fn save_job_logs(app: &mut App) -> Option<Command> {
+    let logs = app.job_logs.as_ref()?;
+
+    // Sanitize job name for filename
+    let safe_name: String = logs
+        .job_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    let content = format_job_logs_for_file(logs);
+    let file_name = format!("{}_{}", safe_name, logs.job_id);
+
+    let path = match write_new_log_file(&file_name, &content) {
+        Ok(path) => path,
+        Err(e) => {
+            app.error = Some(format!("Failed to save logs: {}", e));
+            app.show_error_popup = true;
+            return None;
+        }
+    };
+
+    let path = path.display().to_string();
+    app.clipboard_feedback = Some(format!("Saved logs to {}", path));
+    app.clipboard_feedback_time = std::time::Instant::now();
+    Some(Command::OpenInPager(path))
+}
+
 fn copy_job_logs_to_clipboard(app: &mut App) {
     if let Some(ref logs) = app.job_logs {
         if copy_to_clipboard(&logs.content) {
@@ -1397,14 +3185,83 @@ fn full_copy_step_output(app: &mut App) {
 // Annotation view helpers
 
 fn annotation_next(app: &mut App) {
-    if !app.annotations.is_empty() && app.selected_annotation_index < app.annotations.len() - 1 {
-        app.selected_annotation_index += 1;
+    if !app.annotations.is_empty() {
+        app.selected_annotation_index = step_next(
+            app.selected_annotation_index,
+            app.annotations.len(),
+            app.config.wrap_navigation,
+        );
     }
 }
 
 fn annotation_previous(app: &mut App) {
-    if app.selected_annotation_index > 0 {
-        app.selected_annotation_index -= 1;
+    if !app.annotations.is_empty() {
+        app.selected_annotation_index = step_previous(
+            app.selected_annotation_index,
+            app.annotations.len(),
+            app.config.wrap_navigation,
+        );
+    }
+}
+
+/// Switch to the next annotation sort order. Marked annotations stay marked
+/// (tracked by path and line, since indices change) and the cursor resets.
+fn cycle_annotation_sort(app: &mut App) {
+    let marked: Vec<(String, u32)> = app
+        .selected_annotations
+        .iter()
+        .filter_map(|&i| app.annotations.get(i))
+        .map(|ann| (ann.path.clone(), ann.start_line))
+        .collect();
+
+    app.annotations_sort = app.annotations_sort.next();
+    let mut annotations = app.annotations_unsorted.clone();
+    match app.annotations_sort {
+        AnnotationSort::Original => {}
+        AnnotationSort::Severity => {
+            annotations.sort_by_key(|ann| std::cmp::Reverse(ann.level));
+        }
+        AnnotationSort::File => {
+            annotations.sort_by(|a, b| (&a.path, a.start_line).cmp(&(&b.path, b.start_line)));
+        }
+    }
+    app.annotations = annotations;
+
+    app.selected_annotations = app
+        .annotations
+        .iter()
+        .enumerate()
+        .filter(|(_, ann)| marked.contains(&(ann.path.clone(), ann.start_line)))
+        .map(|(idx, _)| idx)
+        .collect();
+    app.selected_annotation_index = 0;
+}
+
+/// Indices where a new file path starts in the annotations list
+fn annotation_file_starts(annotations: &[CheckAnnotation]) -> Vec<usize> {
+    (0..annotations.len())
+        .filter(|&i| i == 0 || annotations[i].path != annotations[i - 1].path)
+        .collect()
+}
+
+fn annotation_next_file(app: &mut App) {
+    let starts = annotation_file_starts(&app.annotations);
+    if let Some(&next) = starts.iter().find(|&&i| i > app.selected_annotation_index) {
+        app.selected_annotation_index = next;
+    }
+}
+
+fn annotation_previous_file(app: &mut App) {
+    let starts = annotation_file_starts(&app.annotations);
+    // Start of the file the cursor is currently in
+    let current = starts
+        .iter()
+        .rev()
+        .find(|&&i| i <= app.selected_annotation_index)
+        .copied()
+        .unwrap_or(0);
+    if let Some(&prev) = starts.iter().rev().find(|&&i| i < current) {
+        app.selected_annotation_index = prev;
     }
 }
 
@@ -1412,7 +3269,17 @@ fn annotation_previous(app: &mut App) {
 fn format_annotation_for_clipboard(ann: &CheckAnnotation) -> String {
     // Format: file:line message
     // e.g., "config/initializers/01_sentry.rb:62 [Correctable] Lint/UnusedBlockArgument: Unused block argument..."
-    format!("{}:{} {}", ann.path, ann.start_line, ann.message)
+    format!("{}:{} {}", ann.path, ann.start_line, titled_message(ann))
+}
+
+/// The message prefixed with the annotation's title (usually the rule name), if it has one
+fn titled_message(ann: &CheckAnnotation) -> String {
+    match ann.title.as_deref() {
+        Some(title) if !title.is_empty() && !ann.message.starts_with(title) => {
+            format!("{}: {}", title, ann.message)
+        }
+        _ => ann.message.clone(),
+    }
 }
 
 fn toggle_annotation_selection(app: &mut App) {
@@ -1468,6 +3335,91 @@ fn copy_annotations(app: &mut App) {
     }
 }
 
+/// Copy the focused annotation's `path:line`, for an editor's go-to-file
+fn copy_annotation_location(app: &mut App) {
+    let Some(ann) = app.annotations.get(app.selected_annotation_index) else {
+        return;
+    };
+    let location = format!("{}:{}", ann.path, ann.start_line);
+    if copy_to_clipboard(&location) {
+        app.clipboard_feedback = Some(format!("Copied {}", location));
+        app.clipboard_feedback_time = std::time::Instant::now();
+    }
+}
+
+/// Open the focused annotation's file at its line, if it exists in the local checkout
+fn open_annotation_in_editor(app: &mut App) -> Option<Command> {
+    let ann = app.annotations.get(app.selected_annotation_index)?;
+    let local = find_repo_root()
+        .map(|root| root.join(&ann.path))
+        .filter(|path| path.is_file());
+    let Some(path) = local else {
+        app.clipboard_feedback = Some(format!(
+            "{} not found locally; is the PR's branch checked out?",
+            ann.path
+        ));
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return None;
+    };
+    Some(Command::OpenFileAtLine(
+        path.to_string_lossy().into_owned(),
+        ann.start_line.max(1),
+    ))
+}
+
+/// Copy annotations grouped under their file path, sorted by line within each file.
+/// Like `copy_annotations`, only the selected annotations are copied if any are selected.
+fn copy_annotations_grouped(app: &mut App) {
+    if app.annotations.is_empty() {
+        return;
+    }
+
+    let annotations: Vec<&CheckAnnotation> = if app.selected_annotations.is_empty() {
+        app.annotations.iter().collect()
+    } else {
+        app.selected_annotations
+            .iter()
+            .filter_map(|&i| app.annotations.get(i))
+            .collect()
+    };
+
+    let mut by_file: BTreeMap<&str, Vec<&CheckAnnotation>> = BTreeMap::new();
+    for ann in &annotations {
+        by_file.entry(ann.path.as_str()).or_default().push(ann);
+    }
+
+    let text = by_file
+        .iter_mut()
+        .map(|(path, anns)| {
+            anns.sort_by_key(|ann| ann.start_line);
+            let items: Vec<String> = anns
+                .iter()
+                .map(|ann| format!("  {}: {}", ann.start_line, titled_message(ann)))
+                .collect();
+            format!("{}\n{}", path, items.join("\n"))
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+
+    if copy_to_clipboard(&text) {
+        let file_count = by_file.len();
+        let files = if file_count == 1 { "file" } else { "files" };
+        let findings = if annotations.len() == 1 {
+            "finding"
+        } else {
+            "findings"
+        };
+        app.clipboard_feedback = Some(format!(
+            "Copied {} {} in {} {} to clipboard!",
+            annotations.len(),
+            findings,
+            file_count,
+            files
+        ));
+        app.clipboard_feedback_time = std::time::Instant::now();
+    }
+}
+
 /// Detects if running inside a container (devcontainer, Docker, etc.)
 fn is_container() -> bool {
     std::env::var("DOCKER_CONTAINER").is_ok()
@@ -1530,21 +3482,32 @@ fn copy_to_clipboard(text: &str) -> bool {
 
 /// Opens a URL in the browser, with container support.
 /// Returns Some(url) if the URL should be displayed to the user (in containers).
-fn open_url(url: &str) -> Option<String> {
-    // In container, return URL for display (user can ctrl+click)
-    if is_container() {
-        return Some(url.to_string());
-    }
+fn open_url(config: &Config, url: &str) -> Option<String> {
+    open_urls(config, vec![url.to_string()])
+}
 
-    // Native open commands
-    #[cfg(target_os = "macos")]
-    let cmd = "open";
-    #[cfg(target_os = "linux")]
-    let cmd = "xdg-open";
-    #[cfg(not(any(target_os = "macos", target_os = "linux")))]
-    let cmd = "open"; // fallback
+/// Opens URLs in the browser one at a time, a moment apart, so the browser
+/// groups them in order instead of racing to create windows. Returns the URLs,
+/// one per line, if they should be displayed to the user (in containers).
+fn open_urls(config: &Config, urls: Vec<String>) -> Option<String> {
+    // In container, return URLs for display (user can ctrl+click), unless
+    // the user configured an opener that works there
+    if is_container() && config.open_command.is_none() {
+        return Some(urls.join("\n"));
+    }
 
-    let _ = ProcessCommand::new(cmd).arg(url).spawn();
+    let commands: Vec<_> = urls
+        .iter()
+        .filter_map(|url| config.open_command_for(url))
+        .collect();
+    std::thread::spawn(move || {
+        for (i, (program, args)) in commands.into_iter().enumerate() {
+            if i > 0 {
+                std::thread::sleep(OPEN_URL_SPACING);
+            }
+            let _ = ProcessCommand::new(program).args(args).spawn();
+        }
+    });
     None
 }
 
@@ -1567,11 +3530,48 @@ fn open_preview_view(app: &mut App) -> Option<Command> {
         app.preview_data = None;
         app.preview_scroll = 0;
         app.preview_pr_info = Some((title, number));
-        return Some(Command::StartPreviewFetch(owner, repo, number));
+        app.preview_comment_limit = app.config.preview_max_comments;
+        return Some(Command::StartPreviewFetch(
+            owner,
+            repo,
+            number,
+            app.preview_comment_limit,
+        ));
     }
     None
 }
 
+/// Refetch the preview with another page of older comments
+fn preview_load_more(app: &mut App) -> Option<Command> {
+    let data = app.preview_data.as_ref()?;
+    if app.preview_loading || data.shown_comments() >= data.total_comments {
+        return None;
+    }
+    let limit = match app.preview_comment_limit {
+        0 => MAX_PREVIEW_COMMENTS,
+        n => n,
+    };
+    if limit >= MAX_PREVIEW_COMMENTS {
+        app.clipboard_feedback = Some(format!(
+            "GitHub returns at most {} comments; open the PR in the browser for the rest",
+            MAX_PREVIEW_COMMENTS
+        ));
+        app.clipboard_feedback_time = std::time::Instant::now();
+        return None;
+    }
+    let (owner, repo, number) = app
+        .selected_pr()
+        .map(|pr| (pr.repo_owner.clone(), pr.repo_name.clone(), pr.number))?;
+    let step = app.config.preview_max_comments.max(1);
+    app.preview_comment_limit = (limit + step).min(MAX_PREVIEW_COMMENTS);
+    Some(Command::StartPreviewFetch(
+        owner,
+        repo,
+        number,
+        app.preview_comment_limit,
+    ))
+}
+
 fn close_preview_view(app: &mut App) {
     app.show_preview_view = false;
     app.preview_data = None;
@@ -1581,6 +3581,7 @@ fn close_preview_view(app: &mut App) {
     app.preview_comment_positions.clear();
     app.preview_total_lines = 0;
     app.preview_pr_info = None;
+    app.preview_comment_limit = 0;
 }
 
 fn preview_next_section(app: &mut App) {
@@ -1604,6 +3605,52 @@ fn preview_previous_section(app: &mut App) {
     }
 }
 
+fn handle_annotations_result(app: &mut App, result: FetchResult) -> Option<Command> {
+    match result {
+        FetchResult::AnnotationsSuccess(check_run_id, annotations) => {
+            if let Some(ref mut data) = app.actions_data {
+                for job in data
+                    .workflow_runs
+                    .iter_mut()
+                    .flat_map(|run| run.jobs.iter_mut())
+                    .filter(|job| job.id == check_run_id)
+                {
+                    job.annotations = annotations.clone();
+                }
+            }
+            app.job_annotations_cache.insert(check_run_id, annotations);
+
+            // Finish opening the job if the user is still waiting on it
+            let still_selected =
+                get_selected_job(app).is_some_and(|(_, _, job)| job.id == check_run_id);
+            if app.show_job_logs && app.job_logs_loading && still_selected {
+                return open_job_logs(app);
+            }
+            None
+        }
+        FetchResult::AnnotationsError(check_run_id, e) => {
+            // Only report it if the user is still waiting on that job
+            let job_name = get_selected_job(app)
+                .filter(|(_, _, job)| job.id == check_run_id)
+                .map(|(_, _, job)| job.name.clone())
+                .filter(|_| app.show_job_logs && app.job_logs_loading)?;
+            app.job_logs_loading = false;
+            app.job_logs = Some(JobLogs {
+                job_id: check_run_id,
+                job_name,
+                content: format!(
+                    "Failed to load annotations: {}\n\nPress 'o' to open in browser.",
+                    e
+                ),
+                steps: None,
+                test_results: None,
+            });
+            None
+        }
+        _ => None,
+    }
+}
+
 fn handle_preview_result(app: &mut App, result: FetchResult) {
     match result {
         FetchResult::PreviewSuccess(data) => {
@@ -0,0 +1,271 @@
+//! Non-interactive commands for scripting. These print to stdout and report
+//! failures through `GhuiError` exit codes instead of the TUI's error popup.
+
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+
+use clap::ValueEnum;
+
+use crate::data::{CiStatus, PrFilter, PullRequest};
+use crate::error::GhuiError;
+use crate::icons;
+use crate::services::{
+    cache_disabled, cache_saved_at, fetch_prs_graphql, load_cache, load_config, load_label_filters,
+    save_cache, Config,
+};
+use crate::utils::get_current_repo;
+
+/// PR list to fetch, mirroring the TUI tabs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum FilterArg {
+    MyPrs,
+    ReviewRequested,
+    Labels,
+    All,
+}
+
+/// Read the config, failing the command if it doesn't parse
+fn load_cli_config() -> Result<Config, GhuiError> {
+    load_config().map_err(|e| GhuiError::Other(format!("{:#}", e)))
+}
+
+/// Fetch PRs for a filter. `All` merges the other lists, keeping the first copy of each PR.
+pub async fn fetch_filter(
+    filter: FilterArg,
+    config: &Config,
+) -> Result<Vec<PullRequest>, GhuiError> {
+    let (owner, repo) = get_current_repo().ok_or(GhuiError::NotARepo)?;
+    let labels = || {
+        load_label_filters(&owner, &repo)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|lf| lf.label_name)
+            .collect::<Vec<_>>()
+    };
+
+    let filters = match filter {
+        FilterArg::MyPrs => vec![PrFilter::MyPrs],
+        FilterArg::ReviewRequested => vec![PrFilter::ReviewRequested],
+        FilterArg::Labels => vec![PrFilter::Labels(labels())],
+        FilterArg::All => vec![
+            PrFilter::MyPrs,
+            PrFilter::ReviewRequested,
+            PrFilter::Labels(labels()),
+        ],
+    };
+
+    let mut seen = HashSet::new();
+    let mut prs = Vec::new();
+    for filter in filters {
+        let (fetched, warnings) = fetch_prs_graphql(filter, config)
+            .await
+            .map_err(GhuiError::classify)?;
+        for warning in warnings {
+            eprintln!("Warning: {}", warning);
+        }
+        for pr in fetched {
+            if seen.insert((pr.repo_owner.clone(), pr.repo_name.clone(), pr.number)) {
+                prs.push(pr);
+            }
+        }
+    }
+    Ok(prs)
+}
+
+/// `ghui list`: print the PRs for a filter, one per line or as JSON
+pub fn run_list(filter: FilterArg, json: bool) -> Result<(), GhuiError> {
+    let config = load_cli_config()?;
+    let rt = tokio::runtime::Runtime::new().map_err(|e| GhuiError::Other(e.to_string()))?;
+    let prs = rt.block_on(fetch_filter(filter, &config))?;
+
+    if json {
+        let items: Vec<serde_json::Value> = prs.iter().map(pr_to_json).collect();
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&items).map_err(|e| GhuiError::Other(e.to_string()))?
+        );
+    } else {
+        for pr in &prs {
+            println!(
+                "#{}\t{}\t{}\t{}\t{}",
+                pr.number,
+                pr.ci_status.to_str(),
+                pr.author_display(),
+                pr.branch,
+                pr.title
+            );
+        }
+    }
+
+    if prs.is_empty() {
+        return Err(GhuiError::NoResults);
+    }
+    Ok(())
+}
+
+fn pr_to_json(pr: &PullRequest) -> serde_json::Value {
+    serde_json::json!({
+        "number": pr.number,
+        "title": pr.title,
+        "author": pr.author_login(),
+        "branch": pr.branch,
+        "repo": format!("{}/{}", pr.repo_owner, pr.repo_name),
+        "ci_status": pr.ci_status.to_str(),
+        "milestone": pr.milestone,
+        "url": format!(
+            "https://github.com/{}/{}/pull/{}",
+            pr.repo_owner, pr.repo_name, pr.number
+        ),
+    })
+}
+
+/// A change between two fetches of the same PR list
+#[derive(Debug, Clone, PartialEq)]
+pub enum PrChange {
+    Opened,
+    CiChanged { from: CiStatus, to: CiStatus },
+    Closed,
+}
+
+/// Compare two fetches, returning each PR that appeared, disappeared, or changed CI status
+pub fn diff_prs<'a>(
+    previous: &'a [PullRequest],
+    current: &'a [PullRequest],
+) -> Vec<(&'a PullRequest, PrChange)> {
+    let key = |pr: &PullRequest| (pr.repo_owner.clone(), pr.repo_name.clone(), pr.number);
+    let before: HashMap<_, &PullRequest> = previous.iter().map(|pr| (key(pr), pr)).collect();
+    let after: HashSet<_> = current.iter().map(key).collect();
+
+    let mut changes: Vec<(&PullRequest, PrChange)> = current
+        .iter()
+        .filter_map(|pr| match before.get(&key(pr)) {
+            None => Some((pr, PrChange::Opened)),
+            Some(old) if old.ci_status != pr.ci_status => Some((
+                pr,
+                PrChange::CiChanged {
+                    from: old.ci_status,
+                    to: pr.ci_status,
+                },
+            )),
+            Some(_) => None,
+        })
+        .collect();
+    changes.extend(
+        previous
+            .iter()
+            .filter(|pr| !after.contains(&key(pr)))
+            .map(|pr| (pr, PrChange::Closed)),
+    );
+    changes
+}
+
+/// `ghui watch`: poll a filter and print a line per change until interrupted
+pub fn run_watch(filter: FilterArg, interval_secs: Option<u64>) -> Result<(), GhuiError> {
+    let config = load_cli_config()?;
+    let interval = match interval_secs {
+        Some(secs) => Duration::from_secs(secs.max(5)),
+        None => config.refresh_interval(),
+    };
+    let rt = tokio::runtime::Runtime::new().map_err(|e| GhuiError::Other(e.to_string()))?;
+
+    // Ctrl-C ends the watch cleanly at any point, including mid-fetch
+    rt.block_on(async {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => Ok(()),
+            result = watch_loop(filter, interval, &config) => result,
+        }
+    })
+}
+
+async fn watch_loop(
+    filter: FilterArg,
+    interval: Duration,
+    config: &Config,
+) -> Result<(), GhuiError> {
+    // The first fetch is the baseline; only later changes are printed
+    let mut previous = fetch_filter(filter, config).await?;
+    eprintln!(
+        "Watching {} PRs, polling every {}s (Ctrl-C to stop)",
+        previous.len(),
+        interval.as_secs()
+    );
+
+    loop {
+        tokio::time::sleep(interval).await;
+
+        let current = match fetch_filter(filter, config).await {
+            Ok(prs) => prs,
+            // Keep watching through dropped connections; anything else is fatal
+            Err(GhuiError::Network(e)) => {
+                eprintln!("Fetch failed, retrying: {}", e);
+                continue;
+            }
+            Err(e) => return Err(e),
+        };
+        for (pr, change) in diff_prs(&previous, &current) {
+            println!("{}", format_change(pr, &change));
+        }
+        previous = current;
+    }
+}
+
+fn format_change(pr: &PullRequest, change: &PrChange) -> String {
+    let what = match change {
+        PrChange::Opened => "new".to_string(),
+        PrChange::CiChanged { from, to } => format!("ci {} -> {}", from.to_str(), to.to_str()),
+        PrChange::Closed => "gone".to_string(),
+    };
+    format!(
+        "{}/{}#{}\t{}\t{}",
+        pr.repo_owner, pr.repo_name, pr.number, what, pr.title
+    )
+}
+
+/// `ghui status`: print a one-line summary for status bars, e.g. `PRs:12 Review:5 ✗3`.
+/// Reads only the cache unless `refresh` is set, so it stays fast enough to run on
+/// every status bar redraw.
+pub fn run_status(refresh: bool) -> Result<(), GhuiError> {
+    let (owner, repo) = get_current_repo().ok_or(GhuiError::NotARepo)?;
+
+    // Without a cache there's nothing to read, so always fetch
+    let (my_prs, review_prs) = if refresh || cache_disabled() {
+        let config = load_cli_config()?;
+        let rt = tokio::runtime::Runtime::new().map_err(|e| GhuiError::Other(e.to_string()))?;
+        let fetch = |filter: PrFilter| -> Result<Vec<PullRequest>, GhuiError> {
+            let (prs, warnings) = rt
+                .block_on(fetch_prs_graphql(filter.clone(), &config))
+                .map_err(GhuiError::classify)?;
+            for warning in warnings {
+                eprintln!("Warning: {}", warning);
+            }
+            save_cache(&prs, &owner, &repo, filter).map_err(|e| GhuiError::Other(e.to_string()))?;
+            Ok(prs)
+        };
+        (fetch(PrFilter::MyPrs)?, fetch(PrFilter::ReviewRequested)?)
+    } else {
+        let load = |filter: PrFilter| -> Result<Vec<PullRequest>, GhuiError> {
+            // Zero counts from a list that was never cached would look like no PRs
+            let saved = cache_saved_at(&owner, &repo, &filter)
+                .map_err(|e| GhuiError::Other(e.to_string()))?;
+            if saved.is_none() {
+                return Err(GhuiError::Other(format!(
+                    "No cached PRs for {}/{} yet; run `ghui status --refresh` or open ghui first",
+                    owner, repo
+                )));
+            }
+            load_cache(&owner, &repo, filter).map_err(|e| GhuiError::Other(e.to_string()))
+        };
+        (load(PrFilter::MyPrs)?, load(PrFilter::ReviewRequested)?)
+    };
+
+    let mut line = format!("PRs:{} Review:{}", my_prs.len(), review_prs.len());
+    let failing = my_prs
+        .iter()
+        .filter(|pr| pr.ci_status == CiStatus::Failure)
+        .count();
+    if failing > 0 {
+        line.push_str(&format!(" {}{}", icons::ci_failure(), failing));
+    }
+    println!("{}", line);
+    Ok(())
+}
@@ -1,12 +1,15 @@
 pub mod models;
 pub mod types;
 
-pub use models::{LabelFilter, PullRequest};
+pub use models::{LabelFilter, PullRequest, RecentCheckout, SavedSearch};
 pub use types::{
-    ActionsData, AnnotationLevel, CacheMeta, CheckAnnotation, CiStatus, CommitConnection,
-    CommitData, CommitNode, JobLogs, JobStep, LabelFiltersTable, PageInfo, PrComment, PrFilter,
-    PreviewData, PullRequestsTable, SearchConnection, SearchGraphQLData, SearchGraphQLResponse,
-    SearchNode, StatusCheckRollup, TestResult, WorkflowConclusion, WorkflowJob, WorkflowRun,
+    ActionsData, AnnotationLevel, AnnotationSort, CacheMeta, ChangedFile, ChangedFileConnection,
+    CheckAnnotation, CheckContext, CiProvider, CiStatus, CiSummary, CommitConnection, CommitData,
+    CommitNode, DonePrsTable, JobLogs, JobStep, LabelFiltersTable, MergeMethod, Mergeable,
+    PageInfo, PrComment, PrFilter, PrSort, PrSource, PreviewData, ProviderFilter, PullRequestNode,
+    PullRequestsTable, RecentCheckoutsTable, ReviewDecision, ReviewState, RunAction, RunTarget,
+    SavedSearchesTable, SearchConnection, SearchGraphQLData, SearchGraphQLResponse, SearchNode,
+    StatusCheckRollup, TableColumn, TestResult, WorkflowConclusion, WorkflowJob, WorkflowRun,
     WorkflowStatus, CACHE_VERSION,
 };
 
@@ -1,4 +1,5 @@
-use super::types::CiStatus;
+use super::types::{CiStatus, Mergeable, ReviewDecision, ReviewState};
+use crate::icons;
 
 #[derive(Debug, Clone)]
 pub struct PullRequest {
@@ -10,6 +11,43 @@ pub struct PullRequest {
     pub ci_status: CiStatus,
     pub author: String,
     pub head_sha: Option<String>,
+    pub review_decision: ReviewDecision,
+    pub mergeable: Mergeable,
+    pub is_draft: bool,
+    pub milestone: Option<String>,    // Milestone title
+    pub updated_at: Option<i64>,      // Last activity, seconds since the Unix epoch
+    pub created_at: Option<i64>,      // When the PR was opened, seconds since the Unix epoch
+    pub my_review_state: ReviewState, // Not cached, so None until the first fetch
+    pub touches_my_code: bool,        // Changes files CODEOWNERS assigns to me; not cached
+    pub blocking_on_me: bool, // Everyone else requested has reviewed; only I haven't. Not cached
+}
+
+impl PullRequest {
+    /// The head commit's SHA abbreviated to 7 characters, once fetched
+    pub fn short_sha(&self) -> Option<&str> {
+        self.head_sha.as_deref().map(|sha| &sha[..sha.len().min(7)])
+    }
+
+    /// The author's login, or None when GitHub didn't return one (e.g. a deleted account)
+    pub fn author_login(&self) -> Option<&str> {
+        Some(self.author.as_str()).filter(|login| !login.is_empty())
+    }
+
+    /// The author's login for display, or a placeholder when it's missing
+    pub fn author_display(&self) -> &str {
+        self.author_login().unwrap_or(icons::placeholder())
+    }
+
+    /// Green CI, approved (or no review required), and free of conflicts:
+    /// mergeable right now
+    pub fn is_ready_to_merge(&self) -> bool {
+        self.ci_status == CiStatus::Success
+            && matches!(
+                self.review_decision,
+                ReviewDecision::Approved | ReviewDecision::None
+            )
+            && self.mergeable == Mergeable::Mergeable
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -25,3 +63,22 @@ impl LabelFilter {
         self.repo_owner.is_none() && self.repo_name.is_none()
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct SavedSearch {
+    pub id: i64,
+    pub name: String,
+    pub query: String, // GitHub search qualifiers, e.g. "label:bug author:foo"
+    pub repo_owner: String,
+    pub repo_name: String,
+}
+
+/// A branch checked out through ghui, for quick re-checkout
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecentCheckout {
+    pub branch: String,
+    pub pr_number: u64,
+    pub repo_owner: String,
+    pub repo_name: String,
+    pub checked_out_at: i64, // Seconds since the Unix epoch
+}
@@ -1,10 +1,10 @@
 use sea_query::Iden;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use std::str::FromStr;
 
 use crate::icons;
 
-pub const CACHE_VERSION: i32 = 5;
+pub const CACHE_VERSION: i32 = 9;
 
 // Database table identifiers
 #[derive(Iden)]
@@ -25,6 +25,10 @@ pub enum PullRequestsTable {
     CiStatus,
     Filter,
     Author,
+    Milestone,
+    UpdatedAt,
+    CreatedAt,
+    IsDraft,
 }
 
 #[derive(Iden)]
@@ -36,6 +40,34 @@ pub enum LabelFiltersTable {
     RepoName,
 }
 
+#[derive(Iden)]
+pub enum SavedSearchesTable {
+    Table,
+    Id,
+    Name,
+    Query,
+    RepoOwner,
+    RepoName,
+}
+
+#[derive(Iden)]
+pub enum RecentCheckoutsTable {
+    Table,
+    Branch,
+    PrNumber,
+    RepoOwner,
+    RepoName,
+    CheckedOutAt,
+}
+
+#[derive(Iden)]
+pub enum DonePrsTable {
+    Table,
+    PrNumber,
+    RepoOwner,
+    RepoName,
+}
+
 // CI Status
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CiStatus {
@@ -43,6 +75,7 @@ pub enum CiStatus {
     Pending,
     Success,
     Failure,
+    NoCommits, // The PR has no commits yet, so there's nothing for CI to run on
 }
 
 impl CiStatus {
@@ -50,9 +83,10 @@ impl CiStatus {
         use ratatui::style::Color;
         match self {
             CiStatus::Unknown => ("N/A", Color::DarkGray),
-            CiStatus::Pending => (icons::CI_PENDING_DISPLAY, Color::Yellow),
-            CiStatus::Success => (icons::CI_SUCCESS_DISPLAY, Color::Green),
-            CiStatus::Failure => (icons::CI_FAILURE_DISPLAY, Color::Red),
+            CiStatus::Pending => (icons::ci_pending_display(), Color::Yellow),
+            CiStatus::Success => (icons::ci_success_display(), Color::Green),
+            CiStatus::Failure => (icons::ci_failure_display(), Color::Red),
+            CiStatus::NoCommits => ("No commits", Color::DarkGray),
         }
     }
 
@@ -62,10 +96,38 @@ impl CiStatus {
             CiStatus::Pending => "pending",
             CiStatus::Success => "success",
             CiStatus::Failure => "failure",
+            CiStatus::NoCommits => "no_commits",
         }
     }
 }
 
+/// How many PRs in a list have each CI outcome; `Unknown` and `NoCommits` aren't counted
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CiSummary {
+    pub success: usize,
+    pub pending: usize,
+    pub failure: usize,
+}
+
+impl CiSummary {
+    pub fn tally(statuses: impl IntoIterator<Item = CiStatus>) -> Self {
+        let mut summary = Self::default();
+        for status in statuses {
+            match status {
+                CiStatus::Success => summary.success += 1,
+                CiStatus::Pending => summary.pending += 1,
+                CiStatus::Failure => summary.failure += 1,
+                CiStatus::Unknown | CiStatus::NoCommits => {}
+            }
+        }
+        summary
+    }
+
+    pub fn total(&self) -> usize {
+        self.success + self.pending + self.failure
+    }
+}
+
 impl FromStr for CiStatus {
     type Err = std::convert::Infallible;
 
@@ -74,17 +136,117 @@ impl FromStr for CiStatus {
             "PENDING" => CiStatus::Pending,
             "SUCCESS" => CiStatus::Success,
             "FAILURE" | "ERROR" => CiStatus::Failure,
+            "NO_COMMITS" => CiStatus::NoCommits,
             _ => CiStatus::Unknown,
         })
     }
 }
 
+// Review decision, as reported by GitHub's reviewDecision field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReviewDecision {
+    None, // No reviews required, or not yet fetched
+    ReviewRequired,
+    ChangesRequested,
+    Approved,
+}
+
+impl FromStr for ReviewDecision {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_uppercase().as_str() {
+            "APPROVED" => ReviewDecision::Approved,
+            "CHANGES_REQUESTED" => ReviewDecision::ChangesRequested,
+            "REVIEW_REQUIRED" => ReviewDecision::ReviewRequired,
+            _ => ReviewDecision::None,
+        })
+    }
+}
+
+// The current user's latest review, as reported by GitHub's viewerLatestReview field
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReviewState {
+    None, // Not reviewed yet, or not yet fetched
+    Commented,
+    ChangesRequested,
+    Approved,
+    Dismissed,
+}
+
+impl FromStr for ReviewState {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_uppercase().as_str() {
+            "APPROVED" => ReviewState::Approved,
+            "CHANGES_REQUESTED" => ReviewState::ChangesRequested,
+            "COMMENTED" => ReviewState::Commented,
+            "DISMISSED" => ReviewState::Dismissed,
+            _ => ReviewState::None, // PENDING is an unsubmitted draft
+        })
+    }
+}
+
+// Whether the PR can be merged without conflicts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Mergeable {
+    Unknown, // GitHub is still computing it, or not yet fetched
+    Mergeable,
+    Conflicting,
+}
+
+impl FromStr for Mergeable {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_uppercase().as_str() {
+            "MERGEABLE" => Mergeable::Mergeable,
+            "CONFLICTING" => Mergeable::Conflicting,
+            _ => Mergeable::Unknown,
+        })
+    }
+}
+
+// How a PR's commits land on the base branch
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MergeMethod {
+    #[default]
+    Merge,
+    Squash,
+    Rebase,
+}
+
+impl MergeMethod {
+    /// Value of the REST API's `merge_method` parameter
+    pub fn to_str(self) -> &'static str {
+        match self {
+            MergeMethod::Merge => "merge",
+            MergeMethod::Squash => "squash",
+            MergeMethod::Rebase => "rebase",
+        }
+    }
+
+    pub fn next(self) -> Self {
+        match self {
+            MergeMethod::Merge => MergeMethod::Squash,
+            MergeMethod::Squash => MergeMethod::Rebase,
+            MergeMethod::Rebase => MergeMethod::Merge,
+        }
+    }
+}
+
 // PR Filter
 #[derive(Debug, Clone, PartialEq)]
 pub enum PrFilter {
     MyPrs,
     ReviewRequested,
     Labels(Vec<String>),
+    All,            // Virtual: composed from the other three lists, never fetched directly
+    Search(String), // Server-side GitHub search qualifiers (e.g. "label:bug author:foo")
+    Team(String),   // PRs awaiting review from a team, as "org/team"
+    Recent,         // Merged or closed PRs, most recently updated first
 }
 
 impl PrFilter {
@@ -93,6 +255,72 @@ impl PrFilter {
             PrFilter::MyPrs => "my_prs",
             PrFilter::ReviewRequested => "review_requested",
             PrFilter::Labels(_) => "labels",
+            PrFilter::All => "all",
+            PrFilter::Search(_) => "search",
+            PrFilter::Team(_) => "team",
+            PrFilter::Recent => "recent",
+        }
+    }
+
+    /// GitHub search qualifiers matching this filter in the web UI, relative to
+    /// the repo's pull requests page. None for All, which has no single query.
+    pub fn web_query(&self) -> Option<String> {
+        match self {
+            PrFilter::MyPrs => Some("is:pr is:open author:@me".to_string()),
+            PrFilter::ReviewRequested => Some("is:pr is:open review-requested:@me".to_string()),
+            PrFilter::Labels(labels) if !labels.is_empty() => {
+                // A comma-separated label list matches any of them
+                let labels: Vec<String> = labels
+                    .iter()
+                    .map(|label| {
+                        if label.contains(char::is_whitespace) {
+                            format!("\"{}\"", label)
+                        } else {
+                            label.clone()
+                        }
+                    })
+                    .collect();
+                Some(format!("is:pr is:open label:{}", labels.join(",")))
+            }
+            PrFilter::Labels(_) | PrFilter::All => None,
+            PrFilter::Search(qualifiers) => Some(format!("is:pr {}", qualifiers)),
+            PrFilter::Team(slug) => Some(format!("is:pr is:open team-review-requested:{}", slug)),
+            PrFilter::Recent => Some("is:pr is:closed sort:updated-desc".to_string()),
+        }
+    }
+
+    /// Key used for the `filter` column in the PR cache. Each search query gets
+    /// its own key so results for different queries don't overwrite each other.
+    pub fn cache_key(&self) -> String {
+        match self {
+            PrFilter::Search(query) => {
+                // FNV-1a: stable across builds, unlike std's DefaultHasher
+                let hash = query.bytes().fold(0xcbf29ce484222325u64, |hash, byte| {
+                    (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+                });
+                format!("search:{:016x}", hash)
+            }
+            PrFilter::Team(slug) => format!("team:{}", slug),
+            _ => self.to_str().to_string(),
+        }
+    }
+}
+
+// Which tab a PR in the "All" view came from
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PrSource {
+    Mine,
+    Review,
+    Label,
+}
+
+impl PrSource {
+    pub fn display(self) -> (&'static str, ratatui::style::Color) {
+        use ratatui::style::Color;
+        match self {
+            PrSource::Mine => ("mine", Color::Cyan),
+            PrSource::Review => ("review", Color::Yellow),
+            PrSource::Label => ("label", Color::Magenta),
         }
     }
 }
@@ -126,24 +354,41 @@ pub struct StatusCheckRollup {
     pub state: String,
 }
 
+// GitHub can null out any of these levels (e.g. alongside an `errors` array),
+// so each is optional and a missing level reads as an empty result
 #[derive(Debug, Deserialize)]
 pub struct SearchGraphQLResponse {
-    pub data: SearchGraphQLData,
+    pub data: Option<SearchGraphQLData>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct SearchGraphQLData {
-    pub search: SearchConnection,
+    pub search: Option<SearchConnection>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Default, Deserialize)]
 pub struct SearchConnection {
+    #[serde(default, deserialize_with = "lenient_nodes")]
     pub nodes: Vec<SearchNode>,
-    #[serde(rename = "pageInfo")]
+    #[serde(rename = "pageInfo", default)]
     pub page_info: PageInfo,
+    /// GraphQL errors GitHub returned alongside the results
+    #[serde(skip)]
+    pub warnings: Vec<String>,
 }
 
-#[derive(Debug, Deserialize)]
+/// Deserialize search results one node at a time, skipping nulls and nodes
+/// that don't parse, so one odd PR doesn't fail the whole page
+fn lenient_nodes<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<SearchNode>, D::Error> {
+    let nodes: Option<Vec<serde_json::Value>> = Option::deserialize(deserializer)?;
+    Ok(nodes
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|node| serde_json::from_value(node).ok())
+        .collect())
+}
+
+#[derive(Debug, Default, Deserialize)]
 pub struct PageInfo {
     #[serde(rename = "hasNextPage")]
     pub has_next_page: bool,
@@ -156,17 +401,135 @@ pub struct Author {
     pub login: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ViewerReview {
+    pub state: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangedFileConnection {
+    pub nodes: Vec<ChangedFile>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ChangedFile {
+    pub path: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Milestone {
+    pub title: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewRequestConnection {
+    pub nodes: Vec<ReviewRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewRequest {
+    #[serde(rename = "requestedReviewer")]
+    pub requested_reviewer: Option<RequestedReviewer>,
+}
+
+/// A user (with a login) or a team (without one) asked to review
+#[derive(Debug, Deserialize)]
+pub struct RequestedReviewer {
+    pub login: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ReviewConnection {
+    pub nodes: Vec<Review>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Review {
+    pub author: Option<Author>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequestNode {
+    pub number: u64,
+    pub title: String,
+    #[serde(rename = "headRefName")]
+    pub head_ref_name: String,
+    pub commits: Option<CommitConnection>,
+    pub author: Option<Author>,
+    #[serde(rename = "reviewDecision")]
+    pub review_decision: Option<String>,
+    pub mergeable: Option<String>,
+    #[serde(rename = "isDraft", default)]
+    pub is_draft: bool,
+    pub milestone: Option<Milestone>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: Option<String>,
+    #[serde(rename = "viewerLatestReview")]
+    pub viewer_latest_review: Option<ViewerReview>,
+    // Only requested when CODEOWNERS highlighting is on
+    pub files: Option<ChangedFileConnection>,
+    // Only requested for the Review Requested tab
+    #[serde(rename = "reviewRequests")]
+    pub review_requests: Option<ReviewRequestConnection>,
+    #[serde(rename = "latestReviews")]
+    pub latest_reviews: Option<ReviewConnection>,
+}
+
+impl PullRequestNode {
+    /// CI rollup of the head commit. An empty commit list means the PR has no
+    /// commits, which is told apart from a commit without CI.
+    pub fn ci_status(&self) -> CiStatus {
+        let Some(commits) = &self.commits else {
+            return CiStatus::Unknown;
+        };
+        match commits.nodes.first() {
+            None => CiStatus::NoCommits,
+            Some(node) => node
+                .commit
+                .status_check_rollup
+                .as_ref()
+                .map(|s| s.state.parse().unwrap())
+                .unwrap_or(CiStatus::Unknown),
+        }
+    }
+
+    /// The head commit's SHA; None when there are no commits
+    pub fn head_sha(&self) -> Option<&str> {
+        self.commits.as_ref()?.nodes.first()?.oid()
+    }
+
+    /// Whether `login` is the last reviewer still requested after someone else
+    /// has reviewed, so the PR is waiting on them alone. GitHub drops a review
+    /// request once it's answered.
+    pub fn blocked_only_on(&self, login: &str) -> bool {
+        let (Some(requests), Some(reviews)) = (&self.review_requests, &self.latest_reviews) else {
+            return false;
+        };
+        let only_me = match requests.nodes.as_slice() {
+            [request] => request
+                .requested_reviewer
+                .as_ref()
+                .and_then(|reviewer| reviewer.login.as_deref())
+                .is_some_and(|reviewer| reviewer.eq_ignore_ascii_case(login)),
+            _ => false,
+        };
+        let others_reviewed = reviews.nodes.iter().any(|review| {
+            review
+                .author
+                .as_ref()
+                .is_some_and(|author| !author.login.eq_ignore_ascii_case(login))
+        });
+        only_me && others_reviewed
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(tag = "__typename")]
 pub enum SearchNode {
-    PullRequest {
-        number: u64,
-        title: String,
-        #[serde(rename = "headRefName")]
-        head_ref_name: String,
-        commits: CommitConnection,
-        author: Option<Author>,
-    },
+    // Boxed so `Other` stays small
+    PullRequest(Box<PullRequestNode>),
     #[serde(other)]
     Other,
 }
@@ -235,8 +598,8 @@ impl FromStr for WorkflowConclusion {
     }
 }
 
-/// Annotation level for check annotations
-#[derive(Debug, Clone, Copy, PartialEq)]
+/// Annotation level for check annotations, ordered from least to most severe
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum AnnotationLevel {
     Notice,
     Warning,
@@ -255,6 +618,95 @@ impl FromStr for AnnotationLevel {
     }
 }
 
+/// Sort order for the PR list
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrSort {
+    /// As returned by GitHub
+    #[default]
+    Default,
+    /// Longest-open first, for review SLAs
+    OpenedAge,
+    /// Least recently updated first
+    Updated,
+    /// Most recently updated first
+    Newest,
+}
+
+impl PrSort {
+    pub fn next(self) -> Self {
+        match self {
+            PrSort::Default => PrSort::OpenedAge,
+            PrSort::OpenedAge => PrSort::Updated,
+            PrSort::Updated => PrSort::Newest,
+            PrSort::Newest => PrSort::Default,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PrSort::Default => "default",
+            PrSort::OpenedAge => "oldest opened first",
+            PrSort::Updated => "least recently updated first",
+            PrSort::Newest => "most recently updated first",
+        }
+    }
+}
+
+/// A fixed-width PR table column that `<` / `>` resize
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableColumn {
+    #[default]
+    Branch,
+    Author,
+    Milestone,
+}
+
+impl TableColumn {
+    pub fn next(self) -> Self {
+        match self {
+            TableColumn::Branch => TableColumn::Author,
+            TableColumn::Author => TableColumn::Milestone,
+            TableColumn::Milestone => TableColumn::Branch,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TableColumn::Branch => "Branch",
+            TableColumn::Author => "Author",
+            TableColumn::Milestone => "Milestone",
+        }
+    }
+}
+
+/// Sort order for the annotations view
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AnnotationSort {
+    #[default]
+    Original,
+    Severity,
+    File,
+}
+
+impl AnnotationSort {
+    pub fn next(self) -> Self {
+        match self {
+            AnnotationSort::Original => AnnotationSort::Severity,
+            AnnotationSort::Severity => AnnotationSort::File,
+            AnnotationSort::File => AnnotationSort::Original,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AnnotationSort::Original => "original",
+            AnnotationSort::Severity => "severity",
+            AnnotationSort::File => "file",
+        }
+    }
+}
+
 /// A check annotation (e.g., from reviewdog)
 #[derive(Debug, Clone)]
 pub struct CheckAnnotation {
@@ -278,7 +730,19 @@ pub struct WorkflowJob {
     pub details_url: Option<String>,
     pub summary: Option<String>,
     pub text: Option<String>,
-    pub annotations: Vec<CheckAnnotation>,
+    pub annotations: Vec<CheckAnnotation>, // Empty until fetched when the job is opened
+    pub annotations_count: usize,
+}
+
+/// One context from a commit's status check rollup: a check run or a commit
+/// status, so it covers CI that only reports statuses (e.g. CircleCI)
+#[derive(Debug, Clone)]
+pub struct CheckContext {
+    pub name: String,
+    pub status: WorkflowStatus,
+    pub conclusion: Option<WorkflowConclusion>,
+    pub state: String, // GitHub's state in lowercase, e.g. "failure" or "in_progress"
+    pub url: Option<String>,
 }
 
 /// A test result from CircleCI test metadata API
@@ -316,27 +780,120 @@ pub struct JobStep {
     pub sub_steps: Option<Vec<JobStep>>,
 }
 
+/// CI system that reported a workflow run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CiProvider {
+    GitHubActions,
+    CircleCi,
+    Other,
+}
+
+impl CiProvider {
+    /// Provider for a check suite, from the name of the GitHub App that created it
+    pub fn from_app_name(name: &str) -> Self {
+        if name == "GitHub Actions" {
+            CiProvider::GitHubActions
+        } else if name.to_lowercase().contains("circleci") {
+            CiProvider::CircleCi
+        } else {
+            CiProvider::Other
+        }
+    }
+}
+
+/// Which providers' runs the workflows view shows
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ProviderFilter {
+    #[default]
+    All,
+    GitHubActions,
+    CircleCi,
+}
+
+impl ProviderFilter {
+    pub fn next(self) -> Self {
+        match self {
+            ProviderFilter::All => ProviderFilter::GitHubActions,
+            ProviderFilter::GitHubActions => ProviderFilter::CircleCi,
+            ProviderFilter::CircleCi => ProviderFilter::All,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ProviderFilter::All => "All",
+            ProviderFilter::GitHubActions => "GitHub Actions",
+            ProviderFilter::CircleCi => "CircleCI",
+        }
+    }
+
+    pub fn matches(self, provider: CiProvider) -> bool {
+        match self {
+            ProviderFilter::All => true,
+            ProviderFilter::GitHubActions => provider == CiProvider::GitHubActions,
+            ProviderFilter::CircleCi => provider == CiProvider::CircleCi,
+        }
+    }
+}
+
+/// Workflow run that a re-run or cancel applies to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RunTarget {
+    Actions { run_id: u64 },
+    CircleCi { job_number: u64 }, // Any job in the workflow; the API needs its workflow ID
+}
+
+/// Action to take on a workflow run
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RunAction {
+    Rerun,
+    RerunFailed,
+    Cancel,
+}
+
 /// A workflow run (e.g., "CI", "Tests")
 #[derive(Debug, Clone)]
 pub struct WorkflowRun {
-    pub id: u64,
+    pub id: Option<u64>, // Actions run ID; None for other providers or when GitHub omits it
     pub name: String,
+    pub provider: CiProvider,
     pub status: WorkflowStatus,
     pub conclusion: Option<WorkflowConclusion>,
     pub html_url: String,
     pub jobs: Vec<WorkflowJob>,
     pub created_at: String,
     pub updated_at: String,
+    pub head_sha: Option<String>, // Commit the run was for, when the provider says
 }
 
 /// Container for all actions data for a PR
 #[derive(Debug, Clone)]
 pub struct ActionsData {
     pub pr_number: u64,
+    pub head_sha: Option<String>, // The PR's current head commit
     pub workflow_runs: Vec<WorkflowRun>,
     pub error: Option<String>,
 }
 
+impl ActionsData {
+    /// A run's commit, abbreviated, when the runs aren't for the PR's current head
+    pub fn stale_sha(&self) -> Option<&str> {
+        let head = self.head_sha.as_deref()?;
+        self.workflow_runs
+            .iter()
+            .filter_map(|run| run.head_sha.as_deref())
+            .find(|sha| *sha != head)
+            .map(|sha| &sha[..sha.len().min(7)])
+    }
+
+    /// Runs shown under a provider filter. Job selection indexes into these.
+    pub fn visible_runs(&self, filter: ProviderFilter) -> impl Iterator<Item = &WorkflowRun> {
+        self.workflow_runs
+            .iter()
+            .filter(move |run| filter.matches(run.provider))
+    }
+}
+
 /// A comment on a PR (either PR body or review comment)
 #[derive(Debug, Clone)]
 pub struct PrComment {
@@ -352,5 +909,13 @@ pub struct PreviewData {
     pub pr_number: u64,
     pub title: String,
     pub comments: Vec<PrComment>,
+    pub total_comments: usize, // Comments and reviews on the PR, including ones not fetched
     pub error: Option<String>,
 }
+
+impl PreviewData {
+    /// Comments and reviews shown, not counting the description
+    pub fn shown_comments(&self) -> usize {
+        self.comments.iter().filter(|c| !c.is_pr_body).count()
+    }
+}
@@ -0,0 +1,71 @@
+use std::fmt;
+
+use crate::services::{is_network_error, is_unauthorized};
+
+/// Failure categories that scripts can tell apart by exit code
+#[derive(Debug)]
+pub enum GhuiError {
+    /// No GitHub token, or GitHub rejected it
+    Auth(String),
+    /// The current directory isn't a GitHub repository
+    NotARepo,
+    /// GitHub couldn't be reached
+    Network(String),
+    /// The query succeeded but matched nothing
+    NoResults,
+    /// Anything else
+    Other(String),
+}
+
+impl GhuiError {
+    /// Process exit code for this failure. 2 is left to clap for usage errors.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            GhuiError::Other(_) => 1,
+            GhuiError::Auth(_) => 3,
+            GhuiError::NotARepo => 4,
+            GhuiError::Network(_) => 5,
+            GhuiError::NoResults => 6,
+        }
+    }
+
+    /// Categorize an error from the service layer
+    pub fn classify(err: anyhow::Error) -> Self {
+        let err = match err.downcast::<GhuiError>() {
+            Ok(ghui_err) => return ghui_err,
+            Err(err) => err,
+        };
+        if is_network_error(&err) {
+            return GhuiError::Network(format!("{:#}", err));
+        }
+        if is_unauthorized(&err) {
+            return GhuiError::Auth(format!("{:#}", err));
+        }
+        GhuiError::Other(format!("{:#}", err))
+    }
+}
+
+impl fmt::Display for GhuiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GhuiError::Auth(msg) | GhuiError::Network(msg) | GhuiError::Other(msg) => {
+                write!(f, "{}", msg)
+            }
+            GhuiError::NotARepo => write!(f, "Not in a GitHub repository"),
+            GhuiError::NoResults => write!(f, "No pull requests found"),
+        }
+    }
+}
+
+impl std::error::Error for GhuiError {}
+
+/// Exit codes, for `--help`
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  Success
+  1  Other error
+  2  Invalid arguments
+  3  Authentication failed (set GH_TOKEN or run 'gh auth login')
+  4  Not in a GitHub repository
+  5  Network error (GitHub unreachable)
+  6  No results";
@@ -1,62 +1,118 @@
-//! Icons and emoji constants used throughout the UI.
+//! Icons and emoji glyphs used throughout the UI.
+//!
+//! Glyphs are looked up through functions so that ASCII mode (`--ascii` or
+//! `ascii = true` in config) can swap every icon for a plain equivalent on
+//! terminals without Nerd Font or Unicode symbol support.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ASCII_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Switch every icon to its ASCII equivalent
+pub fn set_ascii(enabled: bool) {
+    ASCII_MODE.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_ascii() -> bool {
+    ASCII_MODE.load(Ordering::Relaxed)
+}
+
+/// Best guess at whether the terminal can render Nerd Font glyphs. Fonts can't be
+/// queried, so this only rules out terminals known to ship without them.
+pub fn glyphs_likely_supported() -> bool {
+    let term = std::env::var("TERM").unwrap_or_default();
+    if matches!(term.as_str(), "" | "dumb" | "linux" | "vt100" | "vt220") {
+        return false;
+    }
+    // macOS Terminal.app defaults to a font without Nerd Font glyphs
+    std::env::var("TERM_PROGRAM").as_deref() != Ok("Apple_Terminal")
+}
+
+/// Define an icon lookup returning the glyph, or its ASCII fallback in ASCII mode
+macro_rules! icon {
+    ($name:ident, $glyph:expr, $ascii:expr) => {
+        pub fn $name() -> &'static str {
+            if is_ascii() {
+                $ascii
+            } else {
+                $glyph
+            }
+        }
+    };
+}
 
 // Spinner animation frames (braille characters)
 pub const SPINNER_FRAMES: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+pub const SPINNER_DOTS: &[&str] = &["·  ", "·· ", "···", " ··", "  ·", "   "];
+pub const SPINNER_LINE: &[&str] = &["─", "╲", "│", "╱"];
+pub const SPINNER_ASCII: &[&str] = &["-", "\\", "|", "/"];
 
 // CI Status icons
-pub const CI_PENDING: &str = "●";
-pub const CI_SUCCESS: &str = "✓";
-pub const CI_FAILURE: &str = "✗";
+icon!(ci_pending, "●", "*");
+icon!(ci_success, "✓", "+");
+icon!(ci_failure, "✗", "x");
 
 // CI Status display strings (icon + text)
-pub const CI_PENDING_DISPLAY: &str = "● Pending";
-pub const CI_SUCCESS_DISPLAY: &str = "✓ Passing";
-pub const CI_FAILURE_DISPLAY: &str = "✗ Failing";
+icon!(ci_pending_display, "● Pending", "[~] Pending");
+icon!(ci_success_display, "✓ Passing", "[+] Passing");
+icon!(ci_failure_display, "✗ Failing", "[x] Failing");
 
 // Selection/Navigation indicators
-pub const SELECTOR: &str = "▶ ";
-pub const SELECTOR_INDENTED: &str = "  ▶ ";
-pub const SELECTOR_MARKED: &str = "▶● ";
-pub const SELECTOR_ONLY: &str = "▶  ";
-pub const MARKED_ONLY: &str = " ● ";
-pub const UNMARKED: &str = "   ";
+icon!(selector, "▶ ", "> ");
+icon!(selector_indented, "  ▶ ", "  > ");
+icon!(selector_marked, "▶● ", ">* ");
+icon!(selector_only, "▶  ", ">  ");
+icon!(marked_only, " ● ", " * ");
+icon!(unmarked, "   ", "   ");
 
 // Cursor
-pub const CURSOR: &str = "█";
+icon!(cursor, "█", "_");
 
 // Status icons for workflows
-pub const STATUS_SUCCESS: &str = "✓";
-pub const STATUS_FAILURE: &str = "✗";
-pub const STATUS_CANCELLED: &str = "◯";
-pub const STATUS_SKIPPED: &str = "-";
-pub const STATUS_TIMED_OUT: &str = "⏱";
-pub const STATUS_ACTION_REQUIRED: &str = "!";
-pub const STATUS_IN_PROGRESS: &str = "●";
-pub const STATUS_QUEUED: &str = "○";
-pub const STATUS_WAITING: &str = "⋯";
-pub const STATUS_UNKNOWN: &str = "?";
+icon!(status_success, "✓", "+");
+icon!(status_failure, "✗", "x");
+icon!(status_cancelled, "◯", "o");
+icon!(status_skipped, "-", "-");
+icon!(status_timed_out, "⏱", "T");
+icon!(status_action_required, "!", "!");
+icon!(status_in_progress, "●", "*");
+icon!(status_queued, "○", ".");
+icon!(status_waiting, "⋯", "~");
+icon!(status_unknown, "?", "?");
 
 // Annotation level icons
-pub const ANNOTATION_FAILURE: &str = "  ";
-pub const ANNOTATION_WARNING: &str = "  ";
-pub const ANNOTATION_NOTICE: &str = "󰋽  ";
+icon!(annotation_failure, "  ", "x  ");
+icon!(annotation_warning, "  ", "!  ");
+icon!(annotation_notice, "󰋽  ", "i  ");
 
 // Annotation level labels (for formatted output)
-pub const ANNOTATION_FAILURE_LABEL: &str = " FAILURE";
-pub const ANNOTATION_WARNING_LABEL: &str = "  WARNING";
-pub const ANNOTATION_NOTICE_LABEL: &str = "󰋽 NOTICE";
+icon!(annotation_failure_label, " FAILURE", "[x] FAILURE");
+icon!(annotation_warning_label, "  WARNING", "[!] WARNING");
+icon!(annotation_notice_label, "󰋽 NOTICE", "[i] NOTICE");
 
 // Emoji icons for annotations
-pub const EMOJI_FILE: &str = "  ";
-pub const EMOJI_PIN: &str = "  ";
-pub const EMOJI_MESSAGE: &str = "󰻞  ";
+icon!(emoji_file, "  ", "File:");
+icon!(emoji_pin, "  ", "Title:");
+icon!(emoji_message, "󰻞  ", "Message:");
+
+// Fold indicators for expandable sections
+icon!(fold_expanded, "▼", "v");
+icon!(fold_collapsed, "▶", ">");
 
 // List/UI elements
-pub const BULLET: &str = "•";
-pub const SEPARATOR_CHAR: &str = "─";
+icon!(bullet, "•", "*");
+icon!(histogram_cell, "█", "#");
+icon!(warning, "⚠", "!");
+icon!(separator_char, "─", "-");
+icon!(ellipsis, "…", "...");
+icon!(placeholder, "—", "-");
+icon!(dash, "—", "-");
+icon!(middle_dot, "·", "-");
+icon!(vertical_separator, "│", "|");
+icon!(arrow_right, "→", ">");
 
 // Review state icons
-pub const REVIEW_APPROVED: &str = "✓";
-pub const REVIEW_CHANGES_REQUESTED: &str = "✗";
-pub const REVIEW_COMMENTED: &str = "󰆉";
-pub const REVIEW_DISMISSED: &str = "◯";
+icon!(review_approved, "✓", "+");
+icon!(review_changes_requested, "✗", "x");
+icon!(review_commented, "󰆉", "c");
+icon!(review_dismissed, "◯", "o");
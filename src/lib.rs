@@ -1,5 +1,7 @@
 pub mod app;
+pub mod cli;
 pub mod data;
+pub mod error;
 pub mod icons;
 pub mod services;
 pub mod utils;
@@ -7,5 +9,6 @@ pub mod view;
 
 pub use app::{update, App, Command, FetchResult, Message};
 pub use data::{PrFilter, PullRequest};
+pub use error::GhuiError;
 pub use services::cache::get_cache_path;
 pub use view::ui;
@@ -1,8 +1,9 @@
 use anyhow::Result;
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use crossterm::{
     event::{
-        self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+        self, DisableBracketedPaste, DisableFocusChange, DisableMouseCapture, EnableBracketedPaste,
+        EnableFocusChange, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
     },
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
@@ -10,13 +11,21 @@ use crossterm::{
 use ratatui::{backend::CrosstermBackend, Terminal};
 use std::{io, time::Duration};
 
+use ghui::app::{Action, TextEdit};
+use ghui::cli::{run_list, run_status, run_watch, FilterArg};
+use ghui::error::EXIT_CODES_HELP;
+use ghui::services::split_command;
 use ghui::{ui, update, App, Command, Message, PrFilter};
 
 /// A TUI for GitHub pull requests
 #[derive(Parser)]
 #[command(author, version, about, long_about = None)]
 #[command(disable_version_flag = true)]
+#[command(after_help = EXIT_CODES_HELP)]
 struct Cli {
+    #[command(subcommand)]
+    command: Option<CliCommand>,
+
     /// Print version
     #[arg(short = 'v', long = "version", action = clap::ArgAction::Version)]
     version: (),
@@ -24,6 +33,47 @@ struct Cli {
     /// Clear the local cache and exit
     #[arg(long)]
     clear_cache: bool,
+
+    /// Use plain ASCII instead of Nerd Font and Unicode glyphs
+    #[arg(long)]
+    ascii: bool,
+
+    /// Show the command checkout would run instead of running it
+    #[arg(long)]
+    dry_run: bool,
+}
+
+#[derive(Subcommand)]
+enum CliCommand {
+    /// Print pull requests without starting the TUI
+    #[command(after_help = EXIT_CODES_HELP)]
+    List {
+        /// Which list to print
+        #[arg(long, value_enum, default_value = "my-prs")]
+        filter: FilterArg,
+
+        /// Print JSON instead of tab-separated lines
+        #[arg(long)]
+        json: bool,
+    },
+    /// Poll pull requests and print a line when one appears, closes, or changes CI status
+    #[command(after_help = EXIT_CODES_HELP)]
+    Watch {
+        /// Which list to watch
+        #[arg(long, value_enum, default_value = "my-prs")]
+        filter: FilterArg,
+
+        /// Seconds between polls (defaults to refresh_interval_secs from config)
+        #[arg(long)]
+        interval: Option<u64>,
+    },
+    /// Print a one-line summary from the cache, for tmux and other status bars
+    #[command(after_help = EXIT_CODES_HELP)]
+    Status {
+        /// Fetch fresh data (and update the cache) instead of only reading the cache
+        #[arg(long)]
+        refresh: bool,
+    },
 }
 
 fn main() -> Result<()> {
@@ -41,16 +91,48 @@ fn main() -> Result<()> {
         return Ok(());
     }
 
+    if cli.ascii {
+        ghui::icons::set_ascii(true);
+    }
+
+    // Non-interactive commands report failures through exit codes
+    if let Some(command) = cli.command {
+        let result = match command {
+            CliCommand::List { filter, json } => run_list(filter, json),
+            CliCommand::Watch { filter, interval } => run_watch(filter, interval),
+            CliCommand::Status { refresh } => run_status(refresh),
+        };
+        if let Err(e) = result {
+            eprintln!("{}", e);
+            std::process::exit(e.exit_code());
+        }
+        return Ok(());
+    }
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(
+        stdout,
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    )?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new()?;
-    // Start fetching both lists
-    app.start_fetch(PrFilter::MyPrs);
-    app.start_fetch(PrFilter::ReviewRequested);
+    if cli.dry_run {
+        app.config.checkout_dry_run = true;
+    }
+    if app.config.refresh_on_focus {
+        execute!(terminal.backend_mut(), EnableFocusChange)?;
+    }
+    // Start fetching the configured tabs, unless the user only wants cached data until they refresh
+    if !app.config.offline_start {
+        for tab in app.config.prefetch_tabs.clone() {
+            app.start_fetch(tab.filter());
+        }
+    }
 
     let res = run_app(&mut terminal, &mut app);
 
@@ -58,7 +140,9 @@ fn main() -> Result<()> {
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
-        DisableMouseCapture
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        DisableFocusChange
     )?;
     terminal.show_cursor()?;
 
@@ -98,6 +182,15 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
             }
         }
 
+        // Check for annotations fetch results
+        if let Some(result) = app.check_annotations_result() {
+            if let Some(cmd) = update(app, Message::AnnotationsReceived(result)) {
+                if handle_command(app, cmd, terminal) {
+                    return Ok(());
+                }
+            }
+        }
+
         // Check for CircleCI job logs fetch results
         if let Some(result) = app.check_circleci_logs_result() {
             if let Some(cmd) = update(app, Message::JobLogsReceived(result)) {
@@ -107,6 +200,42 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
             }
         }
 
+        // Check for merge results
+        if let Some(result) = app.check_merge_result() {
+            if let Some(cmd) = update(app, Message::MergeResultReceived(result)) {
+                if handle_command(app, cmd, terminal) {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Check for single-PR CI status results
+        if let Some(result) = app.check_ci_status_result() {
+            if let Some(cmd) = update(app, Message::CiStatusReceived(result)) {
+                if handle_command(app, cmd, terminal) {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Check for checks popup results
+        if let Some(result) = app.check_checks_result() {
+            if let Some(cmd) = update(app, Message::ChecksReceived(result)) {
+                if handle_command(app, cmd, terminal) {
+                    return Ok(());
+                }
+            }
+        }
+
+        // Check for workflow re-run/cancel results
+        if let Some(result) = app.check_run_action_result() {
+            if let Some(cmd) = update(app, Message::RunActionResultReceived(result)) {
+                if handle_command(app, cmd, terminal) {
+                    return Ok(());
+                }
+            }
+        }
+
         // Check for preview fetch results
         if let Some(result) = app.check_preview_result() {
             if let Some(cmd) = update(app, Message::PreviewDataReceived(result)) {
@@ -134,6 +263,15 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
             }
         }
 
+        // Treat a resume from suspend like the terminal regaining focus
+        if app.check_resumed() {
+            if let Some(cmd) = update(app, Message::FocusGained) {
+                if handle_command(app, cmd, terminal) {
+                    return Ok(());
+                }
+            }
+        }
+
         // Update spinner
         if let Some(cmd) = update(app, Message::Tick) {
             if handle_command(app, cmd, terminal) {
@@ -142,19 +280,23 @@ fn run_app(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, app: &mut App)
         }
 
         // Draw UI
-        terminal.draw(|f| ui(f, app))?;
+        let frame = terminal.draw(|f| ui(f, app))?;
+        app.terminal_height = frame.area.height;
 
         // Handle input
         if event::poll(Duration::from_millis(50))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    let msg = key_to_message(app, key.code, key.modifiers);
-                    if let Some(msg) = msg {
-                        if let Some(cmd) = update(app, msg) {
-                            if handle_command(app, cmd, terminal) {
-                                return Ok(());
-                            }
-                        }
+            let msg = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    key_to_message(app, key.code, key.modifiers)
+                }
+                Event::FocusGained => Some(Message::FocusGained),
+                Event::Paste(text) => Some(Message::Paste(text)),
+                _ => None,
+            };
+            if let Some(msg) = msg {
+                if let Some(cmd) = update(app, msg) {
+                    if handle_command(app, cmd, terminal) {
+                        return Ok(());
                     }
                 }
             }
@@ -183,22 +325,135 @@ fn handle_command(
             app.start_job_logs_fetch(&owner, &repo, job_id, &job_name);
             false
         }
-        Command::StartPreviewFetch(owner, repo, pr_number) => {
-            app.start_preview_fetch(&owner, &repo, pr_number);
+        Command::StartPreviewFetch(owner, repo, pr_number, max_comments) => {
+            app.start_preview_fetch(&owner, &repo, pr_number, max_comments);
             false
         }
         Command::StartCircleCIJobLogsFetch(owner, repo, job_number, job_name) => {
             app.start_circleci_logs_fetch(&owner, &repo, job_number, &job_name);
             false
         }
+        Command::StartAnnotationsFetch(owner, repo, check_run_id) => {
+            app.start_annotations_fetch(&owner, &repo, check_run_id);
+            false
+        }
+        Command::StartMerge(owner, repo, pr_number, method) => {
+            app.start_merge(&owner, &repo, pr_number, method);
+            false
+        }
+        Command::StartCiStatusFetch(owner, repo, pr_number) => {
+            app.start_ci_status_fetch(&owner, &repo, pr_number);
+            false
+        }
+        Command::StartChecksFetch(owner, repo, pr_number) => {
+            app.start_checks_fetch(&owner, &repo, pr_number);
+            false
+        }
+        Command::StartRunAction(owner, repo, target, action) => {
+            app.start_run_action(&owner, &repo, target, action);
+            false
+        }
         Command::OpenInEditor(content, filename) => {
             open_in_editor(app, terminal, &content, &filename);
             false
         }
+        Command::OpenInPager(path) => {
+            open_in_pager(app, terminal, &path);
+            false
+        }
+        Command::OpenFileAtLine(path, line) => {
+            open_file_at_line(app, terminal, &path, line);
+            false
+        }
+    }
+}
+
+/// Leave the alternate screen and raw mode, run `f`, then restore the terminal.
+/// Use this for anything that hands the terminal to an external program.
+fn with_suspended_terminal<T>(
+    app: &App,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    f: impl FnOnce() -> T,
+) -> T {
+    // Leave alternate screen and disable raw mode
+    let _ = disable_raw_mode();
+    let _ = execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture,
+        DisableBracketedPaste,
+        DisableFocusChange
+    );
+
+    let result = f();
+
+    // Re-enter alternate screen and enable raw mode
+    let _ = enable_raw_mode();
+    let _ = execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture,
+        EnableBracketedPaste
+    );
+    if app.config.refresh_on_focus {
+        let _ = execute!(terminal.backend_mut(), EnableFocusChange);
+    }
+    // Force a full redraw
+    let _ = terminal.clear();
+
+    result
+}
+
+/// Run a configured command string (program plus optional args) on a file
+fn run_external(cmd: &str, path: &std::path::Path) -> io::Result<std::process::ExitStatus> {
+    let (program, args) = split_command(cmd)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "empty command"))?;
+    std::process::Command::new(program)
+        .args(args)
+        .arg(path)
+        .status()
+}
+
+/// Open a file in the configured pager, suspending the TUI.
+/// The file is left on disk so its path can be reused after returning.
+fn open_in_pager(app: &mut App, terminal: &mut Terminal<CrosstermBackend<io::Stdout>>, path: &str) {
+    let pager = app.config.pager_command();
+
+    let result = with_suspended_terminal(app, terminal, || {
+        run_external(&pager, std::path::Path::new(path))
+    });
+
+    if let Err(e) = result {
+        app.error = Some(format!(
+            "Failed to open {}: {}\nLogs saved to {}",
+            pager, e, path
+        ));
+        app.show_error_popup = true;
+    }
+}
+
+/// Open a local file at a line with the `editor_goto` command, suspending the TUI
+fn open_file_at_line(
+    app: &mut App,
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    path: &str,
+    line: u32,
+) {
+    let Some((program, args)) = app.config.editor_goto_for(path, line) else {
+        return;
+    };
+
+    let result = with_suspended_terminal(app, terminal, || {
+        std::process::Command::new(&program).args(&args).status()
+    });
+
+    if let Err(e) = result {
+        app.clipboard_feedback = Some(format!("Failed to open {}: {}", program, e));
+        app.clipboard_feedback_time = std::time::Instant::now();
     }
 }
 
-/// Open content in $EDITOR, properly suspending and restoring the TUI
+/// Open content in the configured editor, properly suspending and restoring the TUI
 fn open_in_editor(
     app: &mut App,
     terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
@@ -215,31 +470,10 @@ fn open_in_editor(
         return;
     }
 
-    // Get editor from $EDITOR or fall back to common editors
-    let editor = std::env::var("EDITOR")
-        .or_else(|_| std::env::var("VISUAL"))
-        .unwrap_or_else(|_| "vim".to_string());
-
-    // Leave alternate screen and disable raw mode
-    let _ = disable_raw_mode();
-    let _ = execute!(
-        terminal.backend_mut(),
-        LeaveAlternateScreen,
-        DisableMouseCapture
-    );
+    let editor = app.config.editor_command();
 
     // Open editor and wait for it to finish
-    let result = std::process::Command::new(&editor).arg(&temp_file).status();
-
-    // Re-enter alternate screen and enable raw mode
-    let _ = enable_raw_mode();
-    let _ = execute!(
-        terminal.backend_mut(),
-        EnterAlternateScreen,
-        EnableMouseCapture
-    );
-    // Force a full redraw
-    let _ = terminal.clear();
+    let result = with_suspended_terminal(app, terminal, || run_external(&editor, &temp_file));
 
     // Handle result and clean up
     match result {
@@ -254,18 +488,58 @@ fn open_in_editor(
     }
 }
 
+/// Map a key press to an edit for a text input
+fn text_edit(key: KeyCode, modifiers: KeyModifiers) -> Option<TextEdit> {
+    match key {
+        KeyCode::Char('w') if modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(TextEdit::DeleteWord)
+        }
+        KeyCode::Backspace if modifiers.contains(KeyModifiers::ALT) => Some(TextEdit::DeleteWord),
+        KeyCode::Char('a') if modifiers.contains(KeyModifiers::CONTROL) => Some(TextEdit::Home),
+        KeyCode::Char('e') if modifiers.contains(KeyModifiers::CONTROL) => Some(TextEdit::End),
+        KeyCode::Char('u') if modifiers.contains(KeyModifiers::CONTROL) => {
+            Some(TextEdit::ClearLine)
+        }
+        KeyCode::Char(_) if modifiers.contains(KeyModifiers::CONTROL) => None,
+        KeyCode::Char(c) => Some(TextEdit::Insert(c)),
+        KeyCode::Backspace => Some(TextEdit::Backspace),
+        KeyCode::Delete => Some(TextEdit::Delete),
+        KeyCode::Left => Some(TextEdit::Left),
+        KeyCode::Right => Some(TextEdit::Right),
+        KeyCode::Home => Some(TextEdit::Home),
+        KeyCode::End => Some(TextEdit::End),
+        KeyCode::Up => Some(TextEdit::HistoryPrevious),
+        KeyCode::Down => Some(TextEdit::HistoryNext),
+        _ => None,
+    }
+}
+
 /// Convert a key press to a message based on current app state
 fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Message> {
-    // Help popup - any key dismisses
+    // Help popup - j/k scroll it when it doesn't fit, any other key dismisses
     if app.show_help_popup {
-        return Some(Message::DismissHelp);
+        return match key {
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::HelpScrollDown),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::HelpScrollUp),
+            _ => Some(Message::DismissHelp),
+        };
     }
 
-    // Checkout popup
-    if app.show_checkout_popup {
+    // Confirmation prompt (checkout, cancel run, ...)
+    if app.confirmation.is_some() {
         return match key {
-            KeyCode::Char('y') | KeyCode::Enter => Some(Message::ConfirmCheckout),
-            KeyCode::Char('n') | KeyCode::Esc => Some(Message::CancelCheckout),
+            KeyCode::Char('y') | KeyCode::Enter => Some(Message::Confirm),
+            KeyCode::Char('n') | KeyCode::Esc => Some(Message::DismissConfirmation),
+            _ => None,
+        };
+    }
+
+    // Merge popup
+    if app.show_merge_popup {
+        return match key {
+            KeyCode::Char('y') | KeyCode::Enter => Some(Message::ConfirmMerge),
+            KeyCode::Char('n') | KeyCode::Esc => Some(Message::CancelMerge),
+            KeyCode::Tab | KeyCode::Char('m') => Some(Message::CycleMergeMethod),
             _ => None,
         };
     }
@@ -278,6 +552,17 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
         };
     }
 
+    // Missing-glyphs warning
+    if app.show_glyph_warning_popup {
+        return match key {
+            KeyCode::Char('a') => Some(Message::DismissGlyphWarning { ascii: true }),
+            KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => {
+                Some(Message::DismissGlyphWarning { ascii: false })
+            }
+            _ => None,
+        };
+    }
+
     // URL popup (shown in containers when we can't open browser)
     if app.show_url_popup.is_some() {
         return match key {
@@ -294,8 +579,15 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
                 KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseJobLogs),
                 KeyCode::Char('j') | KeyCode::Down => Some(Message::AnnotationNext),
                 KeyCode::Char('k') | KeyCode::Up => Some(Message::AnnotationPrevious),
+                KeyCode::Char('}') => Some(Message::AnnotationNextFile),
+                KeyCode::Char('{') => Some(Message::AnnotationPreviousFile),
                 KeyCode::Char('v') | KeyCode::Char(' ') => Some(Message::ToggleAnnotationSelection),
                 KeyCode::Char('y') => Some(Message::CopyAnnotations),
+                KeyCode::Char('Y') => Some(Message::CopyAnnotationsGrouped),
+                KeyCode::Char('p') => Some(Message::CopyAnnotationLocation),
+                KeyCode::Enter => Some(Message::OpenAnnotationInEditor),
+                KeyCode::Char('s') => Some(Message::CycleAnnotationSort),
+                KeyCode::Char('a') => Some(Message::ToggleAnnotationsView),
                 KeyCode::Char('o') => Some(Message::OpenActionsInBrowser),
                 _ => None,
             };
@@ -315,9 +607,12 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
                 KeyCode::Char('j') | KeyCode::Down => Some(Message::JobLogsNextStep),
                 KeyCode::Char('k') | KeyCode::Up => Some(Message::JobLogsPrevStep),
                 KeyCode::Char(' ') => Some(Message::JobLogsToggleStep),
+                KeyCode::Char('f') => Some(Message::JobLogsJumpToFailure),
                 KeyCode::Enter => Some(Message::OpenStepInEditor),
                 KeyCode::Char('y') => Some(Message::CopyTestFailures),
                 KeyCode::Char('x') => Some(Message::FullCopyStepOutput),
+                KeyCode::Char('s') => Some(Message::SaveJobLogs),
+                KeyCode::Char('a') => Some(Message::ToggleAnnotationsView),
                 KeyCode::Char('o') => Some(Message::OpenActionsInBrowser),
                 _ => None,
             };
@@ -328,13 +623,28 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
             KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseJobLogs),
             KeyCode::Char('j') | KeyCode::Down => Some(Message::JobLogsScrollDown),
             KeyCode::Char('k') | KeyCode::Up => Some(Message::JobLogsScrollUp),
+            KeyCode::Char('f') => Some(Message::JobLogsJumpToFailure),
             KeyCode::Char('y') => Some(Message::CopyTestFailures),
             KeyCode::Char('x') => Some(Message::FullCopyStepOutput),
+            KeyCode::Char('s') => Some(Message::SaveJobLogs),
+            KeyCode::Char('a') => Some(Message::ToggleAnnotationsView),
             KeyCode::Char('o') => Some(Message::OpenActionsInBrowser),
             _ => None,
         };
     }
 
+    // Re-run confirmation (over the workflows view)
+    if app.pending_rerun.is_some() {
+        return match key {
+            KeyCode::Char('a') | KeyCode::Enter => {
+                Some(Message::ConfirmRerun { failed_only: false })
+            }
+            KeyCode::Char('f') => Some(Message::ConfirmRerun { failed_only: true }),
+            KeyCode::Char('n') | KeyCode::Esc => Some(Message::CancelRerun),
+            _ => None,
+        };
+    }
+
     // Workflows view
     if app.show_workflows_view {
         return match key {
@@ -343,6 +653,12 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
             KeyCode::Char('k') | KeyCode::Up => Some(Message::ActionsPreviousJob),
             KeyCode::Char('r') => Some(Message::RefreshActions),
             KeyCode::Char('o') => Some(Message::OpenActionsInBrowser),
+            KeyCode::Char(' ') => Some(Message::ToggleJobSummary),
+            KeyCode::Char('t') => Some(Message::CycleProviderFilter),
+            KeyCode::Char('y') => Some(Message::CopyHeadSha),
+            KeyCode::Char('Y') => Some(Message::CopyRunUrl),
+            KeyCode::Char('R') => Some(Message::PromptRerun),
+            KeyCode::Char('X') => Some(Message::PromptCancelRun),
             KeyCode::Enter => Some(Message::OpenJobLogs),
             _ => None,
         };
@@ -364,7 +680,9 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
             KeyCode::Char('k') | KeyCode::Up => Some(Message::PreviewScrollUp),
             KeyCode::Char('g') => Some(Message::PreviewGoToTop),
             KeyCode::Char('G') => Some(Message::PreviewGoToBottom),
+            KeyCode::Char('L') => Some(Message::PreviewLoadMore),
             KeyCode::Char('o') => Some(Message::OpenSelected),
+            KeyCode::Char('y') => Some(Message::CopyHeadSha),
             _ => None,
         };
     }
@@ -374,19 +692,86 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
         return match key {
             KeyCode::Esc => Some(Message::CloseAddLabelPopup),
             KeyCode::Enter => Some(Message::AddLabel),
-            KeyCode::Backspace => Some(Message::LabelBackspace),
             KeyCode::Tab => Some(Message::ToggleLabelScope),
-            KeyCode::Char(c) => Some(Message::LabelInput(c)),
+            _ => text_edit(key, modifiers).map(Message::LabelEdit),
+        };
+    }
+
+    // Add saved search popup
+    if app.show_add_saved_search_popup {
+        return match key {
+            KeyCode::Esc => Some(Message::CloseAddSavedSearchPopup),
+            KeyCode::Enter => Some(Message::AddSavedSearch),
+            KeyCode::Tab | KeyCode::BackTab => Some(Message::ToggleSavedSearchField),
+            _ => text_edit(key, modifiers).map(Message::SavedSearchEdit),
+        };
+    }
+
+    // Saved searches popup
+    if app.show_saved_searches_popup {
+        return match key {
+            KeyCode::Esc => Some(Message::CloseSavedSearchesPopup),
+            KeyCode::Enter => Some(Message::ActivateSavedSearch),
+            KeyCode::Char('a') => Some(Message::OpenAddSavedSearchPopup),
+            KeyCode::Char('d') | KeyCode::Backspace => Some(Message::DeleteSelectedSavedSearch),
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::SavedSearchesNext),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::SavedSearchesPrevious),
             _ => None,
         };
     }
 
+    // Recent checkouts popup
+    if app.show_recent_checkouts_popup {
+        return match key {
+            KeyCode::Esc | KeyCode::Char('q') => Some(Message::CloseRecentCheckoutsPopup),
+            KeyCode::Enter => app
+                .recent_checkouts_list_state
+                .selected()
+                .map(Message::CheckoutRecent),
+            KeyCode::Char(c @ '1'..='9') => {
+                Some(Message::CheckoutRecent(c as usize - '1' as usize))
+            }
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::RecentCheckoutsNext),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::RecentCheckoutsPrevious),
+            _ => None,
+        };
+    }
+
+    // Checks popup
+    if app.show_checks_popup {
+        return match key {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('x') => {
+                Some(Message::CloseChecksPopup)
+            }
+            KeyCode::Char('j') | KeyCode::Down => Some(Message::ChecksNext),
+            KeyCode::Char('k') | KeyCode::Up => Some(Message::ChecksPrevious),
+            KeyCode::Char('o') | KeyCode::Enter => Some(Message::OpenCheckUrl),
+            _ => None,
+        };
+    }
+
+    // Labels popup search
+    if app.show_labels_popup && app.labels_search_mode {
+        return match key {
+            KeyCode::Esc => Some(Message::LabelsExitSearch { clear: true }),
+            KeyCode::Enter => Some(Message::LabelsExitSearch { clear: false }),
+            KeyCode::Down | KeyCode::Tab => Some(Message::LabelsNext),
+            KeyCode::Up | KeyCode::BackTab => Some(Message::LabelsPrevious),
+            _ => text_edit(key, modifiers).map(Message::LabelsSearchEdit),
+        };
+    }
+
     // Labels popup
     if app.show_labels_popup {
         return match key {
+            KeyCode::Esc if !app.labels_search_query.is_empty() => {
+                Some(Message::LabelsExitSearch { clear: true })
+            }
             KeyCode::Esc => Some(Message::CloseLabelsPopup),
+            KeyCode::Char('/') => Some(Message::LabelsEnterSearch),
             KeyCode::Char('a') => Some(Message::OpenAddLabelPopup),
             KeyCode::Char('d') | KeyCode::Backspace => Some(Message::DeleteSelectedLabel),
+            KeyCode::Enter => Some(Message::FocusSelectedLabel),
             KeyCode::Char('j') | KeyCode::Down => Some(Message::LabelsNext),
             KeyCode::Char('k') | KeyCode::Up => Some(Message::LabelsPrevious),
             _ => None,
@@ -398,43 +783,68 @@ fn key_to_message(app: &App, key: KeyCode, modifiers: KeyModifiers) -> Option<Me
         return match key {
             KeyCode::Esc => Some(Message::ExitSearchMode { clear: true }),
             KeyCode::Enter => Some(Message::ExitSearchMode { clear: false }),
-            KeyCode::Backspace => Some(Message::SearchBackspace),
-            KeyCode::Char(c) => Some(Message::SearchInput(c)),
             KeyCode::Down | KeyCode::Tab => Some(Message::NextItem),
             KeyCode::Up | KeyCode::BackTab => Some(Message::PreviousItem),
-            _ => None,
+            _ => text_edit(key, modifiers).map(Message::SearchEdit),
         };
     }
 
-    // Normal mode
-    match key {
-        KeyCode::Char('q') => Some(Message::Quit),
-        KeyCode::Char('/') => Some(Message::EnterSearchMode),
-        KeyCode::Esc => {
-            if !app.search_query.is_empty() {
-                Some(Message::ExitSearchMode { clear: true })
-            } else {
-                None
-            }
+    // Type-ahead jump: characters extend the prefix, anything else is handled normally
+    if app.type_ahead.is_some() {
+        match key {
+            KeyCode::Esc => return Some(Message::CancelTypeAhead),
+            KeyCode::Char(c) => return Some(Message::TypeAheadInput(c)),
+            _ => {}
         }
-        KeyCode::Char('j') | KeyCode::Down => Some(Message::NextItem),
-        KeyCode::Char('k') | KeyCode::Up => Some(Message::PreviousItem),
-        KeyCode::Char('o') => Some(Message::OpenSelected),
-        KeyCode::Enter => Some(Message::OpenPreviewView),
-        KeyCode::Char('c') => Some(Message::PromptCheckout),
-        KeyCode::Char('r') => Some(Message::Refresh),
-        KeyCode::Char('?') => Some(Message::ToggleHelp),
-        KeyCode::Char('l') => Some(Message::OpenLabelsPopup),
-        KeyCode::Char('w') => Some(Message::OpenWorkflowsView),
-        KeyCode::Char('p') => Some(Message::OpenPreviewView),
-        KeyCode::Char('1') => Some(Message::SwitchTab(PrFilter::MyPrs)),
-        KeyCode::Char('2') => Some(Message::SwitchTab(PrFilter::ReviewRequested)),
-        KeyCode::Char('3') => {
-            let labels = app.get_active_labels();
-            Some(Message::SwitchTab(PrFilter::Labels(labels)))
-        }
-        KeyCode::Char('g') => Some(Message::GoToTop),
-        KeyCode::Char('G') => Some(Message::GoToBottom),
-        _ => None,
     }
+
+    // Normal mode: Esc clears a search, everything else goes through the keymap
+    if key == KeyCode::Esc && !app.search_query.is_empty() {
+        return Some(Message::ExitSearchMode { clear: true });
+    }
+    let message = match app.keymap.action(key)? {
+        Action::Quit => Message::Quit,
+        Action::Search => Message::EnterSearchMode,
+        Action::TypeAhead => Message::StartTypeAhead,
+        Action::NextItem => Message::NextItem,
+        Action::PreviousItem => Message::PreviousItem,
+        Action::Open => Message::OpenSelected,
+        Action::OpenRepoPulls => Message::OpenRepoPulls,
+        Action::CopyHeadSha => Message::CopyHeadSha,
+        Action::CopyGhCommand => Message::CopyGhCommand,
+        Action::CopyTitle => Message::CopyTitle,
+        Action::CopyChangelogEntry => Message::CopyChangelogEntry,
+        Action::ToggleMark => Message::ToggleMarkPr,
+        Action::ToggleDone => Message::ToggleDone,
+        Action::ToggleHideDone => Message::ToggleHideDone,
+        Action::ClearDone => Message::PromptClearDone,
+        Action::CopyChecklist => Message::CopyChecklist,
+        Action::ToggleReadyToMerge => Message::ToggleReadyToMerge,
+        Action::CycleMilestone => Message::CycleMilestoneFilter,
+        Action::CycleSort => Message::CyclePrSort,
+        Action::ToggleHideApproved => Message::ToggleHideApproved,
+        Action::Merge => Message::MergePr,
+        Action::Preview => Message::OpenPreviewView,
+        Action::Checkout => Message::PromptCheckout,
+        Action::Refresh => Message::Refresh,
+        Action::RefreshCi => Message::RefreshSelectedCi,
+        Action::Help => Message::ToggleHelp,
+        Action::Labels => Message::OpenLabelsPopup,
+        Action::SavedSearches => Message::OpenSavedSearchesPopup,
+        Action::RecentCheckouts => Message::OpenRecentCheckoutsPopup,
+        Action::Checks => Message::OpenChecksPopup,
+        Action::Workflows => Message::OpenWorkflowsView,
+        Action::TabMyPrs => Message::SwitchTab(PrFilter::MyPrs),
+        Action::TabReviewRequested => Message::SwitchTab(PrFilter::ReviewRequested),
+        Action::TabLabels => Message::SwitchTab(PrFilter::Labels(app.get_active_labels())),
+        Action::TabAll => Message::SwitchTab(PrFilter::All),
+        Action::TabTeam => Message::NextTeamTab,
+        Action::TabRecent => Message::SwitchTab(PrFilter::Recent),
+        Action::GrowColumn => Message::GrowColumn,
+        Action::ShrinkColumn => Message::ShrinkColumn,
+        Action::CycleResizeColumn => Message::CycleResizeColumn,
+        Action::GoToTop => Message::GoToTop,
+        Action::GoToBottom => Message::GoToBottom,
+    };
+    Some(message)
 }
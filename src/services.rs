@@ -1,17 +1,34 @@
 pub mod cache;
 pub mod circleci;
+pub mod config;
 pub mod github;
 pub mod search;
+pub mod state;
 
 pub use cache::{
-    delete_label_filter, load_cache, load_label_filters, save_cache, save_label_filter,
+    cache_disabled, cache_saved_at, clear_done_prs, delete_label_filter, delete_saved_search,
+    load_cache, load_cache_for_display, load_done_prs, load_global_label_filters,
+    load_label_filters, load_recent_checkouts, load_saved_searches, save_cache, save_done_pr,
+    save_label_filter, save_recent_checkout, save_saved_search,
 };
 pub use circleci::{
-    debug_log as circleci_debug_log, extract_job_number_from_url, fetch_circleci_job_logs,
-    get_circleci_token, is_circleci_configured, is_circleci_url,
+    cancel_circleci_workflow, debug_log as circleci_debug_log, extract_job_number_from_url,
+    fetch_circleci_job_logs, get_circleci_token, is_circleci_configured, is_circleci_url,
+    rerun_circleci_workflow,
+};
+pub use config::{
+    get_config_dir, load_config, load_global_config, parse_team, split_command, ColumnWidths,
+    Config, ConfirmConfig, HighlightConfig, HighlightPreset, MergeConfig, MinSizeConfig,
+    PrefetchTab, SearchField, SpinnerConfig, SpinnerStyle, StalenessConfig, ThemeConfig,
+    TruncationStyle,
 };
 pub use github::{
-    fetch_actions_for_pr, fetch_job_logs, fetch_pr_preview, fetch_prs_graphql, get_current_user,
-    get_github_token,
+    cancel_workflow_run, check_graphql_errors, explain_sso_error, fetch_actions_for_pr,
+    fetch_check_contexts, fetch_job_annotations, fetch_job_logs, fetch_pr_ci_status,
+    fetch_pr_preview, fetch_prs_graphql, get_current_user, get_github_token, is_network_error,
+    is_rate_limited, is_sso_required, is_unauthorized, merge_pr, parse_actions_response,
+    parse_check_contexts, parse_search_response, rerun_failed_jobs, rerun_workflow,
+    with_github_token, MAX_PREVIEW_COMMENTS,
 };
-pub use search::filter_prs;
+pub use search::{filter_labels, filter_prs, MatchPositions};
+pub use state::{load_state, update_state, SavedColumnWidths, State};
@@ -2,15 +2,75 @@ use anyhow::Result;
 use rusqlite::Connection;
 use sea_query::{Cond, Expr, Index, Query, SqliteQueryBuilder, Table};
 use sea_query_rusqlite::RusqliteBinder;
-use std::path::PathBuf;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
 use crate::data::{
-    CacheMeta, LabelFilter, LabelFiltersTable, PrFilter, PullRequest, PullRequestsTable,
-    CACHE_VERSION,
+    CacheMeta, DonePrsTable, LabelFilter, LabelFiltersTable, Mergeable, PrFilter, PullRequest,
+    PullRequestsTable, RecentCheckout, RecentCheckoutsTable, ReviewDecision, ReviewState,
+    SavedSearch, SavedSearchesTable, CACHE_VERSION,
 };
+use crate::services::config::{get_config_dir, load_config};
+use crate::utils::now_timestamp;
 
 pub fn get_cache_path() -> Option<PathBuf> {
-    dirs::config_dir().map(|p| p.join("ghui").join("cache.db"))
+    get_config_dir().map(|p| p.join("cache.db"))
+}
+
+/// The config's cache settings, read once rather than on every cache access
+struct CacheSettings {
+    no_cache: bool,
+    keep_details: bool,
+}
+
+fn cache_settings() -> &'static CacheSettings {
+    static SETTINGS: OnceLock<CacheSettings> = OnceLock::new();
+    SETTINGS.get_or_init(|| {
+        let config = load_config().ok();
+        CacheSettings {
+            no_cache: config.as_ref().is_some_and(|config| config.no_cache),
+            keep_details: config.as_ref().is_none_or(|config| config.cache_pr_details),
+        }
+    })
+}
+
+/// Whether fetched PRs (titles, branches, checkouts) stay in memory instead of
+/// being written to `cache.db`: `GHUI_NO_CACHE` set to anything but "" or "0",
+/// or `no_cache` in the config
+pub fn cache_disabled() -> bool {
+    match std::env::var("GHUI_NO_CACHE") {
+        Ok(value) => !value.is_empty() && value != "0",
+        Err(_) => cache_settings().no_cache,
+    }
+}
+
+/// Open the cache, keeping it readable by its owner only whatever the umask:
+/// it holds titles and branches from possibly private repositories. SQLite
+/// creates its -journal and -wal files with the database's mode.
+fn open_cache(path: &Path) -> Result<Connection> {
+    restrict_permissions(path)?;
+    Ok(Connection::open(path)?)
+}
+
+/// Create the file with mode 0600 before SQLite does, or tighten an existing one
+#[cfg(unix)]
+fn restrict_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+
+    std::fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .mode(0o600)
+        .open(path)?;
+    std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600))?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn restrict_permissions(_path: &Path) -> Result<()> {
+    Ok(())
 }
 
 pub fn init_db(conn: &Connection) -> Result<()> {
@@ -42,18 +102,21 @@ pub fn init_db(conn: &Connection) -> Result<()> {
         .ok();
 
     if current_version != Some(CACHE_VERSION) {
-        // Clear old cache
+        // Clear old cached PRs. Tables the user filled in (label filters, saved
+        // searches, recent checkouts, done marks) are kept; add columns to them
+        // with ALTER TABLE instead.
         let drop_pr_sql = Table::drop()
             .table(PullRequestsTable::Table)
             .if_exists()
             .build(SqliteQueryBuilder);
         let _ = conn.execute(&drop_pr_sql, []);
 
-        let drop_labels_sql = Table::drop()
-            .table(LabelFiltersTable::Table)
-            .if_exists()
-            .build(SqliteQueryBuilder);
-        let _ = conn.execute(&drop_labels_sql, []);
+        // Along with when they were saved
+        let (clear_saved_sql, clear_saved_values) = Query::delete()
+            .from_table(CacheMeta::Table)
+            .and_where(Expr::col(CacheMeta::Key).like("saved_at:%"))
+            .build_rusqlite(SqliteQueryBuilder);
+        let _ = conn.execute(&clear_saved_sql, &*clear_saved_values.as_params());
 
         // Upsert version
         let (upsert_sql, upsert_values) = Query::insert()
@@ -114,6 +177,15 @@ pub fn init_db(conn: &Connection) -> Result<()> {
                 .not_null()
                 .default(""),
         )
+        .col(sea_query::ColumnDef::new(PullRequestsTable::Milestone).text())
+        .col(sea_query::ColumnDef::new(PullRequestsTable::UpdatedAt).integer())
+        .col(sea_query::ColumnDef::new(PullRequestsTable::CreatedAt).integer())
+        .col(
+            sea_query::ColumnDef::new(PullRequestsTable::IsDraft)
+                .boolean()
+                .not_null()
+                .default(false),
+        )
         .primary_key(
             Index::create()
                 .col(PullRequestsTable::Number)
@@ -157,16 +229,128 @@ pub fn init_db(conn: &Connection) -> Result<()> {
         .build(SqliteQueryBuilder);
     conn.execute(&index_sql, [])?;
 
+    // Create saved_searches table
+    let searches_sql = Table::create()
+        .table(SavedSearchesTable::Table)
+        .if_not_exists()
+        .col(
+            sea_query::ColumnDef::new(SavedSearchesTable::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            sea_query::ColumnDef::new(SavedSearchesTable::Name)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(SavedSearchesTable::Query)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(SavedSearchesTable::RepoOwner)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(SavedSearchesTable::RepoName)
+                .text()
+                .not_null(),
+        )
+        .build(SqliteQueryBuilder);
+    conn.execute(&searches_sql, [])?;
+
+    // Create unique index on saved_searches
+    let searches_index_sql = Index::create()
+        .if_not_exists()
+        .name("idx_saved_searches_unique")
+        .table(SavedSearchesTable::Table)
+        .col(SavedSearchesTable::Name)
+        .col(SavedSearchesTable::RepoOwner)
+        .col(SavedSearchesTable::RepoName)
+        .unique()
+        .build(SqliteQueryBuilder);
+    conn.execute(&searches_index_sql, [])?;
+
+    // Create recent_checkouts table, one row per branch per repo
+    let checkouts_sql = Table::create()
+        .table(RecentCheckoutsTable::Table)
+        .if_not_exists()
+        .col(
+            sea_query::ColumnDef::new(RecentCheckoutsTable::Branch)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(RecentCheckoutsTable::PrNumber)
+                .integer()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(RecentCheckoutsTable::RepoOwner)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(RecentCheckoutsTable::RepoName)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(RecentCheckoutsTable::CheckedOutAt)
+                .integer()
+                .not_null(),
+        )
+        .primary_key(
+            Index::create()
+                .col(RecentCheckoutsTable::Branch)
+                .col(RecentCheckoutsTable::RepoOwner)
+                .col(RecentCheckoutsTable::RepoName),
+        )
+        .build(SqliteQueryBuilder);
+    conn.execute(&checkouts_sql, [])?;
+
+    // Create done_prs table, PRs marked done locally for triage
+    let done_sql = Table::create()
+        .table(DonePrsTable::Table)
+        .if_not_exists()
+        .col(
+            sea_query::ColumnDef::new(DonePrsTable::PrNumber)
+                .integer()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(DonePrsTable::RepoOwner)
+                .text()
+                .not_null(),
+        )
+        .col(
+            sea_query::ColumnDef::new(DonePrsTable::RepoName)
+                .text()
+                .not_null(),
+        )
+        .primary_key(
+            Index::create()
+                .col(DonePrsTable::PrNumber)
+                .col(DonePrsTable::RepoOwner)
+                .col(DonePrsTable::RepoName),
+        )
+        .build(SqliteQueryBuilder);
+    conn.execute(&done_sql, [])?;
+
     Ok(())
 }
 
 pub fn load_cache(owner: &str, repo: &str, filter: PrFilter) -> Result<Vec<PullRequest>> {
     let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
-    if !path.exists() {
+    if !path.exists() || cache_disabled() {
         return Ok(Vec::new());
     }
 
-    let conn = Connection::open(&path)?;
+    let conn = open_cache(&path)?;
     init_db(&conn)?;
 
     let (sql, values) = Query::select()
@@ -178,11 +362,15 @@ pub fn load_cache(owner: &str, repo: &str, filter: PrFilter) -> Result<Vec<PullR
             PullRequestsTable::RepoName,
             PullRequestsTable::CiStatus,
             PullRequestsTable::Author,
+            PullRequestsTable::Milestone,
+            PullRequestsTable::UpdatedAt,
+            PullRequestsTable::CreatedAt,
+            PullRequestsTable::IsDraft,
         ])
         .from(PullRequestsTable::Table)
         .and_where(Expr::col(PullRequestsTable::RepoOwner).eq(owner))
         .and_where(Expr::col(PullRequestsTable::RepoName).eq(repo))
-        .and_where(Expr::col(PullRequestsTable::Filter).eq(filter.to_str()))
+        .and_where(Expr::col(PullRequestsTable::Filter).eq(filter.cache_key()))
         .build_rusqlite(SqliteQueryBuilder);
 
     let mut stmt = conn.prepare(&sql)?;
@@ -196,7 +384,17 @@ pub fn load_cache(owner: &str, repo: &str, filter: PrFilter) -> Result<Vec<PullR
                 repo_name: row.get(4)?,
                 ci_status: row.get::<_, String>(5)?.parse().unwrap(),
                 author: row.get(6)?,
-                head_sha: None, // Not cached, will be populated on fresh fetch
+                milestone: row.get(7)?,
+                updated_at: row.get(8)?,
+                created_at: row.get(9)?,
+                is_draft: row.get(10)?,
+                // Not cached, will be populated on fresh fetch
+                head_sha: None,
+                review_decision: ReviewDecision::None,
+                mergeable: Mergeable::Unknown,
+                my_review_state: ReviewState::None,
+                touches_my_code: false,
+                blocking_on_me: false,
             })
         })?
         .filter_map(|r| r.ok())
@@ -205,22 +403,48 @@ pub fn load_cache(owner: &str, repo: &str, filter: PrFilter) -> Result<Vec<PullR
     Ok(prs)
 }
 
+/// Cached PRs that can be listed. Rows saved with `cache_pr_details = false`
+/// have no title or branch, so they only count toward `ghui status`.
+pub fn load_cache_for_display(
+    owner: &str,
+    repo: &str,
+    filter: PrFilter,
+) -> Result<Vec<PullRequest>> {
+    let mut prs = load_cache(owner, repo, filter)?;
+    prs.retain(|pr| !pr.branch.is_empty());
+    Ok(prs)
+}
+
 pub fn save_cache(prs: &[PullRequest], owner: &str, repo: &str, filter: PrFilter) -> Result<()> {
+    if cache_disabled() {
+        return Ok(());
+    }
     let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
 
     if let Some(parent) = path.parent() {
         std::fs::create_dir_all(parent)?;
     }
 
-    let conn = Connection::open(&path)?;
+    let conn = open_cache(&path)?;
     init_db(&conn)?;
 
+    // Optionally keep only numbers, CI states and timestamps, leaving out what
+    // describes the work
+    let keep_details = cache_settings().keep_details;
+    let detail = |value: &str| {
+        if keep_details {
+            value.to_string()
+        } else {
+            String::new()
+        }
+    };
+
     // Only delete PRs for this specific repo and filter
     let (delete_sql, delete_values) = Query::delete()
         .from_table(PullRequestsTable::Table)
         .and_where(Expr::col(PullRequestsTable::RepoOwner).eq(owner))
         .and_where(Expr::col(PullRequestsTable::RepoName).eq(repo))
-        .and_where(Expr::col(PullRequestsTable::Filter).eq(filter.to_str()))
+        .and_where(Expr::col(PullRequestsTable::Filter).eq(filter.cache_key()))
         .build_rusqlite(SqliteQueryBuilder);
 
     conn.execute(&delete_sql, &*delete_values.as_params())?;
@@ -237,35 +461,152 @@ pub fn save_cache(prs: &[PullRequest], owner: &str, repo: &str, filter: PrFilter
                 PullRequestsTable::CiStatus,
                 PullRequestsTable::Filter,
                 PullRequestsTable::Author,
+                PullRequestsTable::Milestone,
+                PullRequestsTable::UpdatedAt,
+                PullRequestsTable::CreatedAt,
+                PullRequestsTable::IsDraft,
             ])
             .values_panic([
                 (pr.number as i64).into(),
-                (&pr.title).into(),
-                (&pr.branch).into(),
+                detail(&pr.title).into(),
+                detail(&pr.branch).into(),
                 (&pr.repo_owner).into(),
                 (&pr.repo_name).into(),
                 pr.ci_status.to_str().into(),
-                filter.to_str().into(),
-                (&pr.author).into(),
+                filter.cache_key().into(),
+                detail(&pr.author).into(),
+                pr.milestone.clone().filter(|_| keep_details).into(),
+                pr.updated_at.into(),
+                pr.created_at.into(),
+                pr.is_draft.into(),
             ])
             .build_rusqlite(SqliteQueryBuilder);
 
         conn.execute(&insert_sql, &*insert_values.as_params())?;
     }
 
+    if matches!(filter, PrFilter::Search(_)) {
+        prune_searches(&conn, owner, repo)?;
+    } else {
+        // Searches come and go, so only the tabs record when they were saved
+        let (saved_sql, saved_values) = Query::insert()
+            .into_table(CacheMeta::Table)
+            .columns([CacheMeta::Key, CacheMeta::Value])
+            .values_panic([
+                saved_at_key(owner, repo, &filter).into(),
+                now_timestamp().into(),
+            ])
+            .on_conflict(
+                sea_query::OnConflict::column(CacheMeta::Key)
+                    .update_column(CacheMeta::Value)
+                    .to_owned(),
+            )
+            .build_rusqlite(SqliteQueryBuilder);
+        conn.execute(&saved_sql, &*saved_values.as_params())?;
+    }
+
     Ok(())
 }
 
+/// Cache metadata key recording when a repo's list for a tab was last saved
+fn saved_at_key(owner: &str, repo: &str, filter: &PrFilter) -> String {
+    format!("saved_at:{}/{}:{}", owner, repo, filter.cache_key())
+}
+
+/// When a repo's list for a tab was last saved to the cache, or None if it
+/// never was. Tells an empty cached list apart from a missing one.
+pub fn cache_saved_at(owner: &str, repo: &str, filter: &PrFilter) -> Result<Option<i64>> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if !path.exists() || cache_disabled() {
+        return Ok(None);
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::select()
+        .column(CacheMeta::Value)
+        .from(CacheMeta::Table)
+        .and_where(Expr::col(CacheMeta::Key).eq(saved_at_key(owner, repo, filter)))
+        .build_rusqlite(SqliteQueryBuilder);
+    match conn.query_row(&sql, &*values.as_params(), |row| row.get(0)) {
+        Ok(saved_at) => Ok(Some(saved_at)),
+        Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Most recent search queries whose results are kept per repo
+pub const MAX_CACHED_SEARCHES: usize = 10;
+
+/// Drop the results of all but the newest `MAX_CACHED_SEARCHES` searches in a
+/// repo. Each query has its own cache key, so they would otherwise pile up.
+fn prune_searches(conn: &Connection, owner: &str, repo: &str) -> Result<()> {
+    use sea_query::{Alias, Func, Order};
+
+    let is_search = || {
+        Cond::all()
+            .add(Expr::col(PullRequestsTable::RepoOwner).eq(owner))
+            .add(Expr::col(PullRequestsTable::RepoName).eq(repo))
+            .add(Expr::col(PullRequestsTable::Filter).like("search:%"))
+    };
+    // Saving replaces a query's rows, so its newest rowid marks when it last ran
+    let (sql, values) = Query::delete()
+        .from_table(PullRequestsTable::Table)
+        .cond_where(is_search())
+        .and_where(
+            Expr::col(PullRequestsTable::Filter).not_in_subquery(
+                Query::select()
+                    .column(PullRequestsTable::Filter)
+                    .from(PullRequestsTable::Table)
+                    .cond_where(is_search())
+                    .group_by_col(PullRequestsTable::Filter)
+                    .order_by_expr(
+                        Func::max(Expr::col(Alias::new("rowid"))).into(),
+                        Order::Desc,
+                    )
+                    .limit(MAX_CACHED_SEARCHES as u64)
+                    .to_owned(),
+            ),
+        )
+        .build_rusqlite(SqliteQueryBuilder);
+    conn.execute(&sql, &*values.as_params())?;
+    Ok(())
+}
+
+/// Load the labels for a repo, followed by global labels
 pub fn load_label_filters(owner: &str, repo: &str) -> Result<Vec<LabelFilter>> {
+    query_label_filters(Some((owner, repo)))
+}
+
+/// Load only global labels, for when ghui runs outside a repository
+pub fn load_global_label_filters() -> Result<Vec<LabelFilter>> {
+    query_label_filters(None)
+}
+
+fn query_label_filters(repo_scope: Option<(&str, &str)>) -> Result<Vec<LabelFilter>> {
     let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
     if !path.exists() {
         return Ok(Vec::new());
     }
 
-    let conn = Connection::open(&path)?;
+    let conn = open_cache(&path)?;
     init_db(&conn)?;
 
-    // Load both repo-specific labels and global labels
+    // Global labels always apply; repo-specific ones only when in that repo
+    let mut scope = Cond::any().add(
+        Cond::all()
+            .add(Expr::col(LabelFiltersTable::RepoOwner).is_null())
+            .add(Expr::col(LabelFiltersTable::RepoName).is_null()),
+    );
+    if let Some((owner, repo)) = repo_scope {
+        scope = scope.add(
+            Cond::all()
+                .add(Expr::col(LabelFiltersTable::RepoOwner).eq(owner))
+                .add(Expr::col(LabelFiltersTable::RepoName).eq(repo)),
+        );
+    }
+
     let (sql, values) = Query::select()
         .columns([
             LabelFiltersTable::Id,
@@ -274,19 +615,7 @@ pub fn load_label_filters(owner: &str, repo: &str) -> Result<Vec<LabelFilter>> {
             LabelFiltersTable::RepoName,
         ])
         .from(LabelFiltersTable::Table)
-        .cond_where(
-            Cond::any()
-                .add(
-                    Cond::all()
-                        .add(Expr::col(LabelFiltersTable::RepoOwner).eq(owner))
-                        .add(Expr::col(LabelFiltersTable::RepoName).eq(repo)),
-                )
-                .add(
-                    Cond::all()
-                        .add(Expr::col(LabelFiltersTable::RepoOwner).is_null())
-                        .add(Expr::col(LabelFiltersTable::RepoName).is_null()),
-                ),
-        )
+        .cond_where(scope)
         .order_by_expr(
             Expr::col(LabelFiltersTable::RepoOwner).is_null(),
             sea_query::Order::Asc,
@@ -319,7 +648,7 @@ pub fn save_label_filter(label_name: &str, owner: Option<&str>, repo: Option<&st
         std::fs::create_dir_all(parent)?;
     }
 
-    let conn = Connection::open(&path)?;
+    let conn = open_cache(&path)?;
     init_db(&conn)?;
 
     let owner_value: sea_query::SimpleExpr = match owner {
@@ -353,7 +682,7 @@ pub fn delete_label_filter(id: i64) -> Result<()> {
         return Ok(());
     }
 
-    let conn = Connection::open(&path)?;
+    let conn = open_cache(&path)?;
     init_db(&conn)?;
 
     let (sql, values) = Query::delete()
@@ -365,3 +694,308 @@ pub fn delete_label_filter(id: i64) -> Result<()> {
 
     Ok(())
 }
+
+pub fn load_saved_searches(owner: &str, repo: &str) -> Result<Vec<SavedSearch>> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::select()
+        .columns([
+            SavedSearchesTable::Id,
+            SavedSearchesTable::Name,
+            SavedSearchesTable::Query,
+            SavedSearchesTable::RepoOwner,
+            SavedSearchesTable::RepoName,
+        ])
+        .from(SavedSearchesTable::Table)
+        .and_where(Expr::col(SavedSearchesTable::RepoOwner).eq(owner))
+        .and_where(Expr::col(SavedSearchesTable::RepoName).eq(repo))
+        .order_by(SavedSearchesTable::Name, sea_query::Order::Asc)
+        .build_rusqlite(SqliteQueryBuilder);
+
+    let mut stmt = conn.prepare(&sql)?;
+    let searches = stmt
+        .query_map(&*values.as_params(), |row| {
+            Ok(SavedSearch {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                query: row.get(2)?,
+                repo_owner: row.get(3)?,
+                repo_name: row.get(4)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(searches)
+}
+
+/// Save a named search for a repo. Saving an existing name replaces its query.
+pub fn save_saved_search(name: &str, query: &str, owner: &str, repo: &str) -> Result<()> {
+    use sea_query::OnConflict;
+
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::insert()
+        .into_table(SavedSearchesTable::Table)
+        .columns([
+            SavedSearchesTable::Name,
+            SavedSearchesTable::Query,
+            SavedSearchesTable::RepoOwner,
+            SavedSearchesTable::RepoName,
+        ])
+        .values_panic([name.into(), query.into(), owner.into(), repo.into()])
+        .on_conflict(
+            OnConflict::columns([
+                SavedSearchesTable::Name,
+                SavedSearchesTable::RepoOwner,
+                SavedSearchesTable::RepoName,
+            ])
+            .update_column(SavedSearchesTable::Query)
+            .to_owned(),
+        )
+        .build_rusqlite(SqliteQueryBuilder);
+
+    conn.execute(&sql, &*values.as_params())?;
+
+    Ok(())
+}
+
+pub fn delete_saved_search(id: i64) -> Result<()> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::delete()
+        .from_table(SavedSearchesTable::Table)
+        .and_where(Expr::col(SavedSearchesTable::Id).eq(id))
+        .build_rusqlite(SqliteQueryBuilder);
+
+    conn.execute(&sql, &*values.as_params())?;
+
+    Ok(())
+}
+
+/// Most recent checkouts kept per repo
+pub const MAX_RECENT_CHECKOUTS: usize = 10;
+
+/// Branches checked out in a repo, most recent first
+pub fn load_recent_checkouts(owner: &str, repo: &str) -> Result<Vec<RecentCheckout>> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if !path.exists() || cache_disabled() {
+        return Ok(Vec::new());
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::select()
+        .columns([
+            RecentCheckoutsTable::Branch,
+            RecentCheckoutsTable::PrNumber,
+            RecentCheckoutsTable::RepoOwner,
+            RecentCheckoutsTable::RepoName,
+            RecentCheckoutsTable::CheckedOutAt,
+        ])
+        .from(RecentCheckoutsTable::Table)
+        .and_where(Expr::col(RecentCheckoutsTable::RepoOwner).eq(owner))
+        .and_where(Expr::col(RecentCheckoutsTable::RepoName).eq(repo))
+        .order_by(RecentCheckoutsTable::CheckedOutAt, sea_query::Order::Desc)
+        .limit(MAX_RECENT_CHECKOUTS as u64)
+        .build_rusqlite(SqliteQueryBuilder);
+
+    let mut stmt = conn.prepare(&sql)?;
+    let checkouts = stmt
+        .query_map(&*values.as_params(), |row| {
+            Ok(RecentCheckout {
+                branch: row.get(0)?,
+                pr_number: row.get(1)?,
+                repo_owner: row.get(2)?,
+                repo_name: row.get(3)?,
+                checked_out_at: row.get(4)?,
+            })
+        })?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(checkouts)
+}
+
+/// Record a checkout, replacing an older one of the same branch and dropping
+/// the oldest beyond `MAX_RECENT_CHECKOUTS`
+pub fn save_recent_checkout(checkout: &RecentCheckout) -> Result<()> {
+    use sea_query::OnConflict;
+
+    // Without PR details, branch names stay out of the cache too
+    if cache_disabled() || !cache_settings().keep_details {
+        return Ok(());
+    }
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::insert()
+        .into_table(RecentCheckoutsTable::Table)
+        .columns([
+            RecentCheckoutsTable::Branch,
+            RecentCheckoutsTable::PrNumber,
+            RecentCheckoutsTable::RepoOwner,
+            RecentCheckoutsTable::RepoName,
+            RecentCheckoutsTable::CheckedOutAt,
+        ])
+        .values_panic([
+            checkout.branch.as_str().into(),
+            checkout.pr_number.into(),
+            checkout.repo_owner.as_str().into(),
+            checkout.repo_name.as_str().into(),
+            checkout.checked_out_at.into(),
+        ])
+        .on_conflict(
+            OnConflict::columns([
+                RecentCheckoutsTable::Branch,
+                RecentCheckoutsTable::RepoOwner,
+                RecentCheckoutsTable::RepoName,
+            ])
+            .update_columns([
+                RecentCheckoutsTable::PrNumber,
+                RecentCheckoutsTable::CheckedOutAt,
+            ])
+            .to_owned(),
+        )
+        .build_rusqlite(SqliteQueryBuilder);
+    conn.execute(&sql, &*values.as_params())?;
+
+    // Keep only the newest entries for this repo
+    let (sql, values) = Query::delete()
+        .from_table(RecentCheckoutsTable::Table)
+        .and_where(Expr::col(RecentCheckoutsTable::RepoOwner).eq(checkout.repo_owner.as_str()))
+        .and_where(Expr::col(RecentCheckoutsTable::RepoName).eq(checkout.repo_name.as_str()))
+        .and_where(
+            Expr::col(RecentCheckoutsTable::Branch).not_in_subquery(
+                Query::select()
+                    .column(RecentCheckoutsTable::Branch)
+                    .from(RecentCheckoutsTable::Table)
+                    .and_where(
+                        Expr::col(RecentCheckoutsTable::RepoOwner).eq(checkout.repo_owner.as_str()),
+                    )
+                    .and_where(
+                        Expr::col(RecentCheckoutsTable::RepoName).eq(checkout.repo_name.as_str()),
+                    )
+                    .order_by(RecentCheckoutsTable::CheckedOutAt, sea_query::Order::Desc)
+                    .limit(MAX_RECENT_CHECKOUTS as u64)
+                    .to_owned(),
+            ),
+        )
+        .build_rusqlite(SqliteQueryBuilder);
+    conn.execute(&sql, &*values.as_params())?;
+
+    Ok(())
+}
+
+/// Numbers of the PRs marked done in a repo
+pub fn load_done_prs(owner: &str, repo: &str) -> Result<HashSet<u64>> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if !path.exists() {
+        return Ok(HashSet::new());
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::select()
+        .column(DonePrsTable::PrNumber)
+        .from(DonePrsTable::Table)
+        .and_where(Expr::col(DonePrsTable::RepoOwner).eq(owner))
+        .and_where(Expr::col(DonePrsTable::RepoName).eq(repo))
+        .build_rusqlite(SqliteQueryBuilder);
+
+    let mut stmt = conn.prepare(&sql)?;
+    let numbers = stmt
+        .query_map(&*values.as_params(), |row| row.get(0))?
+        .filter_map(|r| r.ok())
+        .collect();
+
+    Ok(numbers)
+}
+
+/// Mark a PR done, or clear the mark
+pub fn save_done_pr(owner: &str, repo: &str, number: u64, done: bool) -> Result<()> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = if done {
+        Query::insert()
+            .into_table(DonePrsTable::Table)
+            .columns([
+                DonePrsTable::PrNumber,
+                DonePrsTable::RepoOwner,
+                DonePrsTable::RepoName,
+            ])
+            .values_panic([number.into(), owner.into(), repo.into()])
+            .on_conflict(
+                sea_query::OnConflict::columns([
+                    DonePrsTable::PrNumber,
+                    DonePrsTable::RepoOwner,
+                    DonePrsTable::RepoName,
+                ])
+                .do_nothing()
+                .to_owned(),
+            )
+            .build_rusqlite(SqliteQueryBuilder)
+    } else {
+        Query::delete()
+            .from_table(DonePrsTable::Table)
+            .and_where(Expr::col(DonePrsTable::PrNumber).eq(number))
+            .and_where(Expr::col(DonePrsTable::RepoOwner).eq(owner))
+            .and_where(Expr::col(DonePrsTable::RepoName).eq(repo))
+            .build_rusqlite(SqliteQueryBuilder)
+    };
+    conn.execute(&sql, &*values.as_params())?;
+
+    Ok(())
+}
+
+/// Clear every done mark in a repo
+pub fn clear_done_prs(owner: &str, repo: &str) -> Result<()> {
+    let path = get_cache_path().ok_or_else(|| anyhow::anyhow!("No cache dir"))?;
+    if !path.exists() {
+        return Ok(());
+    }
+
+    let conn = open_cache(&path)?;
+    init_db(&conn)?;
+
+    let (sql, values) = Query::delete()
+        .from_table(DonePrsTable::Table)
+        .and_where(Expr::col(DonePrsTable::RepoOwner).eq(owner))
+        .and_where(Expr::col(DonePrsTable::RepoName).eq(repo))
+        .build_rusqlite(SqliteQueryBuilder);
+    conn.execute(&sql, &*values.as_params())?;
+
+    Ok(())
+}
@@ -34,8 +34,10 @@ use std::io::Write;
 use strip_ansi_escapes::strip_str;
 
 use crate::data::{
-    JobLogs, JobStep, TestResult, WorkflowConclusion, WorkflowJob, WorkflowRun, WorkflowStatus,
+    CiProvider, JobLogs, JobStep, TestResult, WorkflowConclusion, WorkflowJob, WorkflowRun,
+    WorkflowStatus,
 };
+use crate::services::github::{max_log_bytes, truncate_log};
 
 // =============================================================================
 // Constants
@@ -168,6 +170,14 @@ struct JobDetailsResponse {
     messages: Option<Vec<JobMessage>>,
     contexts: Option<Vec<JobContext>>,
     steps: Option<Vec<V2JobStep>>,
+    latest_workflow: Option<JobWorkflowRef>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+struct JobWorkflowRef {
+    id: String,
+    name: String,
 }
 
 #[allow(dead_code)]
@@ -328,7 +338,6 @@ async fn fetch_workflow_jobs(workflow_id: &str) -> Result<Vec<CircleCIJob>> {
 }
 
 /// Fetch job details including steps (v2 API - limited info)
-#[allow(dead_code)]
 async fn fetch_job_details_v2(
     owner: &str,
     repo: &str,
@@ -555,11 +564,17 @@ pub async fn fetch_circleci_job_logs(
             }
         }
 
-        // Fetch all outputs in parallel
+        // Fetch all outputs in parallel, capping each one's size. Output arrives as
+        // JSON, so it's cut after parsing rather than while downloading.
+        let max_bytes = max_log_bytes();
         let fetch_futures = fetch_tasks.iter().map(|(_, _, url)| {
             let url = url.clone();
             let token = token.clone();
-            async move { fetch_step_output(&url, &token).await }
+            async move {
+                fetch_step_output(&url, &token)
+                    .await
+                    .map(|output| truncate_log(output, max_bytes))
+            }
         });
         let fetch_results: Vec<Result<String>> = join_all(fetch_futures).await;
 
@@ -839,13 +854,15 @@ pub async fn fetch_circleci_workflows_for_branch(
                     summary: None,
                     text: None,
                     annotations: Vec::new(),
+                    annotations_count: 0,
                 }
             })
             .collect();
 
         let workflow_run = WorkflowRun {
-            id: latest_pipeline.number,
+            id: None,
             name: format!("CircleCI: {}", workflow.name),
+            provider: CiProvider::CircleCi,
             status: parse_circleci_status(&workflow.status),
             conclusion: parse_circleci_conclusion(&workflow.status),
             html_url: format!(
@@ -855,6 +872,7 @@ pub async fn fetch_circleci_workflows_for_branch(
             jobs: workflow_jobs,
             created_at: workflow.created_at,
             updated_at: workflow.stopped_at.unwrap_or_default(),
+            head_sha: None,
         };
 
         workflow_runs.push(workflow_run);
@@ -863,6 +881,65 @@ pub async fn fetch_circleci_workflows_for_branch(
     Ok(workflow_runs)
 }
 
+/// Re-run the workflow containing a job, optionally only from its failed jobs
+pub async fn rerun_circleci_workflow(
+    owner: &str,
+    repo: &str,
+    job_number: u64,
+    from_failed: bool,
+) -> Result<()> {
+    let token = get_circleci_token()
+        .ok_or_else(|| anyhow::anyhow!("CIRCLECI_TOKEN environment variable not set"))?;
+
+    let job = fetch_job_details_v2(owner, repo, job_number).await?;
+    let workflow = job
+        .latest_workflow
+        .ok_or_else(|| anyhow::anyhow!("CircleCI job {} has no workflow", job_number))?;
+
+    let client = create_client(&token)?;
+    let url = format!("{}/workflow/{}/rerun", CIRCLECI_API_V2_BASE, workflow.id);
+    let response = client
+        .post(&url)
+        .json(&serde_json::json!({ "from_failed": from_failed }))
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+    match body["message"].as_str() {
+        Some(message) => anyhow::bail!("Failed to re-run CircleCI workflow: {}", message),
+        None => anyhow::bail!("Failed to re-run CircleCI workflow: {}", status),
+    }
+}
+
+/// Cancel the workflow containing a job
+pub async fn cancel_circleci_workflow(owner: &str, repo: &str, job_number: u64) -> Result<()> {
+    let token = get_circleci_token()
+        .ok_or_else(|| anyhow::anyhow!("CIRCLECI_TOKEN environment variable not set"))?;
+
+    let job = fetch_job_details_v2(owner, repo, job_number).await?;
+    let workflow = job
+        .latest_workflow
+        .ok_or_else(|| anyhow::anyhow!("CircleCI job {} has no workflow", job_number))?;
+
+    let client = create_client(&token)?;
+    let url = format!("{}/workflow/{}/cancel", CIRCLECI_API_V2_BASE, workflow.id);
+    let response = client.post(&url).send().await?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+    match body["message"].as_str() {
+        Some(message) => anyhow::bail!("Failed to cancel CircleCI workflow: {}", message),
+        None => anyhow::bail!("Failed to cancel CircleCI workflow: {}", status),
+    }
+}
+
 // =============================================================================
 // URL Detection & Parsing
 // =============================================================================
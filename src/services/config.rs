@@ -0,0 +1,650 @@
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::data::{MergeMethod, PrFilter, PrSort, PullRequest, TableColumn};
+use crate::utils::find_repo_root;
+
+/// User configuration loaded from `config.toml` in the ghui config directory,
+/// overlaid with `.ghui.toml` from the repository root when present.
+/// Every field is optional so a partial (or missing) file is valid.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Command used to open content in an editor (e.g. "nvim", "code --wait")
+    pub editor: Option<String>,
+    /// Command for opening a file at a line, with `{path}` and `{line}` filled in
+    /// (e.g. "code -g {path}:{line}"); defaults to the editor with `+{line} {path}`
+    pub editor_goto: Option<String>,
+    /// Command used to page through saved logs (e.g. "less -R", "bat")
+    pub pager: Option<String>,
+    /// Command for opening URLs in the browser, with `{url}` filled in
+    /// (e.g. "firefox --new-tab {url}"); defaults to `open` or `xdg-open`
+    pub open_command: Option<String>,
+    /// Command that prints a GitHub token, used instead of GH_TOKEN and `gh auth token`
+    pub token_command: Option<String>,
+    /// Fixed column widths for the PR table; the title column takes the rest
+    pub columns: ColumnWidths,
+    /// Where long PR titles are cut in the table
+    pub title_truncation: TruncationStyle,
+    /// Wrap the selected row's title onto extra lines instead of truncating it
+    pub expand_selected_title: bool,
+    /// Moving past the end of a list wraps to the other end instead of stopping
+    pub wrap_navigation: bool,
+    /// Start with PRs you've already approved hidden (toggle with `A`)
+    pub hide_approved: bool,
+    /// Highlight PRs that change files the repository's CODEOWNERS assigns to you
+    /// or one of your `teams`; fetches each PR's changed files
+    pub codeowners_highlight: bool,
+    /// Start from cached data only and fetch on explicit refresh, with no auto-refresh
+    pub offline_start: bool,
+    /// Keep fetched PRs in memory only, never writing them to `cache.db` (same as `GHUI_NO_CACHE`)
+    pub no_cache: bool,
+    /// Cache PR titles, branches, authors and milestones; when off only numbers,
+    /// CI states and timestamps are written
+    pub cache_pr_details: bool,
+    /// Tabs fetched at startup; the others load the first time they're opened
+    pub prefetch_tabs: Vec<PrefetchTab>,
+    /// Show each prefetched tab's progress under the tab bar until they've all loaded
+    pub startup_progress: bool,
+    /// Sort each tab starts in, read from the `[sort]` table
+    pub sort: SortConfig,
+    /// Seconds between automatic refreshes of the PR list (and `ghui watch` polls)
+    pub refresh_interval_secs: u64,
+    /// Seconds between polls of the workflows view while jobs are still running
+    pub actions_poll_interval_secs: u64,
+    /// Most seconds of random delay added to each refresh and poll interval
+    pub poll_jitter_secs: u64,
+    /// Add `archived:false` to PR searches so archived repositories are left out
+    pub exclude_archived: bool,
+    /// Refresh the current tab when the terminal regains focus or ghui resumes from suspend
+    pub refresh_on_focus: bool,
+    /// Largest job log (or CircleCI step output) kept in memory; 0 for no limit
+    pub max_log_bytes: usize,
+    /// Most recent comments and reviews fetched for the preview; 0 for as many as GitHub returns
+    pub preview_max_comments: usize,
+    /// Colors and styles, read from the `[theme]` table
+    pub theme: ThemeConfig,
+    /// Loading spinner appearance, read from the `[spinner]` table
+    pub spinner: SpinnerConfig,
+    /// Replace Nerd Font and Unicode glyphs with plain ASCII (same as `--ascii`)
+    pub ascii: bool,
+    /// Confirms a Nerd Font is installed, skipping the missing-glyphs warning
+    pub nerd_font: bool,
+    /// Teams whose review requests get a tab, as "org/team"
+    pub teams: Vec<String>,
+    /// Check out with jj (true) or git (false) instead of detecting a `.jj` directory
+    pub jj: Option<bool>,
+    /// Show the checkout command instead of running it (same as `--dry-run`)
+    pub checkout_dry_run: bool,
+    /// Merging from the list, read from the `[merge]` table
+    pub merge: MergeConfig,
+    /// Which destructive actions ask first, read from the `[confirm_destructive]` table
+    pub confirm_destructive: ConfirmConfig,
+    /// When PRs count as recently active or stale, read from the `[staleness]` table
+    pub staleness: StalenessConfig,
+    /// Smallest terminal the UI is drawn in, read from the `[min_size]` table
+    pub min_size: MinSizeConfig,
+    /// PR fields the fuzzy search matches against; empty means all of them
+    pub search_fields: Vec<SearchField>,
+    /// Command copied with `Y`; `{number}`, `{owner}`, `{repo}` and `{branch}` are filled in
+    pub gh_command: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            editor: None,
+            editor_goto: None,
+            pager: None,
+            open_command: None,
+            token_command: None,
+            columns: ColumnWidths::default(),
+            title_truncation: TruncationStyle::End,
+            expand_selected_title: true,
+            wrap_navigation: false,
+            hide_approved: false,
+            codeowners_highlight: false,
+            offline_start: false,
+            no_cache: false,
+            cache_pr_details: true,
+            prefetch_tabs: PrefetchTab::ALL.to_vec(),
+            startup_progress: true,
+            sort: SortConfig::default(),
+            refresh_interval_secs: 30,
+            actions_poll_interval_secs: 30,
+            poll_jitter_secs: 5,
+            refresh_on_focus: false,
+            exclude_archived: false,
+            max_log_bytes: 5 * 1024 * 1024,
+            preview_max_comments: 30,
+            theme: ThemeConfig::default(),
+            spinner: SpinnerConfig::default(),
+            ascii: false,
+            nerd_font: false,
+            teams: Vec::new(),
+            jj: None,
+            checkout_dry_run: false,
+            merge: MergeConfig::default(),
+            confirm_destructive: ConfirmConfig::default(),
+            staleness: StalenessConfig::default(),
+            min_size: MinSizeConfig::default(),
+            search_fields: SearchField::ALL.to_vec(),
+            gh_command: "gh pr checkout {number} --repo {owner}/{repo}".to_string(),
+        }
+    }
+}
+
+/// Where an over-long title is cut
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TruncationStyle {
+    /// Keep the start: "Add retry logic to the…"
+    #[default]
+    End,
+    /// Keep the start and end: "Add retry…webhook sender"
+    Middle,
+    /// Middle for titles with a shared-looking prefix ("[WIP] ", "feat(api): "), end otherwise
+    Smart,
+}
+
+/// A PR field included in fuzzy search
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchField {
+    Number,
+    Author,
+    Title,
+    Branch,
+    Ci,
+}
+
+impl SearchField {
+    pub const ALL: [SearchField; 5] = [
+        SearchField::Number,
+        SearchField::Author,
+        SearchField::Title,
+        SearchField::Branch,
+        SearchField::Ci,
+    ];
+}
+
+/// A tab that can be fetched at startup
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrefetchTab {
+    MyPrs,
+    ReviewRequested,
+}
+
+impl PrefetchTab {
+    pub const ALL: [PrefetchTab; 2] = [PrefetchTab::MyPrs, PrefetchTab::ReviewRequested];
+
+    pub fn filter(self) -> PrFilter {
+        match self {
+            PrefetchTab::MyPrs => PrFilter::MyPrs,
+            PrefetchTab::ReviewRequested => PrFilter::ReviewRequested,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PrefetchTab::MyPrs => "My PRs",
+            PrefetchTab::ReviewRequested => "Review Requested",
+        }
+    }
+}
+
+/// Sort applied when switching to a tab; `S` still cycles from there
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SortConfig {
+    pub my_prs: PrSort,
+    /// Also used for team review tabs
+    pub review_requested: PrSort,
+}
+
+impl Default for SortConfig {
+    fn default() -> Self {
+        Self {
+            my_prs: PrSort::Newest,
+            // Clear the longest-waiting requests first
+            review_requested: PrSort::Updated,
+        }
+    }
+}
+
+impl SortConfig {
+    pub fn for_filter(&self, filter: &PrFilter) -> PrSort {
+        match filter {
+            PrFilter::MyPrs => self.my_prs,
+            PrFilter::ReviewRequested | PrFilter::Team(_) => self.review_requested,
+            _ => PrSort::Default,
+        }
+    }
+}
+
+/// Age thresholds for coloring the PR table's Updated column
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StalenessConfig {
+    /// Updated within this many hours shows green
+    pub recent_hours: u64,
+    /// Untouched for more than this many days shows red
+    pub stale_days: u64,
+}
+
+impl Default for StalenessConfig {
+    fn default() -> Self {
+        Self {
+            recent_hours: 24,
+            stale_days: 7,
+        }
+    }
+}
+
+/// Terminal size below which only a "too small" message is shown
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct MinSizeConfig {
+    /// Columns
+    pub width: u16,
+    /// Rows
+    pub height: u16,
+}
+
+impl Default for MinSizeConfig {
+    fn default() -> Self {
+        // Fits the widest and tallest fixed-size popups: recent checkouts
+        // (70 columns) and labels (18 rows). Help scrolls instead.
+        Self {
+            width: 70,
+            height: 18,
+        }
+    }
+}
+
+/// Whether each destructive action asks for confirmation. All default to asking.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ConfirmConfig {
+    pub checkout: bool,
+    pub merge: bool,
+    pub rerun: bool,
+    pub cancel_run: bool,
+    /// Always ask, with a warning, before checking out the repo's default branch,
+    /// even when `checkout` is off
+    pub default_branch_checkout: bool,
+}
+
+impl Default for ConfirmConfig {
+    fn default() -> Self {
+        Self {
+            checkout: true,
+            merge: true,
+            rerun: true,
+            cancel_run: true,
+            default_branch_checkout: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct MergeConfig {
+    /// Allow merging PRs from the list. Off by default since it can't be undone.
+    pub enabled: bool,
+    /// Merge method preselected in the confirmation popup
+    pub method: MergeMethod,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct SpinnerConfig {
+    /// Frame set; when unset, braille unless the terminal is known not to render it
+    pub style: Option<SpinnerStyle>,
+    /// Time between frames in milliseconds
+    pub interval_ms: u64,
+}
+
+impl Default for SpinnerConfig {
+    fn default() -> Self {
+        Self {
+            style: None,
+            interval_ms: 80,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpinnerStyle {
+    Braille,
+    Dots,
+    Line,
+    Ascii,
+}
+
+impl SpinnerConfig {
+    /// Frame interval, with a floor so a tiny value can't spin the render loop
+    pub fn interval(&self) -> Duration {
+        Duration::from_millis(self.interval_ms.max(16))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    /// Style of the selected row/item, read from `[theme.highlight]`
+    pub highlight: HighlightConfig,
+}
+
+/// Selection highlight: an optional preset, refined by explicit colors and modifiers
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct HighlightConfig {
+    pub preset: Option<HighlightPreset>,
+    pub bg: Option<String>,
+    pub fg: Option<String>,
+    pub modifiers: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HighlightPreset {
+    /// Swap foreground and background colors; readable on any palette
+    Reverse,
+    /// Solid colored bar behind the selected row
+    Bar,
+}
+
+/// Widths of the fixed-size PR table columns, read from the `[columns]` table
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ColumnWidths {
+    pub author: u16,
+    pub branch: u16,
+    pub milestone: u16,
+}
+
+impl Default for ColumnWidths {
+    fn default() -> Self {
+        Self {
+            author: 15,
+            branch: 24,
+            milestone: 14,
+        }
+    }
+}
+
+impl ColumnWidths {
+    pub fn get(&self, column: TableColumn) -> u16 {
+        match column {
+            TableColumn::Branch => self.branch,
+            TableColumn::Author => self.author,
+            TableColumn::Milestone => self.milestone,
+        }
+    }
+
+    pub fn set(&mut self, column: TableColumn, width: u16) {
+        match column {
+            TableColumn::Branch => self.branch = width,
+            TableColumn::Author => self.author = width,
+            TableColumn::Milestone => self.milestone = width,
+        }
+    }
+}
+
+impl Config {
+    /// Resolve the editor: config, then $EDITOR, then $VISUAL, then vim
+    pub fn editor_command(&self) -> String {
+        self.editor
+            .clone()
+            .or_else(|| std::env::var("EDITOR").ok())
+            .or_else(|| std::env::var("VISUAL").ok())
+            .unwrap_or_else(|| "vim".to_string())
+    }
+
+    /// The program and arguments that open `path` at `line`. Placeholders are filled
+    /// in after splitting, so a path with spaces stays one argument.
+    pub fn editor_goto_for(&self, path: &str, line: u32) -> Option<(String, Vec<String>)> {
+        let template = self
+            .editor_goto
+            .clone()
+            .unwrap_or_else(|| format!("{} +{{line}} {{path}}", self.editor_command()));
+        let (program, args) = split_command(&template)?;
+        let fill = |arg: String| {
+            arg.replace("{path}", path)
+                .replace("{line}", &line.to_string())
+        };
+        Some((fill(program), args.into_iter().map(fill).collect()))
+    }
+
+    /// Resolve the pager: config, then $PAGER, then less
+    pub fn pager_command(&self) -> String {
+        self.pager
+            .clone()
+            .or_else(|| std::env::var("PAGER").ok())
+            .unwrap_or_else(|| "less".to_string())
+    }
+
+    /// Program and arguments that open a URL: `open_command` with `{url}` filled
+    /// in (or the URL appended when there's no placeholder), else the platform opener
+    pub fn open_command_for(&self, url: &str) -> Option<(String, Vec<String>)> {
+        let Some(template) = &self.open_command else {
+            #[cfg(target_os = "linux")]
+            let opener = "xdg-open";
+            #[cfg(not(target_os = "linux"))]
+            let opener = "open";
+            return Some((opener.to_string(), vec![url.to_string()]));
+        };
+        let (program, mut args) = split_command(template)?;
+        if !template.contains("{url}") {
+            args.push(url.to_string());
+        }
+        let fill = |arg: String| arg.replace("{url}", url);
+        Some((fill(program), args.into_iter().map(fill).collect()))
+    }
+
+    /// The `gh_command` template filled in for a PR
+    pub fn gh_command_for(&self, pr: &PullRequest) -> String {
+        self.gh_command
+            .replace("{number}", &pr.number.to_string())
+            .replace("{owner}", &pr.repo_owner)
+            .replace("{repo}", &pr.repo_name)
+            .replace("{branch}", &pr.branch)
+    }
+
+    /// Return a warning for each configured command that can't be found.
+    /// Theme values are checked by `Theme::validate`.
+    pub fn validate(&self) -> Vec<String> {
+        [
+            ("editor", &self.editor),
+            ("editor_goto", &self.editor_goto),
+            ("pager", &self.pager),
+            ("open_command", &self.open_command),
+            ("token_command", &self.token_command),
+        ]
+        .into_iter()
+        .filter_map(|(key, cmd)| {
+            let cmd = cmd.as_ref()?;
+            let (program, _) = split_command(cmd)?;
+            if command_exists(&program) {
+                None
+            } else {
+                Some(format!("Configured {} '{}' not found", key, program))
+            }
+        })
+        .chain(
+            self.teams
+                .iter()
+                .filter(|team| parse_team(team).is_none())
+                .map(|team| format!("Invalid team '{}' (expected org/team)", team)),
+        )
+        .collect()
+    }
+
+    /// Auto-refresh interval, with a floor to stay clear of GitHub's rate limits
+    pub fn refresh_interval(&self) -> Duration {
+        Duration::from_secs(self.refresh_interval_secs.max(5))
+    }
+
+    /// Workflows view polling interval, with the same floor as `refresh_interval`
+    pub fn actions_poll_interval(&self) -> Duration {
+        Duration::from_secs(self.actions_poll_interval_secs.max(5))
+    }
+
+    pub fn poll_jitter(&self) -> Duration {
+        Duration::from_secs(self.poll_jitter_secs)
+    }
+
+    /// Configured teams with a valid "org/team" format
+    pub fn valid_teams(&self) -> Vec<String> {
+        self.teams
+            .iter()
+            .filter(|team| parse_team(team).is_some())
+            .cloned()
+            .collect()
+    }
+}
+
+/// Directory holding ghui's config and cache: `$GHUI_CONFIG_DIR` if set, then
+/// `$XDG_CONFIG_HOME/ghui`, then the platform config directory
+pub fn get_config_dir() -> Option<PathBuf> {
+    let from_env = |var: &str| {
+        std::env::var_os(var)
+            .filter(|value| !value.is_empty())
+            .map(PathBuf::from)
+    };
+    from_env("GHUI_CONFIG_DIR")
+        .or_else(|| from_env("XDG_CONFIG_HOME").map(|p| p.join("ghui")))
+        .or_else(|| dirs::config_dir().map(|p| p.join("ghui")))
+}
+
+pub fn get_config_path() -> Option<PathBuf> {
+    get_config_dir().map(|p| p.join("config.toml"))
+}
+
+/// Per-repo overrides, read from the repository root
+pub fn get_repo_config_path() -> Option<PathBuf> {
+    find_repo_root().map(|root| root.join(".ghui.toml"))
+}
+
+/// Settings a repository's `.ghui.toml` may override. Anything that runs a
+/// command or relaxes a safety check comes only from the user's `config.toml`,
+/// so opening ghui in a cloned repo can't run code from it.
+pub const REPO_CONFIG_KEYS: &[&str] = &[
+    "teams",
+    "columns",
+    "jj",
+    "theme",
+    "sort",
+    "search_fields",
+    "title_truncation",
+    "staleness",
+];
+
+impl Config {
+    /// Parse the global `config.toml` with a repo's `.ghui.toml` merged over it.
+    /// The repo file may only set `REPO_CONFIG_KEYS`.
+    pub fn parse(global: &str, repo: Option<&str>) -> Result<Self> {
+        let mut merged: toml::Table = toml::from_str(global)?;
+        if let Some(repo) = repo {
+            let table: toml::Table = toml::from_str(repo)?;
+            if let Some(key) = table
+                .keys()
+                .find(|key| !REPO_CONFIG_KEYS.contains(&key.as_str()))
+            {
+                bail!(
+                    "\"{}\" can't be set in .ghui.toml, only in the global config.toml",
+                    key
+                );
+            }
+            merge_tables(&mut merged, table);
+        }
+        Ok(toml::Value::Table(merged).try_into()?)
+    }
+}
+
+/// Load the effective config: the repo's `.ghui.toml` over the global
+/// `config.toml` over defaults. Missing files are skipped.
+pub fn load_config() -> Result<Config> {
+    let global = read_config_file(get_config_path())?;
+    let repo = read_config_file(get_repo_config_path())?;
+
+    let files: Vec<String> = global
+        .iter()
+        .chain(&repo)
+        .map(|(path, _)| path.display().to_string())
+        .collect();
+    Config::parse(
+        global.as_ref().map_or("", |(_, content)| content.as_str()),
+        repo.as_ref().map(|(_, content)| content.as_str()),
+    )
+    .with_context(|| format!("Invalid config in {}", files.join(" + ")))
+}
+
+/// Load only the user's own `config.toml`, for settings a repository must never
+/// supply, like `token_command`
+pub fn load_global_config() -> Result<Config> {
+    let global = read_config_file(get_config_path())?;
+    let Some((path, content)) = global else {
+        return Ok(Config::default());
+    };
+    Config::parse(&content, None).with_context(|| format!("Invalid config in {}", path.display()))
+}
+
+/// Read a config file, or None when there's no such file
+fn read_config_file(path: Option<PathBuf>) -> Result<Option<(PathBuf, String)>> {
+    let Some(path) = path.filter(|p| p.exists()) else {
+        return Ok(None);
+    };
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    Ok(Some((path, content)))
+}
+
+/// Recursively merge `overlay` into `base`; nested tables merge key by key,
+/// anything else in `overlay` replaces the value in `base`
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base_table)), toml::Value::Table(overlay_table)) => {
+                merge_tables(base_table, overlay_table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
+/// Split a team slug like "org/team" into its organization and team parts
+pub fn parse_team(slug: &str) -> Option<(&str, &str)> {
+    let (org, team) = slug.split_once('/')?;
+    let valid = |part: &str| {
+        !part.is_empty()
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
+    };
+    (valid(org) && valid(team)).then_some((org, team))
+}
+
+/// Split a command string like "code --wait" into program and arguments
+pub fn split_command(cmd: &str) -> Option<(String, Vec<String>)> {
+    let mut parts = cmd.split_whitespace().map(String::from);
+    let program = parts.next()?;
+    Some((program, parts.collect()))
+}
+
+fn command_exists(program: &str) -> bool {
+    if program.contains(std::path::MAIN_SEPARATOR) {
+        return Path::new(program).is_file();
+    }
+
+    std::env::var_os("PATH")
+        .map(|paths| std::env::split_paths(&paths).any(|dir| dir.join(program).is_file()))
+        .unwrap_or(false)
+}
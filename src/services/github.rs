@@ -1,16 +1,44 @@
 use anyhow::Result;
 use octocrab::Octocrab;
+use std::future::Future;
 use std::process::Command;
+use std::sync::{Mutex, OnceLock};
 
 use crate::data::{
-    ActionsData, CheckAnnotation, CiStatus, JobLogs, PrComment, PrFilter, PreviewData, PullRequest,
-    SearchGraphQLResponse, SearchNode, WorkflowConclusion, WorkflowJob, WorkflowRun,
-    WorkflowStatus,
+    ActionsData, CheckAnnotation, CheckContext, CiProvider, CiStatus, JobLogs, MergeMethod,
+    Mergeable, PrComment, PrFilter, PreviewData, PullRequest, PullRequestNode, ReviewDecision,
+    ReviewState, SearchConnection, SearchGraphQLResponse, SearchNode, WorkflowConclusion,
+    WorkflowJob, WorkflowRun, WorkflowStatus,
 };
-use crate::utils::get_current_repo;
+use crate::error::GhuiError;
+use crate::icons;
+use crate::services::{load_config, load_global_config, parse_team, split_command, Config};
+use crate::utils::{find_repo_root, get_current_repo, parse_timestamp, CodeOwners};
+
+/// Token printed by `token_command`, kept for the session until GitHub rejects it
+static COMMAND_TOKEN: Mutex<Option<String>> = Mutex::new(None);
+
+/// Cap on the PRs accumulated for one query, to avoid runaway pagination
+const MAX_RESULTS: usize = 500;
+/// Merged and closed PRs shown on the Recent tab: one page of search results
+const RECENT_MAX_RESULTS: usize = 100;
 
 pub fn get_github_token() -> Result<String> {
-    // First, check for GH_TOKEN environment variable
+    // A configured token command takes precedence over everything else
+    if let Some(token) = COMMAND_TOKEN
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .clone()
+    {
+        return Ok(token);
+    }
+    if let Some(cmd) = token_command() {
+        let token = run_token_command(cmd)?;
+        *COMMAND_TOKEN.lock().unwrap_or_else(|e| e.into_inner()) = Some(token.clone());
+        return Ok(token);
+    }
+
+    // Then check for GH_TOKEN environment variable
     if let Ok(token) = std::env::var("GH_TOKEN") {
         if !token.is_empty() {
             return Ok(token);
@@ -18,17 +46,174 @@ pub fn get_github_token() -> Result<String> {
     }
 
     // Fall back to gh auth token
-    let output = Command::new("gh").args(["auth", "token"]).output()?;
+    let no_token = || {
+        GhuiError::Auth(
+            "Failed to get GitHub token. Set GH_TOKEN or run 'gh auth login' first.".to_string(),
+        )
+    };
+    let output = Command::new("gh")
+        .args(["auth", "token"])
+        .output()
+        .map_err(|_| no_token())?;
 
     if !output.status.success() {
-        anyhow::bail!("Failed to get GitHub token. Set GH_TOKEN or run 'gh auth login' first.");
+        return Err(no_token().into());
     }
 
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
+/// `token_command` from the config, read once rather than on every request.
+/// Only the user's config.toml counts: a repo's .ghui.toml must never pick it.
+fn token_command() -> Option<&'static str> {
+    static TOKEN_COMMAND: OnceLock<Option<String>> = OnceLock::new();
+    TOKEN_COMMAND
+        .get_or_init(|| {
+            load_global_config()
+                .ok()
+                .and_then(|config| config.token_command)
+        })
+        .as_deref()
+}
+
+fn run_token_command(cmd: &str) -> Result<String> {
+    let (program, args) =
+        split_command(cmd).ok_or_else(|| GhuiError::Auth("token_command is empty".to_string()))?;
+    let output = Command::new(&program)
+        .args(&args)
+        .output()
+        .map_err(|e| GhuiError::Auth(format!("Failed to run token_command '{}': {}", cmd, e)))?;
+    let token = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() || token.is_empty() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(GhuiError::Auth(format!(
+            "token_command '{}' didn't print a token{}",
+            cmd,
+            if stderr.is_empty() {
+                String::new()
+            } else {
+                format!(": {}", stderr)
+            }
+        ))
+        .into());
+    }
+    Ok(token)
+}
+
+/// Drop the cached `token_command` token so the next request runs the command
+/// again. Returns whether there was one to drop.
+fn forget_command_token() -> bool {
+    COMMAND_TOKEN
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .take()
+        .is_some()
+}
+
+/// Whether GitHub rejected the request's token
+pub fn is_unauthorized(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<octocrab::Error>(),
+            Some(octocrab::Error::GitHub { source, .. })
+                if source.status_code.as_u16() == 401
+        ) || matches!(cause.downcast_ref::<GhuiError>(), Some(GhuiError::Auth(_)))
+    })
+}
+
+/// The error for a REST request GitHub answered with 401
+fn token_rejected() -> anyhow::Error {
+    GhuiError::Auth(
+        "GitHub rejected the token (401 Unauthorized). Set GH_TOKEN or run 'gh auth login'."
+            .to_string(),
+    )
+    .into()
+}
+
+/// Run an authenticated request. A token from token_command may be
+/// short-lived, so when GitHub rejects it the command runs again and the
+/// request is retried once with the new token.
+pub async fn with_github_token<T, F, Fut>(request: F) -> Result<T>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    match request(get_github_token()?).await {
+        Err(e) if is_unauthorized(&e) && forget_command_token() => {
+            request(get_github_token()?).await
+        }
+        result => result,
+    }
+}
+
+/// Whether GitHub refused the token because an organization enforces SAML SSO
+/// and the token hasn't been authorized for it. REST and GraphQL both say
+/// "Resource protected by organization SAML enforcement".
+pub fn is_sso_required(err: &anyhow::Error) -> bool {
+    err.chain()
+        .any(|cause| cause.to_string().contains("SAML enforcement"))
+}
+
+/// Swap an SSO refusal for an auth error saying how to authorize the token for
+/// `org`; other errors pass through
+pub fn explain_sso_error(err: anyhow::Error, org: &str) -> anyhow::Error {
+    if !is_sso_required(&err) {
+        return err;
+    }
+    GhuiError::Auth(format!(
+        "Token needs SSO authorization for {} — run `gh auth refresh` or authorize \
+         the token in GitHub settings.",
+        org
+    ))
+    .into()
+}
+
+/// Whether GitHub turned a request away for exceeding a rate limit, either with
+/// a 429 or a 403 / GraphQL error mentioning the limit
+pub fn is_rate_limited(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        let too_many_requests = matches!(
+            cause.downcast_ref::<octocrab::Error>(),
+            Some(octocrab::Error::GitHub { source, .. })
+                if source.status_code.as_u16() == 429
+        );
+        too_many_requests || cause.to_string().to_lowercase().contains("rate limit")
+    })
+}
+
+/// Whether an error came from failing to reach GitHub at all (no connection,
+/// DNS failure, timeout) rather than GitHub rejecting the request.
+pub fn is_network_error(err: &anyhow::Error) -> bool {
+    use std::io::ErrorKind;
+    // octocrab's Hyper and Service errors wrap everything from TLS to body
+    // decoding, so look through them for the underlying cause
+    err.chain().any(|cause| {
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            return e.is_connect() || e.is_timeout();
+        }
+        if let Some(e) = cause.downcast_ref::<std::io::Error>() {
+            return matches!(
+                e.kind(),
+                ErrorKind::ConnectionRefused
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::NotConnected
+                    | ErrorKind::TimedOut
+                    | ErrorKind::HostUnreachable
+                    | ErrorKind::NetworkUnreachable
+            );
+        }
+        // hyper's connector reports a failed lookup as "dns error", with the
+        // resolver's message as its source
+        cause.to_string().starts_with("dns error")
+    })
+}
+
 pub async fn get_current_user() -> Result<String> {
-    let token = get_github_token()?;
+    with_github_token(current_user_with_token).await
+}
+
+async fn current_user_with_token(token: String) -> Result<String> {
     let client = reqwest::Client::new();
     let response = client
         .get("https://api.github.com/user")
@@ -38,6 +223,9 @@ pub async fn get_current_user() -> Result<String> {
         .send()
         .await?;
 
+    if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(token_rejected());
+    }
     if !response.status().is_success() {
         anyhow::bail!("Failed to get current user: {}", response.status());
     }
@@ -50,37 +238,146 @@ pub async fn get_current_user() -> Result<String> {
     Ok(login.to_string())
 }
 
-pub async fn fetch_prs_graphql(filter: PrFilter) -> Result<Vec<PullRequest>> {
-    let (owner, repo) =
-        get_current_repo().ok_or_else(|| anyhow::anyhow!("Not in a GitHub repository"))?;
+/// Fail with a clear message if the authenticated user can't see the team
+async fn check_team_access(org: &str, team: &str) -> Result<()> {
+    with_github_token(|token| team_access_with_token(token, org, team)).await
+}
+
+async fn team_access_with_token(token: String, org: &str, team: &str) -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .get(format!(
+            "https://api.github.com/orgs/{}/teams/{}",
+            org, team
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "ghui")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await?;
+
+    match response.status() {
+        status if status.is_success() => Ok(()),
+        reqwest::StatusCode::UNAUTHORIZED => Err(token_rejected()),
+        reqwest::StatusCode::NOT_FOUND | reqwest::StatusCode::FORBIDDEN => anyhow::bail!(
+            "Can't access team @{}/{}. Check the name, that you're a member of {}, \
+             and that your token has the read:org scope.",
+            org,
+            team,
+            org
+        ),
+        status => anyhow::bail!("Failed to look up team @{}/{}: {}", org, team, status),
+    }
+}
+
+/// Merge a PR. On failure (conflicts, failing checks, branch protection) the
+/// error carries GitHub's own message.
+pub async fn merge_pr(owner: &str, repo: &str, number: u64, method: MergeMethod) -> Result<()> {
+    with_github_token(|token| merge_pr_with_token(token, owner, repo, number, method)).await
+}
+
+async fn merge_pr_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    number: u64,
+    method: MergeMethod,
+) -> Result<()> {
+    let client = reqwest::Client::new();
+    let response = client
+        .put(format!(
+            "https://api.github.com/repos/{}/{}/pulls/{}/merge",
+            owner, repo, number
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "ghui")
+        .header("Accept", "application/vnd.github+json")
+        .json(&serde_json::json!({ "merge_method": method.to_str() }))
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(token_rejected());
+    }
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+    match body["message"].as_str() {
+        Some(message) => anyhow::bail!("Failed to merge #{}: {}", number, message),
+        None => anyhow::bail!("Failed to merge #{}: {}", number, status),
+    }
+}
+
+/// PRs for a filter, plus GitHub's messages about any it couldn't return
+pub async fn fetch_prs_graphql(
+    filter: PrFilter,
+    config: &Config,
+) -> Result<(Vec<PullRequest>, Vec<String>)> {
+    let result =
+        with_github_token(|token| fetch_prs_with_token(token, filter.clone(), config)).await;
+    result.map_err(|e| match get_current_repo() {
+        Some((owner, _)) => explain_sso_error(e, &owner),
+        None => e,
+    })
+}
+
+async fn fetch_prs_with_token(
+    token: String,
+    filter: PrFilter,
+    config: &Config,
+) -> Result<(Vec<PullRequest>, Vec<String>)> {
+    let (owner, repo) = get_current_repo().ok_or(GhuiError::NotARepo)?;
 
-    let token = get_github_token()?;
     let octocrab = Octocrab::builder().personal_token(token).build()?;
+    let exclude_archived = config.exclude_archived;
+    let ownership = if config.codeowners_highlight {
+        load_ownership(config).await
+    } else {
+        None
+    };
 
     // For Labels filter with multiple labels, we need to fetch each label separately
     // and combine results (GitHub Search doesn't support OR with label: qualifier)
     if let PrFilter::Labels(labels) = &filter {
         if labels.is_empty() {
-            return Ok(Vec::new());
+            return Ok((Vec::new(), Vec::new()));
         }
 
         // Fetch PRs for each label separately
         let mut all_prs = Vec::new();
+        let mut warnings = Vec::new();
         for label in labels {
-            let query_string = format!("repo:{}/{} is:pr is:open label:\"{}\"", owner, repo, label);
-            let prs = fetch_prs_for_query(&octocrab, query_string, &owner, &repo).await?;
+            let query_string = with_archived_qualifier(
+                format!("repo:{}/{} is:pr is:open label:\"{}\"", owner, repo, label),
+                exclude_archived,
+            );
+            let (prs, label_warnings) = fetch_prs_for_query(
+                &octocrab,
+                query_string,
+                &owner,
+                &repo,
+                ownership.as_ref(),
+                None,
+                MAX_RESULTS,
+            )
+            .await?;
             all_prs.extend(prs);
+            warnings.extend(label_warnings);
         }
 
         // Deduplicate by PR number
         all_prs.sort_by_key(|pr| pr.number);
         all_prs.dedup_by_key(|pr| pr.number);
 
-        return Ok(all_prs);
+        return Ok((all_prs, warnings));
     }
 
     // Use search instead of repository.pullRequests + client-side filtering.
     // This avoids missing older PRs when a repo has many open PRs.
+    // Review requests get checked for whether I'm the last reviewer left.
+    let mut reviewer = None;
     let query_string = match &filter {
         PrFilter::MyPrs => {
             let current_user = get_current_user().await?;
@@ -91,15 +388,132 @@ pub async fn fetch_prs_graphql(filter: PrFilter) -> Result<Vec<PullRequest>> {
         }
         PrFilter::ReviewRequested => {
             let current_user = get_current_user().await?;
-            format!(
+            let query = format!(
                 "repo:{}/{} is:pr is:open review-requested:{}",
                 owner, repo, current_user
-            )
+            );
+            reviewer = Some(current_user);
+            query
         }
         PrFilter::Labels(_) => unreachable!(), // Handled above
+        PrFilter::All => anyhow::bail!("The All view is composed locally and can't be fetched"),
+        PrFilter::Team(slug) => {
+            let (org, team) = parse_team(slug)
+                .ok_or_else(|| anyhow::anyhow!("Invalid team '{}' (expected org/team)", slug))?;
+            // Search silently returns nothing for teams we can't see, so check first
+            check_team_access(org, team).await?;
+            format!(
+                "repo:{}/{} is:pr is:open team-review-requested:{}/{}",
+                owner, repo, org, team
+            )
+        }
+        // Closed includes merged
+        PrFilter::Recent => format!("repo:{}/{} is:pr is:closed sort:updated-desc", owner, repo),
+        PrFilter::Search(qualifiers) => {
+            // Default to open PRs unless the query picks a state itself
+            let has_state = qualifiers.split_whitespace().any(|term| {
+                matches!(term, "is:open" | "is:closed" | "is:merged" | "is:unmerged")
+                    || term.starts_with("state:")
+            });
+            if has_state {
+                format!("repo:{}/{} is:pr {}", owner, repo, qualifiers)
+            } else {
+                format!("repo:{}/{} is:pr is:open {}", owner, repo, qualifiers)
+            }
+        }
     };
+    let query_string = with_archived_qualifier(query_string, exclude_archived);
 
-    fetch_prs_for_query(&octocrab, query_string, &owner, &repo).await
+    // A repo's closed PRs go back years, so only the newest page is fetched
+    let max_results = if filter == PrFilter::Recent {
+        RECENT_MAX_RESULTS
+    } else {
+        MAX_RESULTS
+    };
+    let (mut prs, warnings) = fetch_prs_for_query(
+        &octocrab,
+        query_string,
+        &owner,
+        &repo,
+        ownership.as_ref(),
+        reviewer.as_deref(),
+        max_results,
+    )
+    .await?;
+    if filter == PrFilter::Recent {
+        // CI on a merged or closed PR is history, not something to act on
+        for pr in &mut prs {
+            pr.ci_status = CiStatus::Unknown;
+        }
+    }
+    Ok((prs, warnings))
+}
+
+/// CODEOWNERS rules from the local checkout and the owner names that mean me:
+/// my login plus the configured teams. None when the repo has no CODEOWNERS
+/// file or the login can't be fetched, which just leaves PRs unhighlighted.
+async fn load_ownership(config: &Config) -> Option<(CodeOwners, Vec<String>)> {
+    let codeowners = CodeOwners::load(&find_repo_root()?)?;
+    let login = get_current_user().await.ok()?;
+    let me = std::iter::once(login)
+        .chain(config.valid_teams())
+        .map(|owner| format!("@{}", owner))
+        .collect();
+    Some((codeowners, me))
+}
+
+/// Fail with the messages from a GraphQL `errors` array. GitHub reports these
+/// with a 200 status, alongside null or partial `data`, so they'd otherwise
+/// surface as a confusing deserialize failure or a silently short list.
+pub fn check_graphql_errors(response: &serde_json::Value) -> Result<()> {
+    let messages = graphql_error_messages(response);
+    if messages.is_empty() {
+        return Ok(());
+    }
+    anyhow::bail!("GitHub API: {}", messages.join("; "))
+}
+
+fn graphql_error_messages(response: &serde_json::Value) -> Vec<String> {
+    response
+        .get("errors")
+        .and_then(|e| e.as_array())
+        .into_iter()
+        .flatten()
+        .map(|e| {
+            e.get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error")
+                .to_string()
+        })
+        .collect()
+}
+
+/// The search connection from a PR search response. Errors next to the
+/// results become its warnings; they only fail a response without results.
+pub fn parse_search_response(response: serde_json::Value) -> Result<SearchConnection> {
+    let messages = graphql_error_messages(&response);
+    let response: SearchGraphQLResponse = serde_json::from_value(response)?;
+    match response.data.and_then(|data| data.search) {
+        // Errors next to results cover only part of them, e.g. a PR the token can't see
+        Some(search) => Ok(SearchConnection {
+            warnings: messages,
+            ..search
+        }),
+        None if messages.is_empty() => Ok(SearchConnection::default()),
+        None => anyhow::bail!("GitHub API: {}", messages.join("; ")),
+    }
+}
+
+/// Add `archived:false` to a search query, unless it already filters on archived
+fn with_archived_qualifier(query: String, exclude_archived: bool) -> String {
+    let has_archived = query
+        .split_whitespace()
+        .any(|term| term.trim_start_matches('-').starts_with("archived:"));
+    if exclude_archived && !has_archived {
+        format!("{} archived:false", query)
+    } else {
+        query
+    }
 }
 
 /// Helper function to fetch PRs for a given search query
@@ -108,9 +522,13 @@ async fn fetch_prs_for_query(
     query_string: String,
     owner: &str,
     repo: &str,
-) -> Result<Vec<PullRequest>> {
+    ownership: Option<&(CodeOwners, Vec<String>)>,
+    reviewer: Option<&str>,
+    max_results: usize,
+) -> Result<(Vec<PullRequest>, Vec<String>)> {
+    // Changed files beyond the first 100 aren't checked against CODEOWNERS
     let query = r#"
-        query($queryString: String!, $after: String) {
+        query($queryString: String!, $after: String, $withFiles: Boolean!, $withReviewers: Boolean!) {
             search(query: $queryString, type: ISSUE, first: 100, after: $after) {
                 nodes {
                     __typename
@@ -118,9 +536,41 @@ async fn fetch_prs_for_query(
                         number
                         title
                         headRefName
+                        reviewDecision
+                        mergeable
+                        isDraft
+                        createdAt
+                        updatedAt
+                        viewerLatestReview {
+                            state
+                        }
                         author {
                             login
                         }
+                        milestone {
+                            title
+                        }
+                        files(first: 100) @include(if: $withFiles) {
+                            nodes {
+                                path
+                            }
+                        }
+                        reviewRequests(first: 20) @include(if: $withReviewers) {
+                            nodes {
+                                requestedReviewer {
+                                    ... on User {
+                                        login
+                                    }
+                                }
+                            }
+                        }
+                        latestReviews(first: 20) @include(if: $withReviewers) {
+                            nodes {
+                                author {
+                                    login
+                                }
+                            }
+                        }
                         commits(last: 1) {
                             nodes {
                                 commit {
@@ -142,46 +592,49 @@ async fn fetch_prs_for_query(
     "#;
 
     let mut prs = Vec::new();
+    let mut warnings = Vec::new();
     let mut after: Option<String> = None;
 
-    // Cap the number of PRs we'll accumulate to avoid runaway pagination.
-    const MAX_RESULTS: usize = 500;
-
     loop {
-        let response: SearchGraphQLResponse = octocrab
+        let response: serde_json::Value = octocrab
             .graphql(&serde_json::json!({
                 "query": query,
                 "variables": {
                     "queryString": query_string,
-                    "after": after
+                    "after": after,
+                    "withFiles": ownership.is_some(),
+                    "withReviewers": reviewer.is_some()
                 }
             }))
             .await?;
+        let search = parse_search_response(response)?;
+        warnings.extend(search.warnings);
 
-        for node in response.data.search.nodes {
-            let (number, title, head_ref_name, commits, author) = match node {
-                SearchNode::PullRequest {
-                    number,
-                    title,
-                    head_ref_name,
-                    commits,
-                    author,
-                } => (number, title, head_ref_name, commits, author),
-                SearchNode::Other => continue,
+        for node in search.nodes {
+            let SearchNode::PullRequest(node) = node else {
+                continue;
             };
-
-            let first_commit = commits.nodes.first();
-
-            let ci_status = first_commit
-                .and_then(|c| c.commit.status_check_rollup.as_ref())
-                .map(|s| s.state.parse().unwrap())
-                .unwrap_or(CiStatus::Unknown);
-
-            let head_sha = first_commit.and_then(|c| c.oid()).map(|s| s.to_string());
-
-            let author_login = author
-                .map(|a| a.login)
-                .unwrap_or_else(|| "unknown".to_string());
+            let ci_status = node.ci_status();
+            let head_sha = node.head_sha().map(str::to_string);
+            let blocking_on_me = reviewer.is_some_and(|login| node.blocked_only_on(login));
+            let PullRequestNode {
+                number,
+                title,
+                head_ref_name,
+                author,
+                review_decision,
+                mergeable,
+                is_draft,
+                milestone,
+                updated_at,
+                created_at,
+                viewer_latest_review,
+                files,
+                ..
+            } = *node;
+
+            // Empty, like the cache column's default, when GitHub returns no author
+            let author_login = author.map(|a| a.login).unwrap_or_default();
 
             prs.push(PullRequest {
                 number,
@@ -192,24 +645,289 @@ async fn fetch_prs_for_query(
                 ci_status,
                 author: author_login,
                 head_sha,
+                review_decision: review_decision
+                    .map(|s| s.parse().unwrap())
+                    .unwrap_or(ReviewDecision::None),
+                mergeable: mergeable
+                    .map(|s| s.parse().unwrap())
+                    .unwrap_or(Mergeable::Unknown),
+                is_draft,
+                milestone: milestone.map(|m| m.title),
+                updated_at: updated_at.as_deref().and_then(parse_timestamp),
+                created_at: created_at.as_deref().and_then(parse_timestamp),
+                my_review_state: viewer_latest_review
+                    .map(|r| r.state.parse().unwrap())
+                    .unwrap_or(ReviewState::None),
+                touches_my_code: ownership
+                    .zip(files)
+                    .is_some_and(|((codeowners, me), files)| {
+                        codeowners.owns_any(files.nodes.iter().map(|f| f.path.as_str()), me)
+                    }),
+                blocking_on_me,
             });
         }
 
-        if prs.len() >= MAX_RESULTS {
+        if prs.len() >= max_results {
             break;
         }
 
-        if !response.data.search.page_info.has_next_page {
+        if !search.page_info.has_next_page {
             break;
         }
 
-        after = response.data.search.page_info.end_cursor;
+        after = search.page_info.end_cursor;
         if after.is_none() {
             break;
         }
     }
 
-    Ok(prs)
+    Ok((prs, warnings))
+}
+
+/// Re-run every job in a GitHub Actions workflow run
+pub async fn rerun_workflow(owner: &str, repo: &str, run_id: u64) -> Result<()> {
+    post_run_action(owner, repo, run_id, "rerun").await
+}
+
+/// Re-run only the failed jobs (and their dependents) of a GitHub Actions workflow run
+pub async fn rerun_failed_jobs(owner: &str, repo: &str, run_id: u64) -> Result<()> {
+    post_run_action(owner, repo, run_id, "rerun-failed-jobs").await
+}
+
+/// Cancel a queued or in-progress GitHub Actions workflow run
+pub async fn cancel_workflow_run(owner: &str, repo: &str, run_id: u64) -> Result<()> {
+    post_run_action(owner, repo, run_id, "cancel").await
+}
+
+async fn post_run_action(owner: &str, repo: &str, run_id: u64, endpoint: &str) -> Result<()> {
+    with_github_token(|token| post_run_action_with_token(token, owner, repo, run_id, endpoint))
+        .await
+}
+
+async fn post_run_action_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    run_id: u64,
+    endpoint: &str,
+) -> Result<()> {
+    let verb = if endpoint == "cancel" {
+        "cancel"
+    } else {
+        "re-run"
+    };
+    let client = reqwest::Client::new();
+    let response = client
+        .post(format!(
+            "https://api.github.com/repos/{}/{}/actions/runs/{}/{}",
+            owner, repo, run_id, endpoint
+        ))
+        .header("Authorization", format!("Bearer {}", token))
+        .header("User-Agent", "ghui")
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    if status == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(token_rejected());
+    }
+    if status == reqwest::StatusCode::FORBIDDEN || status == reqwest::StatusCode::NOT_FOUND {
+        anyhow::bail!(
+            "Can't {} workflows in {}/{}: this requires write access to the repository \
+             (and the workflow scope for classic tokens)",
+            verb,
+            owner,
+            repo
+        );
+    }
+    let body: serde_json::Value = response.json().await.unwrap_or_default();
+    match body["message"].as_str() {
+        Some(message) => anyhow::bail!("Failed to {} workflow: {}", verb, message),
+        None => anyhow::bail!("Failed to {} workflow: {}", verb, status),
+    }
+}
+
+/// Fetch every context in a PR's status check rollup, for the checks popup
+pub async fn fetch_check_contexts(
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<Vec<CheckContext>> {
+    with_github_token(|token| check_contexts_with_token(token, owner, repo, pr_number)).await
+}
+
+async fn check_contexts_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<Vec<CheckContext>> {
+    let octocrab = Octocrab::builder().personal_token(token).build()?;
+
+    let query = r#"
+        query($owner: String!, $repo: String!, $prNumber: Int!) {
+            repository(owner: $owner, name: $repo) {
+                pullRequest(number: $prNumber) {
+                    commits(last: 1) {
+                        nodes {
+                            commit {
+                                statusCheckRollup {
+                                    contexts(first: 100) {
+                                        nodes {
+                                            __typename
+                                            ... on CheckRun {
+                                                name
+                                                status
+                                                conclusion
+                                                detailsUrl
+                                            }
+                                            ... on StatusContext {
+                                                context
+                                                state
+                                                targetUrl
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response: serde_json::Value = octocrab
+        .graphql(&serde_json::json!({
+            "query": query,
+            "variables": {
+                "owner": owner,
+                "repo": repo,
+                "prNumber": pr_number as i64
+            }
+        }))
+        .await?;
+    check_graphql_errors(&response)?;
+
+    Ok(parse_check_contexts(&response))
+}
+
+/// Check runs and commit statuses from a `statusCheckRollup { contexts }`
+/// response. A commit without checks has no rollup, which reads as none.
+pub fn parse_check_contexts(response: &serde_json::Value) -> Vec<CheckContext> {
+    let Some(nodes) = response
+        .pointer(
+            "/data/repository/pullRequest/commits/nodes/0/commit/statusCheckRollup/contexts/nodes",
+        )
+        .and_then(|v| v.as_array())
+    else {
+        return Vec::new();
+    };
+    let str_field = |node: &serde_json::Value, key: &str| {
+        node.get(key).and_then(|v| v.as_str()).map(str::to_string)
+    };
+
+    nodes
+        .iter()
+        .filter_map(|node| match node.get("__typename")?.as_str()? {
+            "CheckRun" => {
+                let status = str_field(node, "status").unwrap_or_else(|| "QUEUED".to_string());
+                let conclusion = str_field(node, "conclusion");
+                Some(CheckContext {
+                    name: str_field(node, "name").unwrap_or_else(|| "Unknown".to_string()),
+                    status: parse_check_status(&status),
+                    conclusion: conclusion.as_deref().map(parse_check_conclusion),
+                    state: conclusion.unwrap_or(status).to_lowercase(),
+                    url: str_field(node, "detailsUrl"),
+                })
+            }
+            "StatusContext" => {
+                let state = str_field(node, "state").unwrap_or_else(|| "PENDING".to_string());
+                let (status, conclusion) = parse_commit_status_state(&state);
+                Some(CheckContext {
+                    name: str_field(node, "context").unwrap_or_else(|| "Unknown".to_string()),
+                    status,
+                    conclusion,
+                    state: state.to_lowercase(),
+                    url: str_field(node, "targetUrl"),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Fetch just the CI rollup and head commit of one PR, for refreshing a single row
+pub async fn fetch_pr_ci_status(
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<(CiStatus, Option<String>)> {
+    with_github_token(|token| ci_status_with_token(token, owner, repo, pr_number)).await
+}
+
+async fn ci_status_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<(CiStatus, Option<String>)> {
+    let octocrab = Octocrab::builder().personal_token(token).build()?;
+
+    let query = r#"
+        query($owner: String!, $repo: String!, $prNumber: Int!) {
+            repository(owner: $owner, name: $repo) {
+                pullRequest(number: $prNumber) {
+                    commits(last: 1) {
+                        nodes {
+                            commit {
+                                oid
+                                statusCheckRollup {
+                                    state
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    "#;
+
+    let response: serde_json::Value = octocrab
+        .graphql(&serde_json::json!({
+            "query": query,
+            "variables": {
+                "owner": owner,
+                "repo": repo,
+                "prNumber": pr_number as i64
+            }
+        }))
+        .await?;
+    check_graphql_errors(&response)?;
+
+    // A just-opened PR can have no commits, which isn't an error
+    let commits = response.pointer("/data/repository/pullRequest/commits/nodes");
+    if commits
+        .and_then(|nodes| nodes.as_array())
+        .is_some_and(|nodes| nodes.is_empty())
+    {
+        return Ok((CiStatus::NoCommits, None));
+    }
+    let commit = response
+        .pointer("/data/repository/pullRequest/commits/nodes/0/commit")
+        .ok_or_else(|| anyhow::anyhow!("No commit data for PR #{}", pr_number))?;
+    let ci_status = commit
+        .pointer("/statusCheckRollup/state")
+        .and_then(|v| v.as_str())
+        .map(|s| s.parse().unwrap())
+        .unwrap_or(CiStatus::Unknown);
+    let head_sha = commit["oid"].as_str().map(|s| s.to_string());
+
+    Ok((ci_status, head_sha))
 }
 
 /// Fetch all checks (GitHub Actions, CircleCI, etc.) for a specific PR
@@ -219,7 +937,15 @@ pub async fn fetch_actions_for_pr(
     pr_number: u64,
     _head_sha: &str, // Not used directly, we fetch via PR number
 ) -> Result<ActionsData> {
-    let token = get_github_token()?;
+    with_github_token(|token| actions_with_token(token, owner, repo, pr_number)).await
+}
+
+async fn actions_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+) -> Result<ActionsData> {
     let octocrab = Octocrab::builder().personal_token(token).build()?;
 
     // Use GraphQL to get all check suites and check runs for the PR's latest commit
@@ -228,17 +954,25 @@ pub async fn fetch_actions_for_pr(
         query($owner: String!, $repo: String!, $prNumber: Int!) {
             repository(owner: $owner, name: $repo) {
                 pullRequest(number: $prNumber) {
+                    headRefOid
                     commits(last: 1) {
                         nodes {
                             commit {
+                                oid
                                 checkSuites(first: 50) {
                                     nodes {
                                         app {
                                             name
                                         }
+                                        commit {
+                                            oid
+                                        }
                                         conclusion
                                         status
                                         url
+                                        workflowRun {
+                                            databaseId
+                                        }
                                         checkRuns(first: 50) {
                                             nodes {
                                                 databaseId
@@ -250,21 +984,8 @@ pub async fn fetch_actions_for_pr(
                                                 completedAt
                                                 text
                                                 summary
-                                                annotations(first: 50) {
-                                                    nodes {
-                                                        path
-                                                        location {
-                                                            start {
-                                                                line
-                                                            }
-                                                            end {
-                                                                line
-                                                            }
-                                                        }
-                                                        annotationLevel
-                                                        message
-                                                        title
-                                                    }
+                                                annotations {
+                                                    totalCount
                                                 }
                                             }
                                         }
@@ -296,11 +1017,23 @@ pub async fn fetch_actions_for_pr(
             }
         }))
         .await?;
+    check_graphql_errors(&response)?;
+
+    parse_actions_response(&response, pr_number)
+}
 
-    let workflow_runs = parse_checks_response(&response)?;
+/// Parse a PR's checks query into actions data, keeping each run's commit so
+/// results for an older push can be told apart from the current head's
+pub fn parse_actions_response(response: &serde_json::Value, pr_number: u64) -> Result<ActionsData> {
+    let workflow_runs = parse_checks_response(response)?;
+    let head_sha = response
+        .pointer("/data/repository/pullRequest/headRefOid")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
 
     Ok(ActionsData {
         pr_number,
+        head_sha,
         workflow_runs,
         error: None,
     })
@@ -312,13 +1045,17 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
     let commit = response
         .pointer("/data/repository/pullRequest/commits/nodes/0/commit")
         .ok_or_else(|| anyhow::anyhow!("No commit data found"))?;
+    let commit_sha = commit
+        .get("oid")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
 
     // Parse check suites (GitHub Actions, CircleCI checks, etc.)
     if let Some(check_suites) = commit
         .pointer("/checkSuites/nodes")
         .and_then(|v| v.as_array())
     {
-        for (idx, suite) in check_suites.iter().enumerate() {
+        for suite in check_suites {
             let app_name = suite
                 .pointer("/app/name")
                 .and_then(|v| v.as_str())
@@ -385,55 +1122,11 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
                         .and_then(|v| v.as_str())
                         .map(|s| s.to_string());
 
-                    // Parse annotations
-                    let mut annotations = Vec::new();
-                    if let Some(annotation_nodes) =
-                        run.pointer("/annotations/nodes").and_then(|v| v.as_array())
-                    {
-                        for ann in annotation_nodes {
-                            let path = ann
-                                .get("path")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("")
-                                .to_string();
-
-                            let start_line = ann
-                                .pointer("/location/start/line")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(0) as u32;
-
-                            let end_line = ann
-                                .pointer("/location/end/line")
-                                .and_then(|v| v.as_u64())
-                                .unwrap_or(start_line as u64)
-                                as u32;
-
-                            let level_str = ann
-                                .get("annotationLevel")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("NOTICE");
-
-                            let message = ann
-                                .get("message")
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("")
-                                .to_string();
-
-                            let title = ann
-                                .get("title")
-                                .and_then(|v| v.as_str())
-                                .map(|s| s.to_string());
-
-                            annotations.push(CheckAnnotation {
-                                path,
-                                start_line,
-                                end_line,
-                                level: level_str.parse().unwrap(),
-                                message,
-                                title,
-                            });
-                        }
-                    }
+                    // Annotations are fetched lazily when the job is opened
+                    let annotations_count = run
+                        .pointer("/annotations/totalCount")
+                        .and_then(|v| v.as_u64())
+                        .unwrap_or(0) as usize;
 
                     jobs.push(WorkflowJob {
                         id: database_id,
@@ -445,7 +1138,8 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
                         details_url,
                         summary,
                         text,
-                        annotations,
+                        annotations: Vec::new(),
+                        annotations_count,
                     });
                 }
             }
@@ -455,8 +1149,18 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
                 let status = parse_check_status(suite_status);
                 let conclusion = suite_conclusion.map(parse_check_conclusion);
 
+                // Actions suites carry the run ID needed to re-run them
+                let id = suite
+                    .pointer("/workflowRun/databaseId")
+                    .and_then(|v| v.as_u64());
+                let head_sha = suite
+                    .pointer("/commit/oid")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string())
+                    .or_else(|| commit_sha.clone());
                 runs.push(WorkflowRun {
-                    id: idx as u64,
+                    id,
+                    provider: CiProvider::from_app_name(&app_name),
                     name: app_name,
                     status,
                     conclusion,
@@ -464,6 +1168,7 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
                     jobs,
                     created_at: String::new(),
                     updated_at: String::new(),
+                    head_sha,
                 });
             }
         }
@@ -512,6 +1217,7 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
                     summary: None,
                     text: None,
                     annotations: Vec::new(),
+                    annotations_count: 0,
                 });
             }
 
@@ -535,15 +1241,27 @@ fn parse_checks_response(response: &serde_json::Value) -> Result<Vec<WorkflowRun
                     (WorkflowStatus::Completed, Some(WorkflowConclusion::Success))
                 };
 
+                // CircleCI reports through commit statuses unless its GitHub App is installed
+                let all_circleci = status_jobs.iter().all(|j| {
+                    j.details_url
+                        .as_deref()
+                        .is_some_and(crate::services::is_circleci_url)
+                });
                 runs.push(WorkflowRun {
-                    id: 999,
+                    id: None,
                     name: "Commit Statuses".to_string(),
+                    provider: if all_circleci {
+                        CiProvider::CircleCi
+                    } else {
+                        CiProvider::Other
+                    },
                     status: overall_status,
                     conclusion: overall_conclusion,
                     html_url: String::new(),
                     jobs: status_jobs,
                     created_at: String::new(),
                     updated_at: String::new(),
+                    head_sha: commit_sha.clone(),
                 });
             }
         }
@@ -589,6 +1307,94 @@ fn parse_commit_status_state(state: &str) -> (WorkflowStatus, Option<WorkflowCon
     }
 }
 
+/// Most annotations fetched for one check run; the rest are left for the browser
+pub const MAX_ANNOTATIONS: usize = 1000;
+
+/// Fetch the annotations for a single check run using the checks API, page by
+/// page up to `MAX_ANNOTATIONS`
+pub async fn fetch_job_annotations(
+    owner: &str,
+    repo: &str,
+    check_run_id: u64,
+) -> Result<Vec<CheckAnnotation>> {
+    with_github_token(|token| annotations_with_token(token, owner, repo, check_run_id)).await
+}
+
+async fn annotations_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    check_run_id: u64,
+) -> Result<Vec<CheckAnnotation>> {
+    const PER_PAGE: usize = 100;
+
+    let client = reqwest::Client::new();
+    let mut annotations = Vec::new();
+
+    for page in 1.. {
+        let url = format!(
+            "https://api.github.com/repos/{}/{}/check-runs/{}/annotations?per_page={}&page={}",
+            owner, repo, check_run_id, PER_PAGE, page
+        );
+        let response = client
+            .get(&url)
+            .header("Authorization", format!("Bearer {}", token))
+            .header("User-Agent", "ghui")
+            .header("Accept", "application/vnd.github+json")
+            .send()
+            .await?;
+
+        if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+            return Err(token_rejected());
+        }
+        if !response.status().is_success() {
+            anyhow::bail!("Failed to fetch annotations: {}", response.status());
+        }
+
+        let json: Vec<serde_json::Value> = response.json().await?;
+        let page_len = json.len();
+
+        for ann in json {
+            let start_line = ann.get("start_line").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+
+            annotations.push(CheckAnnotation {
+                path: ann
+                    .get("path")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                start_line,
+                end_line: ann
+                    .get("end_line")
+                    .and_then(|v| v.as_u64())
+                    .unwrap_or(start_line as u64) as u32,
+                level: ann
+                    .get("annotation_level")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("notice")
+                    .parse()
+                    .unwrap(),
+                message: ann
+                    .get("message")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string(),
+                title: ann
+                    .get("title")
+                    .and_then(|v| v.as_str())
+                    .map(|s| s.to_string()),
+            });
+        }
+
+        if page_len < PER_PAGE || annotations.len() >= MAX_ANNOTATIONS {
+            break;
+        }
+    }
+
+    annotations.truncate(MAX_ANNOTATIONS);
+    Ok(annotations)
+}
+
 /// Fetch job logs using GitHub API
 pub async fn fetch_job_logs(
     owner: &str,
@@ -608,7 +1414,16 @@ pub async fn fetch_job_logs(
         });
     }
 
-    let token = get_github_token()?;
+    with_github_token(|token| job_logs_with_token(token, owner, repo, job_id, job_name)).await
+}
+
+async fn job_logs_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    job_id: u64,
+    job_name: &str,
+) -> Result<JobLogs> {
     let client = reqwest::Client::new();
 
     // GitHub API returns a 302 redirect to download logs
@@ -635,11 +1450,14 @@ pub async fn fetch_job_logs(
         });
     }
 
+    if response.status() == reqwest::StatusCode::UNAUTHORIZED {
+        return Err(token_rejected());
+    }
     if !response.status().is_success() {
         anyhow::bail!("Failed to fetch job logs: {}", response.status());
     }
 
-    let content = response.text().await?;
+    let content = read_capped_log(response, max_log_bytes()).await?;
 
     Ok(JobLogs {
         job_id,
@@ -654,14 +1472,87 @@ pub async fn fetch_job_logs(
     })
 }
 
-/// Fetch PR body and comments for the preview view
-pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result<PreviewData> {
-    let token = get_github_token()?;
+/// Configured cap on fetched log size, or `None` when unlimited
+pub(crate) fn max_log_bytes() -> Option<usize> {
+    let max = load_config().map_or(Config::default().max_log_bytes, |config| {
+        config.max_log_bytes
+    });
+    (max > 0).then_some(max)
+}
+
+/// Read a log response body, stopping once it passes `max_bytes` so huge logs
+/// never land in memory whole
+async fn read_capped_log(
+    mut response: reqwest::Response,
+    max_bytes: Option<usize>,
+) -> Result<String> {
+    let Some(max_bytes) = max_bytes else {
+        return Ok(response.text().await?);
+    };
+    let mut body = Vec::new();
+    while let Some(chunk) = response.chunk().await? {
+        body.extend_from_slice(&chunk);
+        if body.len() > max_bytes {
+            break;
+        }
+    }
+    Ok(truncate_log(
+        String::from_utf8_lossy(&body).into_owned(),
+        Some(max_bytes),
+    ))
+}
+
+/// Cut a log to at most `max_bytes` (on a line break where possible) and note
+/// that the rest is only in the browser
+pub(crate) fn truncate_log(mut log: String, max_bytes: Option<usize>) -> String {
+    let Some(max_bytes) = max_bytes.filter(|&max| log.len() > max) else {
+        return log;
+    };
+    let mut cut = max_bytes;
+    while !log.is_char_boundary(cut) {
+        cut -= 1;
+    }
+    if let Some(newline) = log[..cut].rfind('\n') {
+        cut = newline + 1;
+    }
+    log.truncate(cut);
+    log.push_str(&format!(
+        "\n{} (log truncated, press o to view full log in browser)\n",
+        icons::ellipsis()
+    ));
+    log
+}
+
+/// Most comments or reviews GitHub returns from one GraphQL connection
+pub const MAX_PREVIEW_COMMENTS: usize = 100;
+
+/// Fetch PR body and comments for the preview view: the body plus the most
+/// recent `max_comments` comments and reviews (0 for as many as GitHub allows)
+pub async fn fetch_pr_preview(
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    max_comments: usize,
+) -> Result<PreviewData> {
+    with_github_token(|token| preview_with_token(token, owner, repo, pr_number, max_comments)).await
+}
+
+async fn preview_with_token(
+    token: String,
+    owner: &str,
+    repo: &str,
+    pr_number: u64,
+    max_comments: usize,
+) -> Result<PreviewData> {
     let octocrab = Octocrab::builder().personal_token(token).build()?;
+    let max_comments = match max_comments {
+        0 => MAX_PREVIEW_COMMENTS,
+        n => n.min(MAX_PREVIEW_COMMENTS),
+    };
 
     // GraphQL query to get PR body, comments, and reviews
     let query = r#"
-        query($owner: String!, $repo: String!, $prNumber: Int!) {
+        query($owner: String!, $repo: String!, $prNumber: Int!, $count: Int!) {
             repository(owner: $owner, name: $repo) {
                 pullRequest(number: $prNumber) {
                     title
@@ -670,7 +1561,8 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
                         login
                     }
                     createdAt
-                    comments(first: 100) {
+                    comments(last: $count) {
+                        totalCount
                         nodes {
                             author {
                                 login
@@ -679,7 +1571,8 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
                             createdAt
                         }
                     }
-                    reviews(first: 100) {
+                    reviews(last: $count) {
+                        totalCount
                         nodes {
                             author {
                                 login
@@ -700,10 +1593,12 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
             "variables": {
                 "owner": owner,
                 "repo": repo,
-                "prNumber": pr_number as i64
+                "prNumber": pr_number as i64,
+                "count": max_comments as i64
             }
         }))
         .await?;
+    check_graphql_errors(&response)?;
 
     let pr = response
         .get("data")
@@ -750,6 +1645,8 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
 
     // Collect all comments and reviews with timestamps for sorting
     let mut all_items: Vec<(String, PrComment)> = Vec::new();
+    // Fetched items that won't be shown, so they aren't counted in the total
+    let mut hidden = 0;
 
     // Add actual comments
     if let Some(comment_nodes) = pr
@@ -787,6 +1684,8 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
                         is_pr_body: false,
                     },
                 ));
+            } else {
+                hidden += 1;
             }
         }
     }
@@ -825,19 +1724,24 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
 
             // Skip empty "COMMENTED" reviews (just noise)
             if review_state == "COMMENTED" && review_body.is_empty() {
+                hidden += 1;
                 continue;
             }
 
             // Format review state for display (using nerdfont icons)
             let state_prefix = match review_state.as_str() {
-                "APPROVED" => format!("{} Approved", crate::icons::REVIEW_APPROVED),
+                "APPROVED" => format!("{} Approved", crate::icons::review_approved()),
                 "CHANGES_REQUESTED" => format!(
                     "{} Changes Requested",
-                    crate::icons::REVIEW_CHANGES_REQUESTED
+                    crate::icons::review_changes_requested()
                 ),
-                "COMMENTED" => format!("{} Review", crate::icons::REVIEW_COMMENTED),
-                "DISMISSED" => format!("{} Dismissed", crate::icons::REVIEW_DISMISSED),
-                _ => continue, // Skip unknown/pending states with no useful info
+                "COMMENTED" => format!("{} Review", crate::icons::review_commented()),
+                "DISMISSED" => format!("{} Dismissed", crate::icons::review_dismissed()),
+                // Skip unknown/pending states with no useful info
+                _ => {
+                    hidden += 1;
+                    continue;
+                }
             };
 
             // Include reviews even if body is empty (to show the approval/request status)
@@ -859,9 +1763,23 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
         }
     }
 
-    // Sort by created_at timestamp
+    // Sort by created_at timestamp, then keep the newest across comments and reviews
     all_items.sort_by(|a, b| a.0.cmp(&b.0));
 
+    let total_count = |connection: &str| {
+        pr.get(connection)
+            .and_then(|c| c.get("totalCount"))
+            .and_then(|t| t.as_u64())
+            .unwrap_or(0) as usize
+    };
+    // Never report fewer than we have, e.g. when totalCount is missing
+    let total_comments = (total_count("comments") + total_count("reviews"))
+        .saturating_sub(hidden)
+        .max(all_items.len());
+
+    let skipped = all_items.len().saturating_sub(max_comments);
+    all_items.drain(..skipped);
+
     // Add sorted items to comments
     for (_, comment) in all_items {
         comments.push(comment);
@@ -871,6 +1789,7 @@ pub async fn fetch_pr_preview(owner: &str, repo: &str, pr_number: u64) -> Result
         pr_number,
         title,
         comments,
+        total_comments,
         error: None,
     })
 }
@@ -1,49 +1,126 @@
 use nucleo_matcher::{
     pattern::{CaseMatching, Normalization, Pattern},
-    Matcher,
+    Matcher, Utf32Str,
 };
+use std::cmp::Reverse;
 
-use crate::data::PullRequest;
+use crate::data::{LabelFilter, PullRequest};
+use crate::services::SearchField;
 
-/// Filter pull requests using fuzzy matching.
-/// Returns the indices of matching PRs, sorted by match score (best first).
-pub fn filter_prs(prs: &[PullRequest], query: &str) -> Vec<usize> {
+/// Chars of a PR's title and branch that a search matched, for highlighting
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MatchPositions {
+    pub title: Vec<usize>,
+    pub branch: Vec<usize>,
+}
+
+/// Filter pull requests using fuzzy matching against the given fields
+/// (all of them when `fields` is empty).
+/// Returns the indices of matching PRs, sorted by match score (best first),
+/// each with the positions it matched in the title and branch.
+pub fn filter_prs(
+    prs: &[PullRequest],
+    query: &str,
+    fields: &[SearchField],
+) -> Vec<(usize, MatchPositions)> {
     if query.is_empty() {
-        return (0..prs.len()).collect();
+        return (0..prs.len())
+            .map(|idx| (idx, MatchPositions::default()))
+            .collect();
     }
+    let fields = if fields.is_empty() {
+        &SearchField::ALL[..]
+    } else {
+        fields
+    };
 
     let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
     let pattern = Pattern::parse(query, CaseMatching::Ignore, Normalization::Smart);
-
-    // Build list of (index, haystack) for matching
-    let haystacks: Vec<(usize, String)> = prs
+    let mut indices = Vec::new();
+    let mut matches: Vec<(usize, u32, MatchPositions)> = prs
         .iter()
         .enumerate()
-        .map(|(idx, pr)| {
-            let (ci_text, _) = pr.ci_status.display();
-            (
-                idx,
-                format!(
-                    "#{} {} {} {} {}",
-                    pr.number, pr.author, pr.title, pr.branch, ci_text
-                ),
-            )
+        .filter_map(|(idx, pr)| {
+            // The fields joined by spaces, remembering where each one starts
+            let mut haystack: Vec<char> = Vec::new();
+            let mut title_start = None;
+            let mut branch_start = None;
+            for field in fields {
+                if !haystack.is_empty() {
+                    haystack.push(' ');
+                }
+                let text = match field {
+                    SearchField::Number => format!("#{}", pr.number),
+                    SearchField::Author => pr.author.clone(),
+                    SearchField::Title => {
+                        title_start = Some(haystack.len());
+                        pr.title.clone()
+                    }
+                    SearchField::Branch => {
+                        branch_start = Some(haystack.len());
+                        pr.branch.clone()
+                    }
+                    SearchField::Ci => pr.ci_status.display().0.to_string(),
+                };
+                haystack.extend(text.chars());
+            }
+
+            indices.clear();
+            let score =
+                pattern.indices(Utf32Str::Unicode(&haystack), &mut matcher, &mut indices)?;
+            indices.sort_unstable();
+            indices.dedup();
+            let within = |start: Option<usize>, text: &str| -> Vec<usize> {
+                let Some(start) = start else {
+                    return Vec::new();
+                };
+                let end = start + text.chars().count();
+                indices
+                    .iter()
+                    .map(|&i| i as usize)
+                    .filter(|i| (start..end).contains(i))
+                    .map(|i| i - start)
+                    .collect()
+            };
+            let positions = MatchPositions {
+                title: within(title_start, &pr.title),
+                branch: within(branch_start, &pr.branch),
+            };
+            Some((idx, score, positions))
         })
         .collect();
 
-    // Use match_list to score all items
-    let haystack_refs: Vec<&str> = haystacks.iter().map(|(_, s)| s.as_str()).collect();
-    let matches = pattern.match_list(&haystack_refs, &mut matcher);
-
-    // Convert matches back to indices, sorted by score (match_list returns sorted by score descending)
+    // Best first; ties keep list order
+    matches.sort_by_key(|(_, score, _)| Reverse(*score));
     matches
         .into_iter()
-        .map(|(haystack, _score)| {
-            // Find the index of this haystack
-            haystacks
-                .iter()
-                .position(|(_, s)| s.as_str() == *haystack)
-                .unwrap()
-        })
+        .map(|(idx, _, positions)| (idx, positions))
         .collect()
 }
+
+/// Filter configured label filters by name using fuzzy matching.
+/// Returns the indices of matching labels, sorted by match score (best first).
+pub fn filter_labels(labels: &[LabelFilter], query: &str) -> Vec<usize> {
+    if query.is_empty() {
+        return (0..labels.len()).collect();
+    }
+
+    let mut matcher = Matcher::new(nucleo_matcher::Config::DEFAULT);
+    let pattern = Pattern::parse(query, CaseMatching::Ignore, Normalization::Smart);
+    let mut buf = Vec::new();
+    // Scored one by one so labels sharing a name (repo and global) keep their own index
+    let mut matches: Vec<(usize, u32)> = labels
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, label)| {
+            let haystack = Utf32Str::new(&label.label_name, &mut buf);
+            pattern
+                .score(haystack, &mut matcher)
+                .map(|score| (idx, score))
+        })
+        .collect();
+
+    // Best first; ties keep list order
+    matches.sort_by_key(|(_, score)| Reverse(*score));
+    matches.into_iter().map(|(idx, _)| idx).collect()
+}
@@ -0,0 +1,80 @@
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+use crate::data::TableColumn;
+
+use super::config::{get_config_dir, ColumnWidths};
+
+/// Answers and adjustments remembered between sessions, kept in `state.toml`
+/// next to `config.toml`. Unlike `cache.db` it survives `--clear-cache`,
+/// `no_cache` and cache version bumps.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct State {
+    /// The missing-glyphs warning was dismissed and shouldn't show again
+    pub glyph_warning_acknowledged: bool,
+    /// PR table column widths adjusted with `<` / `>`, read from `[columns]`
+    pub columns: SavedColumnWidths,
+}
+
+/// Widths the user adjusted; unadjusted columns keep the config's width
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SavedColumnWidths {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub branch: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub milestone: Option<u16>,
+}
+
+impl SavedColumnWidths {
+    /// Swap the adjusted widths into the configured ones
+    pub fn apply(&self, widths: &mut ColumnWidths) {
+        for (column, width) in [
+            (TableColumn::Author, self.author),
+            (TableColumn::Branch, self.branch),
+            (TableColumn::Milestone, self.milestone),
+        ] {
+            if let Some(width) = width {
+                widths.set(column, width);
+            }
+        }
+    }
+
+    pub fn set(&mut self, column: TableColumn, width: u16) {
+        let slot = match column {
+            TableColumn::Author => &mut self.author,
+            TableColumn::Branch => &mut self.branch,
+            TableColumn::Milestone => &mut self.milestone,
+        };
+        *slot = Some(width);
+    }
+}
+
+pub fn get_state_path() -> Option<PathBuf> {
+    get_config_dir().map(|p| p.join("state.toml"))
+}
+
+/// Load the saved state, or the defaults when there's none or it can't be read
+pub fn load_state() -> State {
+    get_state_path()
+        .and_then(|path| std::fs::read_to_string(path).ok())
+        .and_then(|content| toml::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Change the saved state and write it back
+pub fn update_state(change: impl FnOnce(&mut State)) -> Result<()> {
+    let path = get_state_path().ok_or_else(|| anyhow::anyhow!("No config dir"))?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut state = load_state();
+    change(&mut state);
+    let content = toml::to_string(&state)?;
+    std::fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
@@ -1,3 +1,10 @@
+pub mod codeowners;
 pub mod git;
+pub mod time;
 
-pub use git::{checkout_branch, get_current_repo, parse_github_url};
+pub use codeowners::CodeOwners;
+pub use git::{
+    branch_exists_locally, checkout_branch, checkout_command, default_branch, find_repo_root,
+    get_current_repo, parse_github_url, uses_jj,
+};
+pub use time::{format_age, jitter, now_timestamp, parse_timestamp};
@@ -0,0 +1,118 @@
+use std::path::Path;
+
+/// Where GitHub looks for CODEOWNERS, in the order it checks them
+const CODEOWNERS_PATHS: &[&str] = &[".github/CODEOWNERS", "CODEOWNERS", "docs/CODEOWNERS"];
+
+/// Ownership rules from a CODEOWNERS file
+#[derive(Debug, Clone, Default)]
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    pattern: String,
+    owners: Vec<String>,
+}
+
+impl CodeOwners {
+    /// Read the CODEOWNERS file GitHub would use from the local checkout, if any
+    pub fn load(repo_root: &Path) -> Option<Self> {
+        CODEOWNERS_PATHS
+            .iter()
+            .find_map(|path| std::fs::read_to_string(repo_root.join(path)).ok())
+            .map(|content| Self::parse(&content))
+    }
+
+    /// Parse CODEOWNERS lines: a pattern followed by owners, with `#` comments
+    pub fn parse(content: &str) -> Self {
+        let rules = content
+            .lines()
+            .filter_map(|line| {
+                let line = line.split('#').next().unwrap_or("").trim();
+                let mut parts = line.split_whitespace();
+                let pattern = parts.next()?.to_string();
+                let owners = parts.map(str::to_string).collect();
+                Some(Rule { pattern, owners })
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Owners of a repository-relative path. The last matching rule wins, and a
+    /// rule without owners leaves the path unowned.
+    pub fn owners_of(&self, path: &str) -> &[String] {
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| pattern_matches(&rule.pattern, path))
+            .map_or(&[], |rule| rule.owners.as_slice())
+    }
+
+    /// Whether any of `paths` is owned by one of `me` ("@login" or "@org/team")
+    pub fn owns_any<'a>(&self, paths: impl IntoIterator<Item = &'a str>, me: &[String]) -> bool {
+        paths.into_iter().any(|path| {
+            self.owners_of(path)
+                .iter()
+                .any(|owner| me.iter().any(|m| m.eq_ignore_ascii_case(owner)))
+        })
+    }
+}
+
+/// Match a gitignore-style CODEOWNERS pattern against a file path. A pattern
+/// that names a directory covers everything below it, but one ending in a
+/// wildcard (`docs/*`) only covers what the wildcard matches.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let dir_only = pattern.ends_with('/');
+    let pattern = pattern.trim_end_matches('/');
+    // A slash anywhere but the end anchors the pattern to the repository root
+    let anchored = pattern.contains('/');
+    let pattern = pattern.trim_start_matches('/');
+
+    let mut pattern_segments: Vec<&str> = pattern.split('/').collect();
+    if !anchored {
+        pattern_segments.insert(0, "**");
+    }
+    let path_segments: Vec<&str> = path.trim_start_matches('/').split('/').collect();
+
+    let last_is_wildcard = pattern_segments
+        .last()
+        .is_some_and(|segment| segment.contains(['*', '?']));
+    if last_is_wildcard && !dir_only {
+        return segments_match(&pattern_segments, &path_segments);
+    }
+    // The file itself, or any directory containing it
+    let longest = if dir_only {
+        path_segments.len() - 1
+    } else {
+        path_segments.len()
+    };
+    (1..=longest).any(|len| segments_match(&pattern_segments, &path_segments[..len]))
+}
+
+fn segments_match(pattern: &[&str], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((&"**", rest)) => (0..=path.len()).any(|skip| segments_match(rest, &path[skip..])),
+        Some((segment, rest)) => path.split_first().is_some_and(|(first, tail)| {
+            glob_matches(segment, first) && segments_match(rest, tail)
+        }),
+    }
+}
+
+/// `*` and `?` wildcards within a single path segment
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    glob_chars(&pattern, &text)
+}
+
+fn glob_chars(pattern: &[char], text: &[char]) -> bool {
+    match pattern.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| glob_chars(rest, &text[skip..])),
+        Some((&c, rest)) => text
+            .split_first()
+            .is_some_and(|(&t, tail)| (c == '?' || c == t) && glob_chars(rest, tail)),
+    }
+}
@@ -1,8 +1,23 @@
+use std::path::PathBuf;
 use std::process::Command;
 
+/// Whether the current directory is a jj repository
+pub fn uses_jj() -> bool {
+    std::path::Path::new(".jj").exists()
+}
+
+/// Walk up from the current directory to the nearest directory containing
+/// `.git` or `.jj`
+pub fn find_repo_root() -> Option<PathBuf> {
+    let cwd = std::env::current_dir().ok()?;
+    cwd.ancestors()
+        .find(|dir| dir.join(".git").exists() || dir.join(".jj").exists())
+        .map(|dir| dir.to_path_buf())
+}
+
 pub fn get_current_repo() -> Option<(String, String)> {
     // Check if repo uses jj by looking for .jj directory
-    let has_jj = std::path::Path::new(".jj").exists();
+    let has_jj = uses_jj();
 
     let output = if has_jj {
         // For jujutsu repos, use jj git remote list
@@ -42,61 +57,115 @@ pub fn get_current_repo() -> Option<(String, String)> {
     parse_github_url(&url)
 }
 
+/// Extract (owner, repo) from a GitHub remote URL in SSH (`git@host:owner/repo.git`)
+/// or URL (`https://host/owner/repo`, `ssh://git@host/owner/repo`) form.
+/// Only the first two path segments are used, so trailing slashes and extra
+/// segments like `/tree/main` are ignored.
 pub fn parse_github_url(url: &str) -> Option<(String, String)> {
-    // Handle SSH: git@github.com:owner/repo.git
-    if url.starts_with("git@github.com:") {
-        let path = url.strip_prefix("git@github.com:")?;
-        let path = path.strip_suffix(".git").unwrap_or(path);
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() >= 2 {
-            return Some((parts[0].to_string(), parts[1].to_string()));
-        }
+    let url = url.trim();
+    let (host, path) = match url.split_once("://") {
+        Some((_, rest)) => rest.split_once('/')?,
+        // scp-like syntax: [user@]host:path
+        None => url.split_once(':')?,
+    };
+    let host = host.rsplit('@').next()?;
+    let host = host.split(':').next()?;
+    if !is_github_host(host) {
+        return None;
     }
 
-    // Handle HTTPS: https://github.com/owner/repo.git
-    if url.contains("github.com") {
-        let path = url.split("github.com").nth(1)?;
-        let path = path.trim_start_matches('/').trim_start_matches(':');
-        let path = path.strip_suffix(".git").unwrap_or(path);
-        let parts: Vec<&str> = path.split('/').collect();
-        if parts.len() >= 2 {
-            return Some((parts[0].to_string(), parts[1].to_string()));
-        }
+    let mut segments = path.split('/').filter(|s| !s.is_empty());
+    let owner = segments.next()?;
+    let repo = segments.next()?;
+    let repo = repo.strip_suffix(".git").unwrap_or(repo);
+    if repo.is_empty() {
+        return None;
     }
+    Some((owner.to_string(), repo.to_string()))
+}
 
-    None
+/// github.com, its SSH-over-HTTPS host, and Enterprise hosts like
+/// `github.example.com` or `example.ghe.com`
+fn is_github_host(host: &str) -> bool {
+    let host = host.to_ascii_lowercase();
+    host == "github.com"
+        || host.ends_with(".github.com")
+        || host.starts_with("github.")
+        || host.ends_with(".ghe.com")
 }
 
-/// Checkout a branch using jj or git depending on the repository type.
-/// Returns Ok(true) if checkout succeeded and the app should exit.
-/// Returns Ok(false) if checkout failed (error will be set).
-/// Returns the error message if checkout failed.
-pub fn checkout_branch(branch: &str) -> Result<(), String> {
-    // Check if repo uses jj by looking for .jj directory
-    let has_jj = std::path::Path::new(".jj").exists();
-
-    let result = if has_jj {
-        // Try edit first
-        let edit_result = Command::new("jj")
-            .args(["edit", &format!("{}@origin", branch)])
-            .output();
-
-        match edit_result {
-            Ok(output) if output.status.success() => Ok(output),
-            _ => {
-                // If edit fails, fall back to new
-                Command::new("jj")
-                    .args(["new", &format!("{}@origin", branch)])
-                    .output()
-            }
-        }
+/// The remote's default branch, from `origin/HEAD` (set by clone, or by
+/// `git remote set-head origin --auto`)
+pub fn default_branch() -> Option<String> {
+    let output = Command::new("git")
+        .args([
+            "symbolic-ref",
+            "--quiet",
+            "--short",
+            "refs/remotes/origin/HEAD",
+        ])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let head = String::from_utf8(output.stdout).ok()?;
+    head.trim()
+        .strip_prefix("origin/")
+        .filter(|branch| !branch.is_empty())
+        .map(String::from)
+}
+
+/// The commands a checkout runs, in order; each is only tried if the previous one failed
+fn checkout_attempts(branch: &str, use_jj: bool) -> Vec<(&'static str, Vec<String>)> {
+    if use_jj {
+        // Try edit first, falling back to new
+        let revision = format!("{}@origin", branch);
+        vec![
+            ("jj", vec!["edit".to_string(), revision.clone()]),
+            ("jj", vec!["new".to_string(), revision]),
+        ]
+    } else {
+        vec![("git", vec!["switch".to_string(), branch.to_string()])]
+    }
+}
+
+/// Describe the command(s) a checkout would run, for dry-run mode
+pub fn checkout_command(branch: &str, use_jj: bool) -> String {
+    checkout_attempts(branch, use_jj)
+        .into_iter()
+        .map(|(program, args)| format!("{} {}", program, args.join(" ")))
+        .collect::<Vec<_>>()
+        .join(" || ")
+}
+
+/// Whether the branch already exists locally, so checkout switches to it
+/// rather than creating it from origin
+pub fn branch_exists_locally(branch: &str, use_jj: bool) -> bool {
+    let output = if use_jj {
+        Command::new("jj")
+            .args(["bookmark", "list", branch])
+            .output()
     } else {
-        Command::new("git").args(["switch", branch]).output()
+        Command::new("git")
+            .args(["rev-parse", "--verify", "--quiet"])
+            .arg(format!("refs/heads/{}", branch))
+            .output()
     };
+    // jj exits successfully for unknown bookmarks, just without listing them
+    output.is_ok_and(|out| out.status.success() && (!use_jj || !out.stdout.is_empty()))
+}
 
-    match result {
-        Ok(output) if output.status.success() => Ok(()),
-        Ok(output) => Err(String::from_utf8_lossy(&output.stderr).trim().to_string()),
-        Err(e) => Err(format!("Failed to checkout: {}", e)),
+/// Checkout a branch using jj or git depending on the repository type.
+/// Returns the error message if checkout failed.
+pub fn checkout_branch(branch: &str, use_jj: bool) -> Result<(), String> {
+    let mut last_error = String::new();
+    for (program, args) in checkout_attempts(branch, use_jj) {
+        match Command::new(program).args(&args).output() {
+            Ok(output) if output.status.success() => return Ok(()),
+            Ok(output) => last_error = String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            Err(e) => last_error = format!("Failed to checkout: {}", e),
+        }
     }
+    Err(last_error)
 }
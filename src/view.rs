@@ -1,5 +1,7 @@
 pub mod components;
+pub mod theme;
 pub mod ui;
 
-pub use components::calculate_preview_positions;
+pub use components::{calculate_preview_positions, help_max_scroll, job_logs_viewport};
+pub use theme::{spinner_frames, Theme};
 pub use ui::ui;
@@ -1,13 +1,19 @@
+pub mod input;
 pub mod popups;
 pub mod search;
 pub mod table;
 pub mod tabs;
 
+pub use input::{render_text_input, text_input_spans};
 pub use popups::{
-    calculate_preview_positions, centered_rect, render_add_label_popup, render_checkout_popup,
-    render_error_popup, render_help_popup, render_job_logs_view, render_labels_popup,
-    render_legend, render_preview_view, render_toast, render_workflows_view, truncate_string,
+    calculate_preview_positions, centered_rect, help_max_scroll, job_logs_viewport,
+    render_add_label_popup, render_add_saved_search_popup, render_checks_popup,
+    render_confirm_popup, render_error_popup, render_glyph_warning_popup, render_help_popup,
+    render_job_logs_view, render_labels_popup, render_legend, render_merge_popup,
+    render_preview_view, render_recent_checkouts_popup, render_rerun_popup,
+    render_saved_searches_popup, render_toast, render_workflows_view, truncate_middle,
+    truncate_string,
 };
 pub use search::render_search_bar;
 pub use table::render_table;
-pub use tabs::render_tabs;
+pub use tabs::{render_startup_progress, render_tabs};
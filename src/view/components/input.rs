@@ -0,0 +1,64 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use crate::app::TextInput;
+use crate::icons;
+
+/// Render a one-line text input after `prompt`, scrolled so the cursor stays visible
+pub fn render_text_input(f: &mut Frame, area: Rect, prompt: &str, input: &TextInput) {
+    let room = (area.width as usize)
+        .saturating_sub(prompt.chars().count())
+        .saturating_sub(1)
+        .max(1);
+    let start = (input.cursor() + 1).saturating_sub(room);
+
+    let mut spans = vec![Span::styled(
+        prompt.to_string(),
+        Style::default().fg(Color::Yellow),
+    )];
+    spans.extend(visible_spans(input, start, room, true));
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+/// Spans for an input's text, with the cursor drawn where it is when `focused`
+pub fn text_input_spans(input: &TextInput, focused: bool) -> Vec<Span<'static>> {
+    visible_spans(input, 0, usize::MAX, focused)
+}
+
+/// Spans for `room` chars of the input starting at `start`
+fn visible_spans(
+    input: &TextInput,
+    start: usize,
+    room: usize,
+    focused: bool,
+) -> Vec<Span<'static>> {
+    let text_style = Style::default().fg(Color::White);
+    let chars: Vec<char> = input.value().chars().collect();
+    let end = chars.len().min(start.saturating_add(room));
+    let text = |range: std::ops::Range<usize>| chars[range].iter().collect::<String>();
+    if !focused {
+        return vec![Span::styled(text(start..end), text_style)];
+    }
+
+    let cursor = input.cursor();
+    let mut spans = vec![Span::styled(text(start..cursor), text_style)];
+    if cursor < chars.len() {
+        // Mid-line, the cursor is the highlighted character under it
+        spans.push(Span::styled(
+            chars[cursor].to_string(),
+            text_style.add_modifier(Modifier::REVERSED),
+        ));
+        spans.push(Span::styled(text(cursor + 1..end), text_style));
+    } else {
+        spans.push(Span::styled(
+            icons::cursor(),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    spans
+}
@@ -3,104 +3,216 @@ use ratatui::{
     layout::{Constraint, Flex, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    widgets::{
+        Block, Borders, Clear, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, Wrap,
+    },
     Frame,
 };
 
-use crate::app::App;
-use crate::data::{AnnotationLevel, WorkflowConclusion, WorkflowStatus};
+use crate::app::{App, Confirmation, KeyMap, TextInput};
+use crate::data::{
+    AnnotationLevel, LabelFilter, MergeMethod, ProviderFilter, PullRequest, WorkflowConclusion,
+    WorkflowJob, WorkflowStatus,
+};
 use crate::icons;
+use crate::utils::{format_age, now_timestamp};
+
+use super::input::{render_text_input, text_input_spans};
 
-/// Render the help popup
-pub fn render_help_popup(f: &mut Frame) {
+/// Render the help popup, scrolled by `help_scroll` when the terminal is too
+/// short to show every key
+pub fn render_help_popup(f: &mut Frame, app: &App) {
     let area = f.area();
-    let popup_width = 40u16;
-    let popup_height = 21u16;
-    let popup_area = centered_rect(popup_width, popup_height, area);
+    let lines = help_lines(&app.keymap);
+    let total = lines.len();
+    let popup_height = total as u16 + HELP_CHROME_HEIGHT;
+    let popup_area = centered_rect(50, popup_height, area);
 
     f.render_widget(Clear, popup_area);
 
-    let help_lines = vec![
-        Line::from(vec![
-            Span::styled("/    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Fuzzy search"),
-        ]),
-        Line::from(vec![
-            Span::styled("1    ", Style::default().fg(Color::Yellow)),
-            Span::raw("My Pull Requests"),
-        ]),
-        Line::from(vec![
-            Span::styled("2    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Review Requested"),
-        ]),
-        Line::from(vec![
-            Span::styled("3    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Labels"),
-        ]),
-        Line::from(vec![
-            Span::styled("l    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Manage labels"),
-        ]),
-        Line::from(vec![
-            Span::styled("w    ", Style::default().fg(Color::Yellow)),
-            Span::raw("View Workflows"),
-        ]),
-        Line::from(vec![
-            Span::styled("j/↓  ", Style::default().fg(Color::Yellow)),
-            Span::raw("Move down"),
-        ]),
-        Line::from(vec![
-            Span::styled("k/↑  ", Style::default().fg(Color::Yellow)),
-            Span::raw("Move up"),
-        ]),
-        Line::from(vec![
-            Span::styled("g/G  ", Style::default().fg(Color::Yellow)),
-            Span::raw("Go to top/bottom"),
-        ]),
-        Line::from(vec![
-            Span::styled("o/⏎  ", Style::default().fg(Color::Yellow)),
-            Span::raw("Open PR in browser"),
-        ]),
-        Line::from(vec![
-            Span::styled("c    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Checkout branch"),
-        ]),
-        Line::from(vec![
-            Span::styled("r    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Refresh"),
-        ]),
+    let block = Block::default()
+        .title(" Help ")
+        .title_style(Style::default().fg(Color::Cyan).bold())
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+    let inner = block.inner(popup_area);
+    f.render_widget(block, popup_area);
+
+    let [keys_area, footer_area] =
+        Layout::vertical([Constraint::Min(0), Constraint::Length(2)]).areas(inner);
+    let viewport = keys_area.height as usize;
+    let scroll = (app.help_scroll as usize).min(total.saturating_sub(viewport));
+    f.render_widget(Paragraph::new(lines).scroll((scroll as u16, 0)), keys_area);
+    render_scrollbar(f, keys_area, total, scroll, viewport);
+
+    let footer = if total > viewport {
         Line::from(vec![
-            Span::styled("q    ", Style::default().fg(Color::Yellow)),
-            Span::raw("Quit"),
-        ]),
+            Span::styled("j/k", Style::default().fg(Color::Yellow)),
+            Span::raw(" scroll  "),
+            Span::raw("any other key closes"),
+        ])
+    } else {
+        Line::from("Press any key to close")
+    };
+    f.render_widget(
+        Paragraph::new(vec![Line::raw(""), footer.centered()]),
+        footer_area,
+    );
+}
+
+/// Rows of the help popup besides the keys: borders, plus a blank line and
+/// the footer below them
+const HELP_CHROME_HEIGHT: u16 = 4;
+
+/// Furthest the help popup scrolls in a terminal `height` rows tall
+pub fn help_max_scroll(keymap: &KeyMap, height: u16) -> u16 {
+    let total = keymap.help_rows().len() as u16;
+    let viewport = (total + HELP_CHROME_HEIGHT)
+        .min(height)
+        .saturating_sub(HELP_CHROME_HEIGHT);
+    total.saturating_sub(viewport)
+}
+
+/// Every bound key the help popup lists, as rebound in `keys.toml`
+fn help_lines(keymap: &KeyMap) -> Vec<Line<'static>> {
+    let rows = keymap.help_rows();
+    let width = rows
+        .iter()
+        .map(|(keys, _)| keys.chars().count())
+        .max()
+        .unwrap_or(0);
+    rows.iter()
+        .map(|(keys, description)| {
+            Line::from(vec![
+                Span::styled(
+                    format!("{:<width$}  ", keys, width = width),
+                    Style::default().fg(Color::Yellow),
+                ),
+                Span::raw(*description),
+            ])
+        })
+        .collect()
+}
+
+/// Render the merge confirmation popup, with the merge method to use
+pub fn render_merge_popup(f: &mut Frame, pr: &PullRequest, method: MergeMethod) {
+    let area = f.area();
+    let popup_width = 60u16.min(area.width.saturating_sub(4));
+    let popup_area = centered_rect(popup_width, 9, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let title_width = popup_width.saturating_sub(10) as usize;
+    let mut content = vec![
+        Line::raw(""),
+        Line::from(truncate_string(
+            &format!("#{} {}", pr.number, pr.title),
+            title_width,
+        ))
+        .centered(),
         Line::raw(""),
-        Line::from("Press any key to close").centered(),
     ];
+    let methods: Vec<Span> = [MergeMethod::Merge, MergeMethod::Squash, MergeMethod::Rebase]
+        .into_iter()
+        .flat_map(|m| {
+            let style = if m == method {
+                Style::default().fg(Color::Black).bg(Color::Cyan).bold()
+            } else {
+                Style::default().fg(Color::DarkGray)
+            };
+            [
+                Span::raw(" "),
+                Span::styled(format!(" {} ", m.to_str()), style),
+            ]
+        })
+        .collect();
+    content.push(Line::from(methods).centered());
+    content.push(Line::raw(""));
+    content.push(
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Cyan).bold()),
+            Span::raw(" method  "),
+            Span::styled("y", Style::default().fg(Color::Green).bold()),
+            Span::raw(" merge  "),
+            Span::styled("n", Style::default().fg(Color::Red).bold()),
+            Span::raw(" cancel"),
+        ])
+        .centered(),
+    );
 
-    let help = Paragraph::new(help_lines).block(
+    let popup = Paragraph::new(content).block(
         Block::default()
-            .title(" Help ")
+            .title(" Merge ")
             .title_style(Style::default().fg(Color::Cyan).bold())
             .borders(Borders::ALL)
             .border_style(Style::default().fg(Color::Cyan)),
     );
 
-    f.render_widget(help, popup_area);
+    f.render_widget(popup, popup_area);
 }
 
-/// Render the checkout confirmation popup
-pub fn render_checkout_popup(f: &mut Frame, branch: &str) {
+/// Render the workflow re-run confirmation popup
+pub fn render_rerun_popup(f: &mut Frame, run_name: &str) {
     let area = f.area();
-    let popup_width = 50u16;
-    let popup_height = 7u16;
-    let popup_area = centered_rect(popup_width, popup_height, area);
+    let popup_width = 56u16.min(area.width.saturating_sub(4));
+    let popup_area = centered_rect(popup_width, 7, area);
 
     f.render_widget(Clear, popup_area);
 
     let content = vec![
         Line::raw(""),
-        Line::from(format!("Checkout branch: {}", branch)).centered(),
+        Line::from(truncate_string(
+            &format!("Re-run {}?", run_name),
+            popup_width.saturating_sub(4) as usize,
+        ))
+        .centered(),
         Line::raw(""),
+        Line::from(vec![
+            Span::styled("a", Style::default().fg(Color::Green).bold()),
+            Span::raw(" all jobs  "),
+            Span::styled("f", Style::default().fg(Color::Yellow).bold()),
+            Span::raw(" failed jobs  "),
+            Span::styled("n", Style::default().fg(Color::Red).bold()),
+            Span::raw(" cancel"),
+        ])
+        .centered(),
+    ];
+
+    let popup = Paragraph::new(content).block(
+        Block::default()
+            .title(" Re-run ")
+            .title_style(Style::default().fg(Color::Cyan).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(popup, popup_area);
+}
+
+/// Render a yes/no confirmation prompt, red-bordered for destructive actions
+pub fn render_confirm_popup(f: &mut Frame, confirmation: &Confirmation) {
+    let area = f.area();
+    let longest = confirmation
+        .lines
+        .iter()
+        .map(|line| line.width())
+        .max()
+        .unwrap_or(0) as u16;
+    let popup_width = (longest + 6).clamp(50, area.width.saturating_sub(4).max(50));
+    let popup_height = confirmation.lines.len() as u16 + 5;
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let mut content = vec![Line::raw("")];
+    content.extend(
+        confirmation
+            .lines
+            .iter()
+            .map(|line| line.clone().centered()),
+    );
+    content.push(Line::raw(""));
+    content.push(
         Line::from(vec![
             Span::raw("Press "),
             Span::styled("y", Style::default().fg(Color::Green).bold()),
@@ -109,14 +221,19 @@ pub fn render_checkout_popup(f: &mut Frame, branch: &str) {
             Span::raw(" to cancel"),
         ])
         .centered(),
-    ];
+    );
 
+    let color = if confirmation.danger {
+        Color::Red
+    } else {
+        Color::Cyan
+    };
     let popup = Paragraph::new(content).block(
         Block::default()
-            .title(" Checkout ")
-            .title_style(Style::default().fg(Color::Cyan).bold())
+            .title(format!(" {} ", confirmation.title))
+            .title_style(Style::default().fg(color).bold())
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(Style::default().fg(color)),
     );
 
     f.render_widget(popup, popup_area);
@@ -125,7 +242,9 @@ pub fn render_checkout_popup(f: &mut Frame, branch: &str) {
 /// Render the error popup
 pub fn render_error_popup(f: &mut Frame, error: &str) {
     let area = f.area();
-    let popup_width = (area.width * 60 / 100).max(40).min(area.width - 4);
+    let popup_width = (area.width * 60 / 100)
+        .max(40)
+        .min(area.width.saturating_sub(4));
     let popup_height = 7u16;
     let popup_area = centered_rect(popup_width, popup_height, area);
 
@@ -165,8 +284,8 @@ pub fn render_error_popup(f: &mut Frame, error: &str) {
 /// Render the labels management popup
 pub fn render_labels_popup(f: &mut Frame, app: &App) {
     let area = f.area();
-    let popup_width = 50u16;
-    let popup_height = 16u16;
+    let popup_width = 56u16;
+    let popup_height = 18u16;
     let popup_area = centered_rect(popup_width, popup_height, area);
 
     f.render_widget(Clear, popup_area);
@@ -185,16 +304,28 @@ pub fn render_labels_popup(f: &mut Frame, app: &App) {
         Line::raw(""),
     ];
 
-    // Separate repo-specific and global labels
-    let repo_labels: Vec<_> = app
-        .configured_labels
+    // Search line, shown while searching or when a query narrows the list
+    if app.labels_search_mode || !app.labels_search_query.is_empty() {
+        let mut spans = vec![Span::styled("/", Style::default().fg(Color::Yellow))];
+        spans.extend(text_input_spans(
+            &app.labels_search_query,
+            app.labels_search_mode,
+        ));
+        lines.push(Line::from(spans));
+        lines.push(Line::raw(""));
+    }
+
+    // Separate repo-specific and global labels; positions index the filtered list
+    let visible_labels: Vec<(usize, &LabelFilter)> = (0..app.labels_filtered_indices.len())
+        .filter_map(|pos| app.visible_label(pos).map(|label| (pos, label)))
+        .collect();
+    let repo_labels: Vec<_> = visible_labels
         .iter()
-        .filter(|l| !l.is_global())
+        .filter(|(_, l)| !l.is_global())
         .collect();
-    let global_labels: Vec<_> = app
-        .configured_labels
+    let global_labels: Vec<_> = visible_labels
         .iter()
-        .filter(|l| l.is_global())
+        .filter(|(_, l)| l.is_global())
         .collect();
 
     if !repo_labels.is_empty() {
@@ -202,16 +333,22 @@ pub fn render_labels_popup(f: &mut Frame, app: &App) {
             "Repo labels:",
             Style::default().fg(Color::Yellow),
         ));
-        for (i, label) in repo_labels.iter().enumerate() {
-            let is_selected = app.labels_list_state.selected() == Some(i);
-            let prefix = if is_selected { icons::SELECTOR } else { "  " };
+        for (pos, label) in repo_labels {
+            let is_selected = app.labels_list_state.selected() == Some(*pos);
+            let prefix = if is_selected { icons::selector() } else { "  " };
             let style = if is_selected {
-                Style::default().fg(Color::Cyan).bold()
+                app.theme.selection
             } else {
                 Style::default().fg(Color::White)
             };
             lines.push(Line::styled(
-                format!("{}{} {}", prefix, icons::BULLET, label.label_name),
+                format!(
+                    "{}{} {}{}",
+                    prefix,
+                    icons::bullet(),
+                    label.label_name,
+                    focus_marker(app, label)
+                ),
                 style,
             ));
         }
@@ -223,17 +360,22 @@ pub fn render_labels_popup(f: &mut Frame, app: &App) {
             "Global labels:",
             Style::default().fg(Color::Yellow),
         ));
-        let offset = repo_labels.len();
-        for (i, label) in global_labels.iter().enumerate() {
-            let is_selected = app.labels_list_state.selected() == Some(offset + i);
-            let prefix = if is_selected { icons::SELECTOR } else { "  " };
+        for (pos, label) in global_labels {
+            let is_selected = app.labels_list_state.selected() == Some(*pos);
+            let prefix = if is_selected { icons::selector() } else { "  " };
             let style = if is_selected {
-                Style::default().fg(Color::Cyan).bold()
+                app.theme.selection
             } else {
                 Style::default().fg(Color::White)
             };
             lines.push(Line::styled(
-                format!("{}{} {} (global)", prefix, icons::BULLET, label.label_name),
+                format!(
+                    "{}{} {} (global){}",
+                    prefix,
+                    icons::bullet(),
+                    label.label_name,
+                    focus_marker(app, label)
+                ),
                 style,
             ));
         }
@@ -246,6 +388,12 @@ pub fn render_labels_popup(f: &mut Frame, app: &App) {
             Style::default().fg(Color::DarkGray),
         ));
         lines.push(Line::raw(""));
+    } else if visible_labels.is_empty() {
+        lines.push(Line::styled(
+            "No labels match",
+            Style::default().fg(Color::DarkGray),
+        ));
+        lines.push(Line::raw(""));
     }
 
     // Hint line
@@ -254,6 +402,10 @@ pub fn render_labels_popup(f: &mut Frame, app: &App) {
         Span::raw(" add  "),
         Span::styled("d", Style::default().fg(Color::Yellow)),
         Span::raw(" delete  "),
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(" only  "),
+        Span::styled("/", Style::default().fg(Color::Yellow)),
+        Span::raw(" search  "),
         Span::styled("Esc", Style::default().fg(Color::Yellow)),
         Span::raw(" close"),
     ]));
@@ -269,6 +421,15 @@ pub fn render_labels_popup(f: &mut Frame, app: &App) {
     f.render_widget(popup, popup_area);
 }
 
+/// Suffix marking the label the Labels tab is focused on
+fn focus_marker(app: &App, label: &LabelFilter) -> &'static str {
+    if app.active_focused_label() == Some(label.label_name.as_str()) {
+        " (only)"
+    } else {
+        ""
+    }
+}
+
 /// Render the add label popup
 pub fn render_add_label_popup(f: &mut Frame, app: &App) {
     let area = f.area();
@@ -283,11 +444,7 @@ pub fn render_add_label_popup(f: &mut Frame, app: &App) {
 
     let content = vec![
         Line::raw(""),
-        Line::from(vec![
-            Span::styled("Label: ", Style::default().fg(Color::Yellow)),
-            Span::styled(&app.label_input, Style::default().fg(Color::White)),
-            Span::styled(icons::CURSOR, Style::default().fg(Color::Cyan)),
-        ]),
+        Line::raw(""), // Label input, drawn over this row below
         Line::raw(""),
         Line::styled("Scope:", Style::default().fg(Color::Yellow)),
         Line::from(vec![
@@ -320,6 +477,276 @@ pub fn render_add_label_popup(f: &mut Frame, app: &App) {
     );
 
     f.render_widget(popup, popup_area);
+
+    let input_area = Rect::new(
+        popup_area.x + 1,
+        popup_area.y + 2,
+        popup_area.width.saturating_sub(2),
+        1,
+    );
+    render_text_input(f, input_area, "Label: ", &app.label_input);
+}
+
+/// Render the saved searches popup
+pub fn render_saved_searches_popup(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let popup_width = 60u16;
+    let popup_height = 16u16;
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines: Vec<Line> = vec![Line::raw("")];
+
+    if app.saved_searches.is_empty() {
+        lines.push(Line::styled(
+            "No saved searches",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    for (i, search) in app.saved_searches.iter().enumerate() {
+        let is_selected = app.saved_searches_list_state.selected() == Some(i);
+        let prefix = if is_selected { icons::selector() } else { "  " };
+        let style = if is_selected {
+            app.theme.selection
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}{} {}", prefix, icons::bullet(), search.name),
+                style,
+            ),
+            Span::styled(
+                format!("  {}", search.query),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines.push(Line::raw(""));
+
+    // Hint line
+    lines.push(Line::from(vec![
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(" run  "),
+        Span::styled("a", Style::default().fg(Color::Yellow)),
+        Span::raw(" add  "),
+        Span::styled("d", Style::default().fg(Color::Yellow)),
+        Span::raw(" delete  "),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" close"),
+    ]));
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Saved Searches ")
+            .title_style(Style::default().fg(Color::Cyan).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(popup, popup_area);
+}
+
+/// Render the recently checked-out branches, newest first
+pub fn render_recent_checkouts_popup(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let popup_width = 70u16;
+    let popup_height = 16u16;
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines: Vec<Line> = vec![Line::raw("")];
+
+    if app.recent_checkouts.is_empty() {
+        lines.push(Line::styled(
+            "No branches checked out with ghui yet",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    let now = now_timestamp();
+    for (i, checkout) in app.recent_checkouts.iter().enumerate() {
+        let is_selected = app.recent_checkouts_list_state.selected() == Some(i);
+        let prefix = if is_selected { icons::selector() } else { "  " };
+        let style = if is_selected {
+            app.theme.selection
+        } else {
+            Style::default().fg(Color::White)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}{} ", prefix, i + 1),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(checkout.branch.clone(), style),
+            Span::styled(
+                format!(
+                    "  #{}  {} ago",
+                    checkout.pr_number,
+                    format_age(now - checkout.checked_out_at)
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines.push(Line::raw(""));
+
+    // Hint line
+    lines.push(Line::from(vec![
+        Span::styled("1-9", Style::default().fg(Color::Yellow)),
+        Span::raw("/"),
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(" checkout  "),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" close"),
+    ]));
+
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(" Recent Checkouts ")
+            .title_style(Style::default().fg(Color::Cyan).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(popup, popup_area);
+}
+
+/// Render the status checks popup: one row per check run or commit status
+pub fn render_checks_popup(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let popup_width = 90u16.min(area.width.saturating_sub(4));
+    // Room for the checks plus the blank lines and hint, within the screen
+    let popup_height = (app.checks.len().max(1) as u16 + 5).min(area.height.saturating_sub(4));
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let mut lines: Vec<Line> = vec![Line::raw("")];
+
+    if app.checks_loading {
+        lines.push(Line::styled(
+            "Loading checks...",
+            Style::default().fg(Color::DarkGray),
+        ));
+    } else if app.checks.is_empty() {
+        lines.push(Line::styled(
+            "No checks reported for this PR's head commit",
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    // Scroll so the selected check stays in view
+    let visible = popup_height.saturating_sub(5).max(1) as usize;
+    let selected = app.checks_list_state.selected().unwrap_or(0);
+    let start = (selected + 1).saturating_sub(visible);
+    let inner_width = popup_width.saturating_sub(2) as usize;
+    let name_width = (inner_width / 2).max(12);
+    for (i, check) in app.checks.iter().enumerate().skip(start).take(visible) {
+        let is_selected = app.checks_list_state.selected() == Some(i);
+        let prefix = if is_selected { icons::selector() } else { "  " };
+        let (icon, color) = get_workflow_status_display(check.status, check.conclusion);
+        let name_style = if is_selected {
+            app.theme.selection
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let state = format!("{:<16}", check.state);
+        let url_width = inner_width.saturating_sub(name_width + state.len() + 8);
+        lines.push(Line::from(vec![
+            Span::raw(prefix),
+            Span::styled(format!("{} ", icon), Style::default().fg(color)),
+            Span::styled(
+                format!(
+                    "{:<width$} ",
+                    truncate_string(&check.name, name_width),
+                    width = name_width
+                ),
+                name_style,
+            ),
+            Span::styled(state, Style::default().fg(color)),
+            Span::styled(
+                truncate_string(check.url.as_deref().unwrap_or(""), url_width),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
+    }
+    lines.push(Line::raw(""));
+
+    // Hint line
+    lines.push(Line::from(vec![
+        Span::styled("j/k", Style::default().fg(Color::Yellow)),
+        Span::raw(" nav  "),
+        Span::styled("o", Style::default().fg(Color::Yellow)),
+        Span::raw("/"),
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(" open check  "),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" close"),
+    ]));
+
+    let title = match app.checks_pr {
+        Some(number) => format!(" Checks for #{} ", number),
+        None => " Checks ".to_string(),
+    };
+    let popup = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .title_style(Style::default().fg(Color::Cyan).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(popup, popup_area);
+}
+
+/// Render the add saved search popup
+pub fn render_add_saved_search_popup(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let popup_width = 60u16;
+    let popup_height = 9u16;
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let field = |prompt: &'static str, input: &TextInput, focused: bool| {
+        let mut spans = vec![Span::styled(prompt, Style::default().fg(Color::Yellow))];
+        spans.extend(text_input_spans(input, focused));
+        Line::from(spans)
+    };
+    let editing_query = app.saved_search_editing_query;
+
+    let content = vec![
+        Line::raw(""),
+        field("Name:  ", &app.saved_search_name_input, !editing_query),
+        field("Query: ", &app.saved_search_query_input, editing_query),
+        Line::styled(
+            "       e.g. label:bug author:octocat",
+            Style::default().fg(Color::DarkGray),
+        ),
+        Line::raw(""),
+        Line::from(vec![
+            Span::styled("Tab", Style::default().fg(Color::Yellow)),
+            Span::raw(" switch field  "),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" save  "),
+            Span::styled("Esc", Style::default().fg(Color::Yellow)),
+            Span::raw(" cancel"),
+        ]),
+    ];
+
+    let popup = Paragraph::new(content).block(
+        Block::default()
+            .title(" Add Saved Search ")
+            .title_style(Style::default().fg(Color::Cyan).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Cyan)),
+    );
+
+    f.render_widget(popup, popup_area);
 }
 
 /// Render the workflows view as a full page
@@ -327,11 +754,13 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
     let area = f.area();
 
     // Create the outer block - show refresh indicator in title if loading while data exists
-    let title = if app.actions_loading && app.actions_data.is_some() {
-        format!(" Workflows {} ", app.spinner())
-    } else {
-        " Workflows ".to_string()
-    };
+    let mut title = " Workflows ".to_string();
+    if app.workflows_provider_filter != ProviderFilter::All {
+        title.push_str(&format!("[{}] ", app.workflows_provider_filter.label()));
+    }
+    if app.actions_loading && app.actions_data.is_some() {
+        title.push_str(&format!("{} ", app.spinner()));
+    }
     let block = Block::default()
         .title(title)
         .title_style(Style::default().fg(Color::Cyan).bold())
@@ -361,11 +790,29 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
                 Span::styled(number.to_string(), Style::default().fg(Color::Cyan)),
                 Span::styled(" - ", Style::default().fg(Color::DarkGray)),
                 Span::styled(
-                    truncate_string(title, (area.width as usize).saturating_sub(20)),
+                    truncate_string(title, (area.width as usize).saturating_sub(30)),
                     Style::default().fg(Color::White),
                 ),
+                Span::styled(
+                    app.selected_pr()
+                        .and_then(|pr| pr.short_sha())
+                        .map(|sha| format!(" @ {}", sha))
+                        .unwrap_or_default(),
+                    Style::default().fg(Color::Yellow),
+                ),
             ]),
-            Line::raw(""),
+            // CI can lag behind the latest push; don't let old results pass for current ones
+            match app.actions_data.as_ref().and_then(|data| data.stale_sha()) {
+                Some(sha) => Line::styled(
+                    format!(
+                        "{} CI for older commit {} (r to refresh)",
+                        icons::warning(),
+                        sha
+                    ),
+                    Style::default().fg(Color::Black).bg(Color::Yellow).bold(),
+                ),
+                None => Line::raw(""),
+            },
         ]);
         f.render_widget(header, header_area);
     }
@@ -382,10 +829,22 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
         Line::from(vec![
             Span::styled("Enter", Style::default().fg(Color::Yellow)),
             Span::raw(" view logs  "),
+            Span::styled("Space", Style::default().fg(Color::Yellow)),
+            Span::raw(" summary  "),
             Span::styled("r", Style::default().fg(Color::Yellow)),
             Span::raw(" refresh  "),
             Span::styled("o", Style::default().fg(Color::Yellow)),
             Span::raw(" open  "),
+            Span::styled("y", Style::default().fg(Color::Yellow)),
+            Span::raw(" copy SHA  "),
+            Span::styled("Y", Style::default().fg(Color::Yellow)),
+            Span::raw(" copy URL  "),
+            Span::styled("t", Style::default().fg(Color::Yellow)),
+            Span::raw(" provider  "),
+            Span::styled("R", Style::default().fg(Color::Yellow)),
+            Span::raw(" re-run  "),
+            Span::styled("X", Style::default().fg(Color::Yellow)),
+            Span::raw(" cancel  "),
             Span::styled("q", Style::default().fg(Color::Yellow)),
             Span::raw(" back"),
             auto_refresh_indicator,
@@ -414,14 +873,20 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
             content_lines.push(Line::raw(""));
         }
 
+        let filter = app.workflows_provider_filter;
         if data.workflow_runs.is_empty() {
             content_lines.push(Line::styled(
                 "No workflow runs found",
                 Style::default().fg(Color::DarkGray),
             ));
+        } else if data.visible_runs(filter).next().is_none() {
+            content_lines.push(Line::styled(
+                format!("No {} runs (t to change provider)", filter.label()),
+                Style::default().fg(Color::DarkGray),
+            ));
         } else {
             let mut job_index = 0;
-            for run in &data.workflow_runs {
+            for run in data.visible_runs(filter) {
                 // Workflow header
                 let (status_icon, status_color) =
                     get_workflow_status_display(run.status, run.conclusion);
@@ -439,7 +904,7 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
                         selected_line_index = content_lines.len();
                     }
                     let prefix = if is_selected {
-                        icons::SELECTOR_INDENTED
+                        icons::selector_indented()
                     } else {
                         "    "
                     };
@@ -448,7 +913,7 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
                         get_workflow_status_display(job.status, job.conclusion);
 
                     let style = if is_selected {
-                        Style::default().fg(Color::Cyan).bold()
+                        app.theme.selection
                     } else {
                         Style::default().fg(Color::White)
                     };
@@ -460,6 +925,14 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
                         Span::styled(&job.name, style),
                     ]));
 
+                    if is_selected {
+                        content_lines.extend(job_summary_lines(
+                            job,
+                            app.job_summary_expanded,
+                            content_area.width as usize,
+                        ));
+                    }
+
                     job_index += 1;
                 }
                 content_lines.push(Line::raw(""));
@@ -484,6 +957,78 @@ pub fn render_workflows_view(f: &mut Frame, app: &App) {
     f.render_widget(content, content_area);
 }
 
+/// Number of check output `text` lines shown before explicit expansion is required
+const JOB_TEXT_PREVIEW_LINES: usize = 3;
+
+/// Build the inline check output lines shown under the selected job.
+/// Collapsed: the first summary line plus a short preview of `text`.
+/// Expanded: the full summary and text bodies.
+fn job_summary_lines(job: &WorkflowJob, expanded: bool, width: usize) -> Vec<Line<'static>> {
+    let summary = job.summary.as_deref().unwrap_or("").trim();
+    let text = job.text.as_deref().unwrap_or("").trim();
+    if summary.is_empty() && text.is_empty() {
+        return Vec::new();
+    }
+
+    let indent = "        ";
+    let max_width = width.saturating_sub(indent.len() + 1);
+    let summary_style = Style::default().fg(Color::Gray);
+    let text_style = Style::default().fg(Color::DarkGray);
+    let mut lines: Vec<Line<'static>> = Vec::new();
+
+    let push_wrapped = |line: &str, style: Style, lines: &mut Vec<Line<'static>>| {
+        for wrapped in wrap_text(line, max_width) {
+            lines.push(Line::from(vec![
+                Span::raw(indent),
+                Span::styled(wrapped, style),
+            ]));
+        }
+    };
+
+    if expanded {
+        for line in summary.lines().filter(|l| !l.trim().is_empty()) {
+            push_wrapped(line, summary_style, &mut lines);
+        }
+        if !text.is_empty() {
+            lines.push(Line::raw(""));
+            for line in text.lines() {
+                push_wrapped(line, text_style, &mut lines);
+            }
+        }
+        lines.push(Line::raw(""));
+        return lines;
+    }
+
+    if let Some(first) = summary.lines().find(|l| !l.trim().is_empty()) {
+        lines.push(Line::from(vec![
+            Span::raw(indent),
+            Span::styled(truncate_string(first.trim(), max_width), summary_style),
+        ]));
+    }
+
+    let text_lines: Vec<&str> = text.lines().filter(|l| !l.trim().is_empty()).collect();
+    for line in text_lines.iter().take(JOB_TEXT_PREVIEW_LINES) {
+        lines.push(Line::from(vec![
+            Span::raw(indent),
+            Span::styled(truncate_string(line.trim(), max_width), text_style),
+        ]));
+    }
+
+    let has_more = summary.lines().filter(|l| !l.trim().is_empty()).count() > 1
+        || text_lines.len() > JOB_TEXT_PREVIEW_LINES;
+    if has_more {
+        lines.push(Line::from(vec![
+            Span::raw(indent),
+            Span::styled(
+                format!("{} (Space to expand)", icons::ellipsis()),
+                Style::default().fg(Color::DarkGray).italic(),
+            ),
+        ]));
+    }
+
+    lines
+}
+
 /// Render the job logs view as a full page
 pub fn render_job_logs_view(f: &mut Frame, app: &App) {
     // Use annotations view if we have annotations, otherwise show regular logs
@@ -494,15 +1039,34 @@ pub fn render_job_logs_view(f: &mut Frame, app: &App) {
     }
 }
 
+/// Footer hint for switching back to annotations, when the job has any
+fn annotations_hint(app: &App) -> Vec<Span<'static>> {
+    if app.annotations.is_empty() {
+        return Vec::new();
+    }
+    vec![
+        Span::styled("a", Style::default().fg(Color::Yellow)),
+        Span::raw(" annotations  "),
+    ]
+}
+
 /// Render the annotations view (for reviewdog, etc.)
 fn render_annotations_view(f: &mut Frame, app: &App) {
     let area = f.area();
 
     // Get job name for title
-    let title = if let Some(ref logs) = app.job_logs {
-        format!(" {} ({} findings) ", logs.job_name, app.annotations.len())
+    let shown = app.annotations.len();
+    let findings = if app.annotations_total > shown {
+        format!(
+            "showing first {} of {} findings",
+            shown, app.annotations_total
+        )
     } else {
-        " Annotations ".to_string()
+        format!("{} findings", shown)
+    };
+    let title = match app.job_logs {
+        Some(ref logs) => format!(" {} ({}) ", logs.job_name, findings),
+        None => format!(" Annotations ({}) ", findings),
     };
 
     let block = Block::default()
@@ -527,7 +1091,7 @@ fn render_annotations_view(f: &mut Frame, app: &App) {
     // Render footer with annotation-specific hints
     let footer_line = if let Some(ref feedback) = app.clipboard_feedback {
         Line::from(vec![Span::styled(
-            format!("{} {}", icons::STATUS_SUCCESS, feedback),
+            format!("{} {}", icons::status_success(), feedback),
             Style::default().fg(Color::Green),
         )])
     } else {
@@ -540,10 +1104,22 @@ fn render_annotations_view(f: &mut Frame, app: &App) {
         Line::from(vec![
             Span::styled("j/k", Style::default().fg(Color::Yellow)),
             Span::raw(" navigate  "),
+            Span::styled("{/}", Style::default().fg(Color::Yellow)),
+            Span::raw(" file  "),
             Span::styled("v", Style::default().fg(Color::Yellow)),
             Span::raw(" select  "),
             Span::styled("y", Style::default().fg(Color::Yellow)),
             Span::raw(copy_hint),
+            Span::styled("Y", Style::default().fg(Color::Yellow)),
+            Span::raw(" by file  "),
+            Span::styled("p", Style::default().fg(Color::Yellow)),
+            Span::raw(" path:line  "),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" edit  "),
+            Span::styled("s", Style::default().fg(Color::Yellow)),
+            Span::raw(format!(" sort: {}  ", app.annotations_sort.label())),
+            Span::styled("a", Style::default().fg(Color::Yellow)),
+            Span::raw(" raw logs  "),
             Span::styled("o", Style::default().fg(Color::Yellow)),
             Span::raw(" open  "),
             Span::styled("q", Style::default().fg(Color::Yellow)),
@@ -562,9 +1138,9 @@ fn render_annotations_view(f: &mut Frame, app: &App) {
 
         // Level indicator with color (use consistent spacing)
         let (level_icon, level_color) = match ann.level {
-            AnnotationLevel::Failure => (icons::ANNOTATION_FAILURE, Color::Red),
-            AnnotationLevel::Warning => (icons::ANNOTATION_WARNING, Color::Yellow),
-            AnnotationLevel::Notice => (icons::ANNOTATION_NOTICE, Color::Blue),
+            AnnotationLevel::Failure => (icons::annotation_failure(), Color::Red),
+            AnnotationLevel::Warning => (icons::annotation_warning(), Color::Yellow),
+            AnnotationLevel::Notice => (icons::annotation_notice(), Color::Blue),
         };
 
         // Check if this annotation is selected for copying
@@ -572,17 +1148,17 @@ fn render_annotations_view(f: &mut Frame, app: &App) {
 
         // Selection indicator: ▶ for cursor, ● for marked, space otherwise
         let prefix = if is_selected && is_marked {
-            icons::SELECTOR_MARKED
+            icons::selector_marked()
         } else if is_selected {
-            icons::SELECTOR_ONLY
+            icons::selector_only()
         } else if is_marked {
-            icons::MARKED_ONLY
+            icons::marked_only()
         } else {
-            icons::UNMARKED
+            icons::unmarked()
         };
 
         let highlight_style = if is_selected {
-            Style::default().fg(Color::Cyan).bold()
+            app.theme.selection
         } else if is_marked {
             Style::default().fg(Color::Green)
         } else {
@@ -596,8 +1172,8 @@ fn render_annotations_view(f: &mut Frame, app: &App) {
             format!("{}:{}-{}", ann.path, ann.start_line, ann.end_line)
         };
 
-        // Main line: [prefix][icon] file:line
-        lines.push(Line::from(vec![
+        // Main line: [prefix][icon] [title] file:line
+        let mut header = vec![
             Span::styled(
                 prefix,
                 if is_marked {
@@ -607,8 +1183,13 @@ fn render_annotations_view(f: &mut Frame, app: &App) {
                 },
             ),
             Span::styled(level_icon, Style::default().fg(level_color)),
-            Span::styled(line_info, highlight_style),
-        ]));
+        ];
+        // Linters usually put the rule name here, e.g. "E501 line too long"
+        if let Some(title) = ann.title.as_deref().filter(|t| !t.is_empty()) {
+            header.push(Span::styled(format!("{} ", title), highlight_style.bold()));
+        }
+        header.push(Span::styled(line_info, highlight_style));
+        lines.push(Line::from(header));
 
         // Message lines (indented, wrapped)
         let message_style = if is_selected {
@@ -723,24 +1304,32 @@ fn render_foldable_steps_view(f: &mut Frame, app: &App, area: Rect) {
     // Render footer with step-specific hints
     let footer_line = if let Some(ref feedback) = app.clipboard_feedback {
         Line::from(vec![Span::styled(
-            format!("{} {}", icons::STATUS_SUCCESS, feedback),
+            format!("{} {}", icons::status_success(), feedback),
             Style::default().fg(Color::Green),
         )])
     } else {
-        Line::from(vec![
+        let mut spans = vec![
             Span::styled("j/k", Style::default().fg(Color::Yellow)),
             Span::raw(" nav  "),
             Span::styled("Enter", Style::default().fg(Color::Yellow)),
             Span::raw(" expand  "),
+            Span::styled("f", Style::default().fg(Color::Yellow)),
+            Span::raw(" failure  "),
             Span::styled("y", Style::default().fg(Color::Yellow)),
             Span::raw(" yank tests  "),
             Span::styled("x", Style::default().fg(Color::Yellow)),
             Span::raw(" copy  "),
+            Span::styled("s", Style::default().fg(Color::Yellow)),
+            Span::raw(" save  "),
+        ];
+        spans.extend(annotations_hint(app));
+        spans.extend([
             Span::styled("o", Style::default().fg(Color::Yellow)),
             Span::raw(" open  "),
             Span::styled("q", Style::default().fg(Color::Yellow)),
             Span::raw(" back"),
-        ])
+        ]);
+        Line::from(spans)
     };
     let footer = Paragraph::new(vec![Line::raw(""), footer_line]);
     f.render_widget(footer, footer_area);
@@ -768,23 +1357,23 @@ fn render_foldable_steps_view(f: &mut Frame, app: &App, area: Rect) {
 
                 // Step/Container header
                 let prefix = if is_container_selected {
-                    icons::SELECTOR
+                    icons::selector()
                 } else {
                     "  "
                 };
                 let fold_icon = if has_sub_steps || !step.output.is_empty() {
                     if is_expanded {
-                        "▼"
+                        icons::fold_expanded()
                     } else {
-                        "▶"
+                        icons::fold_collapsed()
                     }
                 } else {
                     " "
                 };
                 let status_icon = if step.is_failed {
-                    icons::STATUS_FAILURE
+                    icons::status_failure()
                 } else {
-                    icons::STATUS_SUCCESS
+                    icons::status_success()
                 };
                 let status_color = if step.is_failed {
                     Color::Red
@@ -831,15 +1420,19 @@ fn render_foldable_steps_view(f: &mut Frame, app: &App, area: Rect) {
 
                             // Sub-step header (indented)
                             let sub_prefix = if is_sub_selected {
-                                format!("  {}", icons::SELECTOR)
+                                format!("  {}", icons::selector())
                             } else {
                                 "    ".to_string()
                             };
-                            let sub_fold_icon = if is_sub_expanded { "▼" } else { "▶" };
+                            let sub_fold_icon = if is_sub_expanded {
+                                icons::fold_expanded()
+                            } else {
+                                icons::fold_collapsed()
+                            };
                             let sub_status_icon = if sub_step.is_failed {
-                                icons::STATUS_FAILURE
+                                icons::status_failure()
                             } else {
-                                icons::STATUS_SUCCESS
+                                icons::status_success()
                             };
                             let sub_status_color = if sub_step.is_failed {
                                 Color::Red
@@ -938,12 +1531,28 @@ fn render_foldable_steps_view(f: &mut Frame, app: &App, area: Rect) {
             // Clear the content area first to prevent artifacts
             f.render_widget(Clear, content_area);
 
+            let total = lines.len();
             let content = Paragraph::new(lines).scroll((scroll_offset, 0));
             f.render_widget(content, content_area);
+            render_scrollbar(
+                f,
+                content_area,
+                total,
+                scroll_offset as usize,
+                visible_height,
+            );
         }
     }
 }
 
+/// Rows of the plain logs view besides the log itself: borders and the footer
+const LOGS_CHROME_HEIGHT: u16 = 4;
+
+/// Log lines the plain logs view shows in a terminal `height` rows tall
+pub fn job_logs_viewport(height: u16) -> usize {
+    height.saturating_sub(LOGS_CHROME_HEIGHT).max(1) as usize
+}
+
 /// Render plain text logs (GitHub Actions, etc.)
 fn render_plain_logs_view(f: &mut Frame, app: &App, area: Rect) {
     // Get job name for title
@@ -977,20 +1586,28 @@ fn render_plain_logs_view(f: &mut Frame, app: &App, area: Rect) {
     // Render footer
     let footer_line = if let Some(ref feedback) = app.clipboard_feedback {
         Line::from(vec![Span::styled(
-            format!("{} {}", icons::STATUS_SUCCESS, feedback),
+            format!("{} {}", icons::status_success(), feedback),
             Style::default().fg(Color::Green),
         )])
     } else {
-        Line::from(vec![
+        let mut spans = vec![
             Span::styled("j/k", Style::default().fg(Color::Yellow)),
             Span::raw(" scroll  "),
+            Span::styled("f", Style::default().fg(Color::Yellow)),
+            Span::raw(" failure  "),
             Span::styled("y", Style::default().fg(Color::Yellow)),
             Span::raw(" copy  "),
+            Span::styled("s", Style::default().fg(Color::Yellow)),
+            Span::raw(" save  "),
+        ];
+        spans.extend(annotations_hint(app));
+        spans.extend([
             Span::styled("o", Style::default().fg(Color::Yellow)),
             Span::raw(" open  "),
             Span::styled("q", Style::default().fg(Color::Yellow)),
             Span::raw(" back"),
-        ])
+        ]);
+        Line::from(spans)
     };
     let footer = Paragraph::new(vec![Line::raw(""), footer_line]);
     f.render_widget(footer, footer_area);
@@ -1003,16 +1620,43 @@ fn render_plain_logs_view(f: &mut Frame, app: &App, area: Rect) {
         ])]);
         f.render_widget(loading, content_area);
     } else if let Some(ref logs) = app.job_logs {
+        // Only build the lines that fit; every line takes at least one row.
+        // Logs can run to tens of thousands of lines.
+        let visible = content_area.height as usize;
         let lines: Vec<Line> = logs
             .content
             .lines()
-            .map(|line| Line::raw(line.to_string()))
+            .skip(app.job_logs_scroll as usize)
+            .take(visible)
+            .map(Line::raw)
             .collect();
 
-        let content = Paragraph::new(lines)
-            .scroll((app.job_logs_scroll, 0))
-            .wrap(Wrap { trim: false });
-        f.render_widget(content, content_area);
+        // Leave the last column for the scrollbar
+        let text_area = Rect {
+            width: content_area.width.saturating_sub(1),
+            ..content_area
+        };
+        // Long lines wrap, so fewer lines than rows may fit. Size the thumb
+        // by the lines that actually made it on screen.
+        let width = text_area.width.max(1) as usize;
+        let mut rows = 0;
+        let shown = lines
+            .iter()
+            .take_while(|line| {
+                rows += line.width().div_ceil(width).max(1);
+                rows <= visible
+            })
+            .count()
+            .max(1);
+        let content = Paragraph::new(lines).wrap(Wrap { trim: false });
+        f.render_widget(content, text_area);
+        render_scrollbar(
+            f,
+            content_area,
+            app.job_logs_line_count,
+            app.job_logs_scroll as usize,
+            shown,
+        );
     } else {
         let empty = Paragraph::new("No logs available");
         f.render_widget(empty, content_area);
@@ -1026,26 +1670,28 @@ fn get_workflow_status_display(
 ) -> (&'static str, Color) {
     match status {
         WorkflowStatus::Completed => match conclusion {
-            Some(WorkflowConclusion::Success) => (icons::STATUS_SUCCESS, Color::Green),
-            Some(WorkflowConclusion::Failure) => (icons::STATUS_FAILURE, Color::Red),
-            Some(WorkflowConclusion::Cancelled) => (icons::STATUS_CANCELLED, Color::Yellow),
-            Some(WorkflowConclusion::Skipped) => (icons::STATUS_SKIPPED, Color::DarkGray),
-            Some(WorkflowConclusion::TimedOut) => (icons::STATUS_TIMED_OUT, Color::Red),
+            Some(WorkflowConclusion::Success) => (icons::status_success(), Color::Green),
+            Some(WorkflowConclusion::Failure) => (icons::status_failure(), Color::Red),
+            Some(WorkflowConclusion::Cancelled) => (icons::status_cancelled(), Color::Yellow),
+            Some(WorkflowConclusion::Skipped) => (icons::status_skipped(), Color::DarkGray),
+            Some(WorkflowConclusion::TimedOut) => (icons::status_timed_out(), Color::Red),
             Some(WorkflowConclusion::ActionRequired) => {
-                (icons::STATUS_ACTION_REQUIRED, Color::Yellow)
+                (icons::status_action_required(), Color::Yellow)
             }
-            _ => (icons::STATUS_UNKNOWN, Color::DarkGray),
+            _ => (icons::status_unknown(), Color::DarkGray),
         },
-        WorkflowStatus::InProgress => (icons::STATUS_IN_PROGRESS, Color::Yellow),
-        WorkflowStatus::Queued => (icons::STATUS_QUEUED, Color::DarkGray),
-        WorkflowStatus::Pending => (icons::STATUS_QUEUED, Color::Yellow),
-        WorkflowStatus::Waiting => (icons::STATUS_WAITING, Color::DarkGray),
-        _ => (icons::STATUS_UNKNOWN, Color::DarkGray),
+        WorkflowStatus::InProgress => (icons::status_in_progress(), Color::Yellow),
+        WorkflowStatus::Queued => (icons::status_queued(), Color::DarkGray),
+        WorkflowStatus::Pending => (icons::status_queued(), Color::Yellow),
+        WorkflowStatus::Waiting => (icons::status_waiting(), Color::DarkGray),
+        _ => (icons::status_unknown(), Color::DarkGray),
     }
 }
 
 /// Calculate a centered rectangle within an area
 pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
+    // Never larger than the area, however small the terminal
+    let (width, height) = (width.min(area.width), height.min(area.height));
     let vertical = Layout::vertical([Constraint::Length(height)])
         .flex(Flex::Center)
         .split(area);
@@ -1055,27 +1701,101 @@ pub fn centered_rect(width: u16, height: u16, area: Rect) -> Rect {
         .split(vertical[0])[0]
 }
 
+/// Draw a vertical scrollbar on the right edge of `area` for `total` rows of
+/// content scrolled down by `offset`, only when they don't all fit in `viewport`
+pub fn render_scrollbar(f: &mut Frame, area: Rect, total: usize, offset: usize, viewport: usize) {
+    if total <= viewport || area.height < 2 {
+        return;
+    }
+    let mut scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+        .style(Style::default().fg(Color::DarkGray));
+    if icons::is_ascii() {
+        scrollbar = scrollbar
+            .begin_symbol(Some("^"))
+            .end_symbol(Some("v"))
+            .track_symbol(Some("|"))
+            .thumb_symbol("#");
+    }
+    // One position per possible offset, so the thumb reaches the end at the last page
+    let mut state = ScrollbarState::new(total - viewport + 1)
+        .position(offset)
+        .viewport_content_length(viewport);
+    f.render_stateful_widget(scrollbar, area, &mut state);
+}
+
+/// Rows `lines` take up when wrapped to `width`
+fn wrapped_line_count(lines: &[Line], width: u16) -> usize {
+    let width = width.max(1) as usize;
+    lines
+        .iter()
+        .map(|line| line.width().div_ceil(width).max(1))
+        .sum()
+}
+
+/// Render the one-time warning shown when Nerd Font glyphs may not render
+pub fn render_glyph_warning_popup(f: &mut Frame) {
+    let area = f.area();
+    let popup_width = 60u16.min(area.width.saturating_sub(4));
+    let popup_height = 11u16;
+    let popup_area = centered_rect(popup_width, popup_height, area);
+
+    f.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from("This terminal may not be able to show the icons ghui uses."),
+        Line::from("If you see boxes or question marks, either install a Nerd"),
+        Line::from("Font (https://www.nerdfonts.com) or use ASCII mode with"),
+        Line::from(vec![
+            Span::styled("--ascii", Style::default().fg(Color::Yellow)),
+            Span::raw(" or "),
+            Span::styled("ascii = true", Style::default().fg(Color::Yellow)),
+            Span::raw(" in config.toml."),
+        ]),
+        Line::from(""),
+        Line::from("This message won't be shown again."),
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("a", Style::default().fg(Color::Yellow)),
+            Span::raw(" use ASCII now  "),
+            Span::styled("Enter", Style::default().fg(Color::Yellow)),
+            Span::raw(" dismiss"),
+        ])
+        .centered(),
+    ];
+
+    let popup = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .title(" Missing Glyphs? ")
+            .title_style(Style::default().fg(Color::Yellow).bold())
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Yellow)),
+    );
+    f.render_widget(popup, popup_area);
+}
+
 /// Render a toast notification at the bottom of the screen (for clipboard feedback)
 pub fn render_toast(f: &mut Frame, app: &App) {
     // URL popup - centered modal that requires manual dismiss
-    if let Some(ref url) = app.show_url_popup {
+    if let Some(ref urls) = app.show_url_popup {
         let area = f.area();
-        let popup_width = (url.len() as u16 + 6)
+        // One URL per line when several PRs were opened at once
+        let widest = urls.lines().map(str::len).max().unwrap_or(0);
+        let popup_width = (widest as u16 + 6)
             .min(area.width.saturating_sub(4))
             .max(30);
-        let popup_height = 5;
+        let popup_height = urls.lines().count() as u16 + 4;
         let popup_area = centered_rect(popup_width, popup_height, area);
 
         f.render_widget(Clear, popup_area);
 
-        let lines = vec![
-            Line::from(""),
-            Line::from(url.as_str()).centered(),
+        let mut lines = vec![Line::from("")];
+        lines.extend(urls.lines().map(|url| Line::from(url).centered()));
+        lines.extend([
             Line::from(""),
             Line::from("Press Enter or q to close")
                 .centered()
                 .style(Style::default().fg(Color::DarkGray)),
-        ];
+        ]);
 
         let popup = Paragraph::new(lines).block(
             Block::default()
@@ -1111,15 +1831,39 @@ pub fn render_toast(f: &mut Frame, app: &App) {
 
 /// Truncate a string to a maximum length with ellipsis
 pub fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if s.chars().count() <= max_len {
         s.to_string()
     } else {
-        format!("{}…", &s[..max_len - 1])
+        let ellipsis = icons::ellipsis();
+        let kept: String = s
+            .chars()
+            .take(max_len.saturating_sub(ellipsis.chars().count()))
+            .collect();
+        format!("{}{}", kept, ellipsis)
     }
 }
 
+/// Truncate a string to a maximum length by cutting out the middle, keeping
+/// both the start and the end
+pub fn truncate_middle(s: &str, max_len: usize) -> String {
+    let len = s.chars().count();
+    let ellipsis = icons::ellipsis();
+    let available = max_len.saturating_sub(ellipsis.chars().count());
+    if len <= max_len {
+        return s.to_string();
+    }
+    if available < 2 {
+        return truncate_string(s, max_len);
+    }
+    let head = available.div_ceil(2);
+    let tail = available - head;
+    let start: String = s.chars().take(head).collect();
+    let end: String = s.chars().skip(len - tail).collect();
+    format!("{}{}{}", start, ellipsis, end)
+}
+
 /// Wrap text to fit within a maximum width, breaking on word boundaries
-fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
+pub(super) fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
     if max_width == 0 {
         return vec![text.to_string()];
     }
@@ -1176,8 +1920,8 @@ fn wrap_text(text: &str, max_width: usize) -> Vec<String> {
 }
 
 /// Render the bottom legend with keyboard shortcuts
-pub fn render_legend(f: &mut Frame, area: Rect) {
-    let legend = Line::from(vec![
+pub fn render_legend(f: &mut Frame, app: &App, area: Rect) {
+    let mut legend = Line::from(vec![
         Span::styled("j/k", Style::default().fg(Color::Yellow)),
         Span::raw(" nav  "),
         Span::styled("o", Style::default().fg(Color::Yellow)),
@@ -1198,6 +1942,25 @@ pub fn render_legend(f: &mut Frame, area: Rect) {
         Span::raw(" quit"),
     ]);
 
+    // Show the type-ahead prefix while it's active
+    if let Some(ref prefix) = app.type_ahead {
+        legend = Line::from(vec![
+            Span::styled("'", Style::default().fg(Color::Yellow)),
+            Span::styled(prefix.clone(), Style::default().fg(Color::White)),
+            Span::styled(icons::cursor(), Style::default().fg(Color::Cyan)),
+            Span::raw("  jump to title"),
+        ]);
+    }
+
+    // Make it clear that nothing is fetched until the user asks for it
+    if app.config.offline_start {
+        legend.push_span(Span::raw(format!("  {}  ", icons::vertical_separator())));
+        legend.push_span(Span::styled(
+            format!("cache only {} r to fetch", icons::dash()),
+            Style::default().fg(Color::Yellow),
+        ));
+    }
+
     let paragraph = Paragraph::new(legend).style(Style::default().fg(Color::DarkGray));
     f.render_widget(paragraph, area);
 }
@@ -1208,7 +1971,17 @@ pub fn render_preview_view(f: &mut Frame, app: &App) {
 
     // Get PR info for title
     let title = if let Some((ref pr_title, pr_number)) = app.preview_pr_info {
-        format!(" #{} - {} ", pr_number, truncate_string(pr_title, 60))
+        let pr = app.selected_pr();
+        let mut title = format!(" #{} - {}", pr_number, truncate_string(pr_title, 60));
+        if let Some(sha) = pr.and_then(|pr| pr.short_sha()) {
+            title.push_str(&format!(" @ {}", sha));
+        }
+        if let Some(created_at) = pr.and_then(|pr| pr.created_at) {
+            let age = format_age(now_timestamp() - created_at);
+            title.push_str(&format!(" {} opened {} ago", icons::middle_dot(), age));
+        }
+        title.push(' ');
+        title
     } else {
         " Preview ".to_string()
     };
@@ -1252,7 +2025,23 @@ pub fn render_preview_view(f: &mut Frame, app: &App) {
         Span::styled("q", Style::default().fg(Color::Yellow)),
         Span::raw(" back"),
     ]);
-    let footer = Paragraph::new(vec![Line::raw(""), footer_line]);
+    // Note when older comments were left out, above the keys
+    let notice_line = match app.preview_data {
+        Some(ref data) if data.shown_comments() < data.total_comments => Line::from(vec![
+            Span::styled(
+                format!(
+                    "Showing last {} of {} comments  ",
+                    data.shown_comments(),
+                    data.total_comments
+                ),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled("L", Style::default().fg(Color::Yellow)),
+            Span::raw(" load more"),
+        ]),
+        _ => Line::raw(""),
+    };
+    let footer = Paragraph::new(vec![notice_line, footer_line]);
     f.render_widget(footer, footer_area);
 
     // Render content
@@ -1270,7 +2059,7 @@ pub fn render_preview_view(f: &mut Frame, app: &App) {
             if idx > 0 {
                 lines.push(Line::raw(""));
                 lines.push(Line::styled(
-                    "─".repeat(content_area.width as usize - 2),
+                    icons::separator_char().repeat(content_area.width.saturating_sub(2) as usize),
                     Style::default().fg(Color::DarkGray),
                 ));
                 lines.push(Line::raw(""));
@@ -1296,7 +2085,8 @@ pub fn render_preview_view(f: &mut Frame, app: &App) {
             lines.push(Line::raw(""));
 
             // Render markdown body
-            let md_lines = markdown_to_lines(&comment.body, content_area.width as usize - 2);
+            let md_lines =
+                markdown_to_lines(&comment.body, content_area.width.saturating_sub(2) as usize);
             lines.extend(md_lines);
         }
 
@@ -1307,10 +2097,23 @@ pub fn render_preview_view(f: &mut Frame, app: &App) {
             ));
         }
 
+        // Leave the last column for the scrollbar
+        let text_area = Rect {
+            width: content_area.width.saturating_sub(1),
+            ..content_area
+        };
+        let total = wrapped_line_count(&lines, text_area.width);
         let content = Paragraph::new(lines)
             .scroll((app.preview_scroll, 0))
             .wrap(Wrap { trim: false });
-        f.render_widget(content, content_area);
+        f.render_widget(content, text_area);
+        render_scrollbar(
+            f,
+            content_area,
+            total,
+            app.preview_scroll as usize,
+            content_area.height as usize,
+        );
     } else {
         let empty = Paragraph::new("No preview data available");
         f.render_widget(empty, content_area);
@@ -1380,7 +2183,10 @@ fn markdown_to_lines(markdown: &str, _max_width: usize) -> Vec<Line<'static>> {
                 }
                 Tag::Item => {
                     flush_text(&mut current_text, &mut current_spans, bold, italic, code);
-                    current_spans.push(Span::styled("• ", Style::default().fg(Color::Yellow)));
+                    current_spans.push(Span::styled(
+                        format!("{} ", icons::bullet()),
+                        Style::default().fg(Color::Yellow),
+                    ));
                 }
                 Tag::Strong => {
                     flush_text(&mut current_text, &mut current_spans, bold, italic, code);
@@ -7,26 +7,57 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::icons;
+
+use super::input::text_input_spans;
 
 /// Render the search bar
 pub fn render_search_bar(f: &mut Frame, app: &App, area: Rect) {
     let total_count = app.current_prs().len();
     let filtered_count = app.filtered_indices.len();
 
-    let cursor = if app.search_mode { icons::CURSOR } else { "" };
-    let count_display = if app.search_query.is_empty() {
+    let quick_filtered = app.ready_to_merge_only
+        || app.milestone_filter.is_some()
+        || app.hide_approved
+        || app.hide_done;
+    let count_display = if app.search_query.is_empty() && !quick_filtered {
         String::new()
+    } else if app.search_query.value().starts_with(':') {
+        " (Enter to search GitHub)".to_string()
     } else {
         format!(" ({}/{})", filtered_count, total_count)
     };
 
-    let search_line = Line::from(vec![
-        Span::styled("/", Style::default().fg(Color::Yellow)),
-        Span::styled(&app.search_query, Style::default().fg(Color::White)),
-        Span::styled(cursor, Style::default().fg(Color::Cyan)),
-        Span::styled(count_display, Style::default().fg(Color::DarkGray)),
-    ]);
+    let mut spans = Vec::new();
+    if app.ready_to_merge_only {
+        spans.push(Span::styled(
+            "[ready to merge] ",
+            Style::default().fg(Color::Green),
+        ));
+    }
+    if let Some(ref milestone) = app.milestone_filter {
+        spans.push(Span::styled(
+            format!("[milestone: {}] ", milestone),
+            Style::default().fg(Color::Magenta),
+        ));
+    }
+    if app.hide_approved {
+        spans.push(Span::styled(
+            format!("[{} approved hidden] ", app.hidden_approved),
+            Style::default().fg(Color::Cyan),
+        ));
+    }
+    if app.hide_done {
+        spans.push(Span::styled(
+            format!("[{} done hidden] ", app.hidden_done),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+    spans.push(Span::styled("/", Style::default().fg(Color::Yellow)));
+    spans.extend(text_input_spans(&app.search_query, app.search_mode));
+    spans.push(Span::styled(
+        count_display,
+        Style::default().fg(Color::DarkGray),
+    ));
 
-    f.render_widget(Paragraph::new(search_line), area);
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
 }
@@ -1,91 +1,406 @@
 use ratatui::{
-    layout::{Constraint, Rect},
+    layout::{Alignment, Constraint, Rect},
     style::{Color, Modifier, Style, Stylize},
-    widgets::{Cell, Row, Table},
+    text::{Line, Span, Text},
+    widgets::{Cell, Paragraph, Row, Table},
     Frame,
 };
 
 use crate::app::App;
-use crate::data::PrFilter;
+use crate::data::{PrFilter, PullRequest};
 use crate::icons;
 
-use super::popups::truncate_string;
+use crate::services::{StalenessConfig, TruncationStyle};
+use crate::utils::{format_age, now_timestamp};
+
+use super::popups::{render_scrollbar, truncate_middle, truncate_string, wrap_text};
+
+const MIN_TITLE_WIDTH: u16 = 20;
+const UPDATED_WIDTH: u16 = 7;
+/// Marks Review Requested PRs where everyone else has already reviewed
+const BLOCKING_MARKER: &str = "blocking ";
+const DRAFT_MARKER: &str = "[draft] ";
 
 /// Render the PR table
 pub fn render_table(f: &mut Frame, app: &App, area: Rect) {
-    let visible_prs = app.visible_prs();
+    let prs = app.current_prs();
+    let show_source = app.pr_filter == PrFilter::All;
     let show_owner = matches!(
         app.pr_filter,
-        PrFilter::ReviewRequested | PrFilter::Labels(_)
+        PrFilter::ReviewRequested
+            | PrFilter::Labels(_)
+            | PrFilter::All
+            | PrFilter::Search(_)
+            | PrFilter::Team(_)
+            | PrFilter::Recent
     );
+    // Only spend the width when something in this list is in a milestone
+    let show_milestone = prs.iter().any(|pr| pr.milestone.is_some());
 
-    let header = if show_owner {
-        Row::new(vec![
-            Cell::from("PR#").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("Author").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("Title").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("Branch").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("CI Status").style(Style::default().fg(Color::Yellow).bold()),
-        ])
-    } else {
-        Row::new(vec![
-            Cell::from("PR#").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("Title").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("Branch").style(Style::default().fg(Color::Yellow).bold()),
-            Cell::from("CI Status").style(Style::default().fg(Color::Yellow).bold()),
-        ])
+    let header_style = Style::default().fg(Color::Yellow).bold();
+    let mut header_cells = vec![Cell::from("PR#").style(header_style)];
+    if show_source {
+        header_cells.push(Cell::from("Source").style(header_style));
+    }
+    if show_owner {
+        header_cells.push(Cell::from("Author").style(header_style));
+    }
+    header_cells.push(Cell::from("Title").style(header_style));
+    if show_milestone {
+        header_cells.push(Cell::from("Milestone").style(header_style));
+    }
+    header_cells.extend([
+        Cell::from("Branch").style(header_style),
+        Cell::from("Updated").style(header_style),
+        Cell::from("CI Status").style(header_style),
+    ]);
+    let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+    // Fixed columns: PR#, optional Source/Author/Milestone, Branch, CI Status. The
+    // title column gets whatever is left, so clamp the branch to keep it usable.
+    let author_width = app.column_widths.author;
+    let milestone_width = app.column_widths.milestone;
+    let mut fixed_width = 8 + UPDATED_WIDTH + 12 + icons::selector().chars().count() as u16;
+    if show_source {
+        fixed_width += 8;
     }
-    .height(1)
-    .bottom_margin(1);
+    if show_owner {
+        fixed_width += author_width;
+    }
+    if show_milestone {
+        fixed_width += milestone_width;
+    }
+    let column_count = 4 + show_source as u16 + show_owner as u16 + show_milestone as u16;
+    fixed_width += column_count; // one space between each column
+    let branch_width = app
+        .column_widths
+        .branch
+        .min(area.width.saturating_sub(fixed_width + MIN_TITLE_WIDTH))
+        .max(4);
+    let title_width = area.width.saturating_sub(fixed_width + branch_width) as usize;
 
-    let rows: Vec<Row> = visible_prs
+    let selected = app.table_state.selected();
+    let now = now_timestamp();
+    let mut row_heights = Vec::with_capacity(app.filtered_indices.len());
+    let rows: Vec<Row> = app
+        .filtered_indices
         .iter()
-        .map(|pr| {
+        .filter_map(|&idx| prs.get(idx).map(|pr| (idx, pr)))
+        .enumerate()
+        .map(|(row, (idx, pr))| {
             let (ci_text, ci_color) = pr.ci_status.display();
+            let mut cells = vec![if app.is_marked(pr) {
+                Cell::from(format!("{}#{}", icons::bullet(), pr.number))
+                    .style(Style::default().fg(Color::Cyan).bold())
+            } else {
+                Cell::from(format!("#{}", pr.number))
+            }];
+            if show_source {
+                let (source_text, source_color) = app
+                    .pr_source(idx)
+                    .map(|source| source.display())
+                    .unwrap_or(("", Color::DarkGray));
+                cells.push(Cell::from(source_text).style(Style::default().fg(source_color)));
+            }
             if show_owner {
-                Row::new(vec![
-                    Cell::from(format!("#{}", pr.number)),
-                    Cell::from(pr.author.clone()).style(Style::default().fg(Color::Magenta)),
-                    Cell::from(truncate_string(&pr.title, 45)),
-                    Cell::from(truncate_string(&pr.branch, 22)),
-                    Cell::from(ci_text).style(Style::default().fg(ci_color)),
-                ])
+                cells.push(Cell::from(author_span(pr)));
+            }
+            let mut markers = Vec::new();
+            if pr.blocking_on_me {
+                markers.push(Span::styled(
+                    BLOCKING_MARKER,
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                ));
+            }
+            if pr.is_draft {
+                markers.push(Span::styled(
+                    DRAFT_MARKER,
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            let markers_width: usize = markers.iter().map(|m| m.content.len()).sum();
+            let title_width = title_width.saturating_sub(markers_width);
+            // The selected row grows to show its whole title; the rest stay one line
+            let positions = app.match_positions.get(&idx);
+            let title_positions = positions.map_or(&[][..], |p| p.title.as_slice());
+            let mut title_lines: Vec<Line> = if app.config.expand_selected_title
+                && selected == Some(row)
+                && pr.title.chars().count() > title_width
+            {
+                let lines = wrap_text(&pr.title, title_width);
+                let offsets = wrapped_offsets(&pr.title, &lines);
+                lines
+                    .iter()
+                    .zip(&offsets)
+                    .map(|(line, offsets)| highlighted_line(line, offsets, title_positions))
+                    .collect()
             } else {
-                Row::new(vec![
-                    Cell::from(format!("#{}", pr.number)),
-                    Cell::from(truncate_string(&pr.title, 50)),
-                    Cell::from(truncate_string(&pr.branch, 25)),
-                    Cell::from(ci_text).style(Style::default().fg(ci_color)),
-                ])
+                let line = truncate_title(&pr.title, title_width, app.config.title_truncation);
+                let offsets = truncated_offsets(&pr.title, &line);
+                vec![highlighted_line(&line, &offsets, title_positions)]
+            };
+            let height = title_lines.len() as u16;
+            row_heights.push(height);
+            if let Some(first) = title_lines.first_mut() {
+                first.spans.splice(0..0, markers);
+            }
+            let title_cell = Cell::from(Text::from(title_lines));
+            // PRs touching code CODEOWNERS assigns to me stand out
+            cells.push(if pr.touches_my_code {
+                title_cell.style(Style::default().fg(Color::LightYellow))
+            } else {
+                title_cell
+            });
+            if show_milestone {
+                cells.push(
+                    Cell::from(truncate_string(
+                        pr.milestone.as_deref().unwrap_or(""),
+                        milestone_width as usize,
+                    ))
+                    .style(Style::default().fg(Color::Magenta)),
+                );
+            }
+            cells.extend([
+                Cell::from({
+                    let branch = truncate_string(&pr.branch, branch_width as usize);
+                    let offsets = truncated_offsets(&pr.branch, &branch);
+                    let branch_positions = positions.map_or(&[][..], |p| p.branch.as_slice());
+                    highlighted_line(&branch, &offsets, branch_positions)
+                }),
+                Cell::from(updated_span(pr, &app.config.staleness, now)),
+                Cell::from(ci_text).style(Style::default().fg(ci_color)),
+            ]);
+            let row = Row::new(cells).height(height);
+            // Done PRs fade into the background
+            if app.is_done(pr) {
+                row.style(
+                    Style::default()
+                        .fg(Color::DarkGray)
+                        .add_modifier(Modifier::DIM),
+                )
+            } else {
+                row
             }
         })
         .collect();
 
-    let table = if show_owner {
-        let widths = [
-            Constraint::Length(8),
-            Constraint::Length(15),
-            Constraint::Min(25),
-            Constraint::Length(24),
-            Constraint::Length(12),
-        ];
-        Table::new(rows, widths)
+    let mut widths = vec![Constraint::Length(8)];
+    if show_source {
+        widths.push(Constraint::Length(8));
+    }
+    if show_owner {
+        widths.push(Constraint::Length(author_width));
+    }
+    widths.push(Constraint::Min(MIN_TITLE_WIDTH));
+    if show_milestone {
+        widths.push(Constraint::Length(milestone_width));
+    }
+    widths.extend([
+        Constraint::Length(branch_width),
+        Constraint::Length(UPDATED_WIDTH),
+        Constraint::Length(12),
+    ]);
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(app.theme.row_highlight)
+        .highlight_symbol(icons::selector());
+
+    let mut table_state = app.table_state.clone();
+    f.render_stateful_widget(table, area, &mut table_state);
+
+    // Rows sit below the header and its margin
+    let rows_area = Rect {
+        y: area.y + 2,
+        height: area.height.saturating_sub(2),
+        ..area
+    };
+    // Rows that fit from the offset down; the expanded selected row takes more than one line
+    let offset = table_state.offset();
+    let visible_rows = row_heights
+        .iter()
+        .skip(offset)
+        .scan(0u16, |used, &height| {
+            *used = used.saturating_add(height);
+            (*used <= rows_area.height).then_some(())
+        })
+        .count();
+    render_scrollbar(f, rows_area, row_heights.len(), offset, visible_rows.max(1));
+
+    let empty_message = if !app.filtered_indices.is_empty() {
+        None
+    } else if app.ready_to_merge_only {
+        Some("Nothing ready to merge (m to show all)")
+    } else if app.milestone_filter.is_some() {
+        Some("No PRs in this milestone (i to change)")
+    } else if app.hidden_approved > 0 {
+        Some("You've approved everything here (A to show)")
+    } else if app.hidden_done > 0 {
+        Some("Everything here is done (D to show)")
+    } else if matches!(app.pr_filter, PrFilter::Labels(_)) && app.search_query.is_empty() {
+        // Before the first fetch an empty list means "not loaded", not "no PRs"
+        if app.configured_labels.is_empty() {
+            Some("No labels configured. Press l, then a to add one.")
+        } else if app.labels_fetched {
+            Some("No open PRs with these labels")
+        } else if app.loading_labels_prs {
+            Some("Loading labeled PRs...")
+        } else {
+            None
+        }
     } else {
-        let widths = [
-            Constraint::Length(8),
-            Constraint::Min(30),
-            Constraint::Length(27),
-            Constraint::Length(12),
-        ];
-        Table::new(rows, widths)
-    }
-    .header(header)
-    .row_highlight_style(
-        Style::default()
-            .bg(Color::DarkGray)
-            .add_modifier(Modifier::BOLD),
-    )
-    .highlight_symbol(icons::SELECTOR);
-
-    f.render_stateful_widget(table, area, &mut app.table_state.clone());
+        None
+    };
+    if let Some(message) = empty_message {
+        f.render_widget(
+            Paragraph::new(message)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            rows_area,
+        );
+    }
+}
+
+/// Time since the PR's last activity: green while recent, red once stale
+fn updated_span(pr: &PullRequest, staleness: &StalenessConfig, now: i64) -> Span<'static> {
+    let Some(updated_at) = pr.updated_at else {
+        return Span::styled(icons::placeholder(), Style::default().fg(Color::DarkGray));
+    };
+    let age = now - updated_at;
+    let color = if age <= staleness.recent_hours as i64 * 3_600 {
+        Color::Green
+    } else if age > staleness.stale_days as i64 * 86_400 {
+        Color::Red
+    } else {
+        Color::Reset
+    };
+    Span::styled(format_age(age), Style::default().fg(color))
+}
+
+/// Which char of `source` each char of its truncated `display` shows: all of
+/// them, or a start and an end around the ellipsis
+fn truncated_offsets(source: &str, display: &str) -> Vec<Option<usize>> {
+    let source: Vec<char> = source.chars().collect();
+    let display: Vec<char> = display.chars().collect();
+    if source == display {
+        return (0..display.len()).map(Some).collect();
+    }
+    let ellipsis: Vec<char> = icons::ellipsis().chars().collect();
+    // The kept start can't run into the ellipsis, even when the source has one too
+    let mut head = source
+        .iter()
+        .zip(&display)
+        .take_while(|(s, d)| s == d)
+        .count();
+    while !display[head..].starts_with(&ellipsis) {
+        if head == 0 {
+            return vec![None; display.len()];
+        }
+        head -= 1;
+    }
+    let tail = display.len() - head - ellipsis.len();
+    (0..head)
+        .map(Some)
+        .chain(std::iter::repeat_n(None, ellipsis.len()))
+        .chain((source.len() - tail..source.len()).map(Some))
+        .collect()
+}
+
+/// Which char of `source` each char of its wrapped `lines` shows. Wrapping
+/// keeps every other char in order and only drops or collapses whitespace.
+fn wrapped_offsets(source: &str, lines: &[String]) -> Vec<Vec<Option<usize>>> {
+    let source: Vec<char> = source.chars().collect();
+    let mut next = 0;
+    lines
+        .iter()
+        .map(|line| {
+            line.chars()
+                .map(|c| {
+                    if c.is_whitespace() {
+                        return None;
+                    }
+                    while next < source.len() && source[next] != c {
+                        next += 1;
+                    }
+                    let offset = (next < source.len()).then_some(next);
+                    next += 1;
+                    offset
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// `display` with the chars showing a searched-for char of the source (at
+/// sorted `positions`) bold and underlined
+fn highlighted_line(
+    display: &str,
+    offsets: &[Option<usize>],
+    positions: &[usize],
+) -> Line<'static> {
+    if positions.is_empty() {
+        return Line::from(display.to_string());
+    }
+    let matched = Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+    let mut spans: Vec<Span> = Vec::new();
+    let mut run = String::new();
+    let mut run_matched = false;
+    for (c, offset) in display.chars().zip(offsets) {
+        let is_match = offset.is_some_and(|o| positions.binary_search(&o).is_ok());
+        if is_match != run_matched && !run.is_empty() {
+            let text = std::mem::take(&mut run);
+            spans.push(if run_matched {
+                Span::styled(text, matched)
+            } else {
+                Span::raw(text)
+            });
+        }
+        run_matched = is_match;
+        run.push(c);
+    }
+    if !run.is_empty() {
+        spans.push(if run_matched {
+            Span::styled(run, matched)
+        } else {
+            Span::raw(run)
+        });
+    }
+    Line::from(spans)
+}
+
+/// A PR's author, or a dim placeholder when it's missing
+fn author_span(pr: &PullRequest) -> Span<'static> {
+    let color = if pr.author_login().is_some() {
+        Color::Magenta
+    } else {
+        Color::DarkGray
+    };
+    Span::styled(pr.author_display().to_string(), Style::default().fg(color))
+}
+
+fn truncate_title(title: &str, max_len: usize, style: TruncationStyle) -> String {
+    match style {
+        TruncationStyle::End => truncate_string(title, max_len),
+        TruncationStyle::Middle => truncate_middle(title, max_len),
+        TruncationStyle::Smart if has_title_prefix(title) => truncate_middle(title, max_len),
+        TruncationStyle::Smart => truncate_string(title, max_len),
+    }
+}
+
+/// Whether a title starts with a tag many PRs share, like "[WIP]" or "feat(api):",
+/// so the end is what tells them apart
+fn has_title_prefix(title: &str) -> bool {
+    if title.starts_with('[') {
+        return title.contains(']');
+    }
+    // Conventional commit type: a lowercase word, optional (scope) and "!", then ":"
+    let Some((prefix, _)) = title.split_once(':') else {
+        return false;
+    };
+    let kind = prefix
+        .split('(')
+        .next()
+        .unwrap_or(prefix)
+        .trim_end_matches('!');
+    !kind.is_empty() && kind.chars().all(|c| c.is_ascii_lowercase())
 }
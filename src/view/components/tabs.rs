@@ -6,7 +6,11 @@ use ratatui::{
 };
 
 use crate::app::App;
-use crate::data::PrFilter;
+use crate::data::{CiSummary, PrFilter};
+use crate::icons;
+
+/// Cells in the CI health bar
+const HISTOGRAM_WIDTH: usize = 8;
 
 /// Render the tab bar
 pub fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
@@ -28,6 +32,49 @@ pub fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
         Style::default().fg(Color::DarkGray)
     };
 
+    let tab4_style = if app.pr_filter == PrFilter::All {
+        Style::default().fg(Color::Cyan).bold()
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let recent_style = if app.pr_filter == PrFilter::Recent {
+        Style::default().fg(Color::Cyan).bold()
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    // Team tab, shown once teams are configured; 5 cycles through them
+    let teams = app.config.valid_teams();
+    let team_tab = match &app.pr_filter {
+        PrFilter::Team(slug) => Some((
+            format!("[5] @{} ({}) ", slug, app.team_prs.len()),
+            Style::default().fg(Color::Cyan).bold(),
+        )),
+        _ => teams.first().map(|slug| {
+            let label = if teams.len() > 1 {
+                format!("[5] @{} (+{}) ", slug, teams.len() - 1)
+            } else {
+                format!("[5] @{} ", slug)
+            };
+            (label, Style::default().fg(Color::DarkGray))
+        }),
+    };
+
+    // Server-side search results get a temporary tab while they're being viewed
+    let search_tab = match &app.pr_filter {
+        PrFilter::Search(qualifiers) => {
+            // Show the saved search's name when this query came from one
+            let name = app
+                .saved_searches
+                .iter()
+                .find(|s| &s.query == qualifiers)
+                .map_or(qualifiers.as_str(), |s| s.name.as_str());
+            Some(format!("[:] {} ({}) ", name, app.search_prs.len()))
+        }
+        _ => None,
+    };
+
     let loading_indicator = if app.is_loading() {
         format!("{} ", app.spinner())
     } else {
@@ -38,28 +85,60 @@ pub fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
     let my_count = app.my_prs.len();
     let review_count = app.review_prs.len();
     let labels_count = app.labels_prs.len();
+    let unique_count = app.unique_pr_count();
 
     let tab1_label = format!(" [1] My PRs ({}) ", my_count);
     let tab2_label = format!("[2] Review Requested ({}) ", review_count);
-    let tab3_label = format!("[3] Labels ({}) ", labels_count);
+    let tab3_label = match app.active_focused_label() {
+        Some(label) => format!("[3] Labels: {} ({}) ", label, labels_count),
+        None => format!("[3] Labels ({}) ", labels_count),
+    };
+    let tab4_label = format!("[4] All ({}) ", unique_count);
+    let recent_label = format!("[6] Closed ({}) ", app.recent_prs.len());
 
     // Left side: tabs
-    let left = Line::from(vec![
+    let mut left = Line::from(vec![
         Span::styled(tab1_label, tab1_style),
         Span::raw(" "),
         Span::styled(tab2_label, tab2_style),
         Span::raw(" "),
         Span::styled(tab3_label, tab3_style),
+        Span::raw(" "),
+        Span::styled(tab4_label, tab4_style),
     ]);
+    if let Some((label, style)) = team_tab {
+        left.push_span(Span::raw(" "));
+        left.push_span(Span::styled(label, style));
+    }
+    left.push_span(Span::raw(" "));
+    left.push_span(Span::styled(recent_label, recent_style));
+    if let Some(label) = search_tab {
+        left.push_span(Span::raw(" "));
+        left.push_span(Span::styled(label, Style::default().fg(Color::Cyan).bold()));
+    }
 
-    // Right side: loading + repo info
-    let right = Line::from(vec![
-        Span::styled(loading_indicator, Style::default().fg(Color::Yellow)),
-        Span::styled(
-            format!("{} ", repo_display),
-            Style::default().fg(Color::DarkGray),
-        ),
-    ]);
+    // Right side: loading + CI health + repo info
+    let mut right = Line::from(Span::styled(
+        loading_indicator,
+        Style::default().fg(Color::Yellow),
+    ));
+    let histogram = ci_histogram(&app.ci_summary);
+    // Only when it fits alongside the tabs
+    if !histogram.is_empty()
+        && left.width() + right.width() + repo_display.len() + HISTOGRAM_WIDTH + 3
+            <= area.width as usize
+    {
+        right.spans.extend(histogram);
+        right.push_span(Span::raw(" "));
+    }
+    right.push_span(Span::styled(
+        format!("{} unique {} ", unique_count, icons::middle_dot()),
+        Style::default().fg(Color::DarkGray),
+    ));
+    right.push_span(Span::styled(
+        format!("{} ", repo_display),
+        Style::default().fg(Color::DarkGray),
+    ));
 
     let chunks = Layout::horizontal([Constraint::Min(0), Constraint::Length(right.width() as u16)])
         .split(area);
@@ -67,3 +146,51 @@ pub fn render_tabs(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(left, chunks[0]);
     f.render_widget(right, chunks[1]);
 }
+
+/// Render which of the tabs fetched at startup have loaded, e.g.
+/// "Loading  My PRs ✓  Review Requested ⠋"
+pub fn render_startup_progress(f: &mut Frame, app: &App, area: Rect) {
+    let mut line = Line::from(Span::styled(
+        " Loading ",
+        Style::default().fg(Color::DarkGray),
+    ));
+    for tab in app.config.prefetch_tabs.iter().copied() {
+        let (mark, color) = if app.is_prefetch_loading(tab) {
+            (app.spinner(), Color::Yellow)
+        } else {
+            (icons::ci_success(), Color::Green)
+        };
+        line.push_span(Span::raw(format!(" {} ", tab.label())));
+        line.push_span(Span::styled(mark, Style::default().fg(color)));
+        line.push_span(Span::raw(" "));
+    }
+    f.render_widget(line, area);
+}
+
+/// A fixed-width bar split green/yellow/red in proportion to the CI outcomes
+fn ci_histogram(summary: &CiSummary) -> Vec<Span<'static>> {
+    let total = summary.total();
+    if total == 0 {
+        return Vec::new();
+    }
+    let mut spans = Vec::new();
+    let mut counted = 0;
+    let mut drawn = 0;
+    for (count, color) in [
+        (summary.success, Color::Green),
+        (summary.pending, Color::Yellow),
+        (summary.failure, Color::Red),
+    ] {
+        // Round cumulative boundaries so the cells always add up to the full width
+        counted += count;
+        let end = (counted * HISTOGRAM_WIDTH + total / 2) / total;
+        if end > drawn {
+            spans.push(Span::styled(
+                icons::histogram_cell().repeat(end - drawn),
+                Style::default().fg(color),
+            ));
+            drawn = end;
+        }
+    }
+    spans
+}
@@ -0,0 +1,130 @@
+use ratatui::style::{Color, Modifier, Style};
+
+use crate::icons;
+use crate::services::{HighlightConfig, HighlightPreset, SpinnerConfig, SpinnerStyle, ThemeConfig};
+
+/// Resolved styles shared by every renderer
+#[derive(Debug, Clone)]
+pub struct Theme {
+    /// Selected row in the PR table
+    pub row_highlight: Style,
+    /// Selected item in lists (labels, saved searches, jobs, annotations)
+    pub selection: Style,
+    /// Loading spinner frames, resolved once from the config and terminal
+    pub spinner_frames: &'static [&'static str],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self {
+            row_highlight: Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD),
+            selection: Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD),
+            spinner_frames: icons::SPINNER_FRAMES,
+        }
+    }
+}
+
+impl Theme {
+    /// Build the theme from config; a configured highlight applies to every selection
+    pub fn from_config(config: &ThemeConfig, spinner: &SpinnerConfig) -> Self {
+        let mut theme = Self::default();
+        if let Some(style) = highlight_style(&config.highlight) {
+            theme.row_highlight = style;
+            theme.selection = style;
+        }
+        theme.spinner_frames = spinner_frames(spinner);
+        theme
+    }
+
+    /// Return a warning for each color or modifier that can't be parsed
+    pub fn validate(config: &ThemeConfig) -> Vec<String> {
+        let highlight = &config.highlight;
+        let colors = [("bg", &highlight.bg), ("fg", &highlight.fg)]
+            .into_iter()
+            .filter_map(|(key, value)| {
+                let value = value.as_ref()?;
+                value
+                    .parse::<Color>()
+                    .is_err()
+                    .then(|| format!("Unknown highlight {} color '{}'", key, value))
+            });
+        let modifiers = highlight
+            .modifiers
+            .iter()
+            .flatten()
+            .filter(|m| parse_modifier(m).is_none())
+            .map(|m| format!("Unknown highlight modifier '{}'", m));
+        colors.chain(modifiers).collect()
+    }
+}
+
+/// The configured highlight style, or None to keep the built-in highlights
+fn highlight_style(config: &HighlightConfig) -> Option<Style> {
+    if config.preset.is_none()
+        && config.bg.is_none()
+        && config.fg.is_none()
+        && config.modifiers.is_none()
+    {
+        return None;
+    }
+
+    let mut style = match config.preset {
+        Some(HighlightPreset::Reverse) => Style::default().add_modifier(Modifier::REVERSED),
+        Some(HighlightPreset::Bar) => Style::default()
+            .bg(Color::Blue)
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+        None => Style::default()
+            .bg(Color::DarkGray)
+            .add_modifier(Modifier::BOLD),
+    };
+    if let Some(bg) = config.bg.as_deref().and_then(|c| c.parse::<Color>().ok()) {
+        style = style.bg(bg);
+    }
+    if let Some(fg) = config.fg.as_deref().and_then(|c| c.parse::<Color>().ok()) {
+        style = style.fg(fg);
+    }
+    // Explicit modifiers replace the preset's rather than adding to them
+    if let Some(ref names) = config.modifiers {
+        style = style.remove_modifier(Modifier::all());
+        for modifier in names.iter().filter_map(|m| parse_modifier(m)) {
+            style = style.add_modifier(modifier);
+        }
+    }
+    Some(style)
+}
+
+/// Frames for the configured style, falling back to ASCII in ASCII mode and
+/// on terminals (the Linux console, dumb terminals) that mangle braille glyphs
+pub fn spinner_frames(config: &SpinnerConfig) -> &'static [&'static str] {
+    let style = config.style.unwrap_or_else(|| {
+        if icons::is_ascii() {
+            return SpinnerStyle::Ascii;
+        }
+        match std::env::var("TERM").as_deref() {
+            Ok("linux") | Ok("dumb") => SpinnerStyle::Ascii,
+            _ => SpinnerStyle::Braille,
+        }
+    });
+    match style {
+        SpinnerStyle::Braille => icons::SPINNER_FRAMES,
+        SpinnerStyle::Dots => icons::SPINNER_DOTS,
+        SpinnerStyle::Line => icons::SPINNER_LINE,
+        SpinnerStyle::Ascii => icons::SPINNER_ASCII,
+    }
+}
+
+fn parse_modifier(name: &str) -> Option<Modifier> {
+    match name.to_lowercase().as_str() {
+        "bold" => Some(Modifier::BOLD),
+        "dim" => Some(Modifier::DIM),
+        "italic" => Some(Modifier::ITALIC),
+        "underlined" | "underline" => Some(Modifier::UNDERLINED),
+        "reversed" | "reverse" => Some(Modifier::REVERSED),
+        _ => None,
+    }
+}
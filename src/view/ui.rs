@@ -1,17 +1,21 @@
 use ratatui::{
-    layout::{Constraint, Layout},
+    layout::{Alignment, Constraint, Layout, Rect},
     style::{Color, Style},
-    widgets::{Clear, Paragraph},
+    widgets::{Clear, Paragraph, Wrap},
     Frame,
 };
 
 use crate::app::App;
 use crate::icons;
+use crate::services::MinSizeConfig;
 
 use super::components::{
-    render_add_label_popup, render_checkout_popup, render_error_popup, render_help_popup,
-    render_job_logs_view, render_labels_popup, render_legend, render_preview_view,
-    render_search_bar, render_table, render_tabs, render_toast, render_workflows_view,
+    render_add_label_popup, render_add_saved_search_popup, render_checks_popup,
+    render_confirm_popup, render_error_popup, render_glyph_warning_popup, render_help_popup,
+    render_job_logs_view, render_labels_popup, render_legend, render_merge_popup,
+    render_preview_view, render_recent_checkouts_popup, render_rerun_popup,
+    render_saved_searches_popup, render_search_bar, render_startup_progress, render_table,
+    render_tabs, render_toast, render_workflows_view,
 };
 
 /// Main UI rendering function
@@ -19,6 +23,12 @@ pub fn ui(f: &mut Frame, app: &App) {
     // Always clear the entire screen first to prevent leftover characters
     f.render_widget(Clear, f.area());
 
+    let min_size = &app.config.min_size;
+    if f.area().width < min_size.width || f.area().height < min_size.height {
+        render_too_small(f, min_size);
+        return;
+    }
+
     // If in workflows view, render it as a full page
     if app.show_workflows_view {
         if app.show_job_logs {
@@ -27,6 +37,13 @@ pub fn ui(f: &mut Frame, app: &App) {
             render_workflows_view(f, app);
         }
 
+        if let Some((ref run_name, _)) = app.pending_rerun {
+            render_rerun_popup(f, run_name);
+        }
+        if let Some(ref confirmation) = app.confirmation {
+            render_confirm_popup(f, confirmation);
+        }
+
         // Still render error popup over workflows view
         if app.show_error_popup {
             if let Some(ref error) = app.error {
@@ -51,8 +68,14 @@ pub fn ui(f: &mut Frame, app: &App) {
         return;
     }
 
-    // Calculate layout based on whether search is active
-    let chunks = if app.search_mode || !app.search_query.is_empty() {
+    // Calculate layout based on whether search or a quick filter is active
+    let show_search_bar = app.search_mode
+        || !app.search_query.is_empty()
+        || app.ready_to_merge_only
+        || app.milestone_filter.is_some()
+        || app.hide_approved
+        || app.hide_done;
+    let chunks = if show_search_bar {
         Layout::vertical([
             Constraint::Length(1), // Tabs
             Constraint::Length(1), // Separator
@@ -73,31 +96,56 @@ pub fn ui(f: &mut Frame, app: &App) {
 
     render_tabs(f, app, chunks[0]);
 
-    // Separator line
-    let separator = icons::SEPARATOR_CHAR.repeat(chunks[1].width as usize);
-    f.render_widget(
-        Paragraph::new(separator).style(Style::default().fg(Color::DarkGray)),
-        chunks[1],
-    );
+    // Separator line, replaced by a banner while GitHub is unreachable
+    if app.offline {
+        f.render_widget(
+            Paragraph::new(format!(
+                " offline {} showing cached data (r to retry)",
+                icons::dash()
+            ))
+            .style(Style::default().fg(Color::Black).bg(Color::Yellow)),
+            chunks[1],
+        );
+    } else if app.startup_progress {
+        render_startup_progress(f, app, chunks[1]);
+    } else {
+        let separator = icons::separator_char().repeat(chunks[1].width as usize);
+        f.render_widget(
+            Paragraph::new(separator).style(Style::default().fg(Color::DarkGray)),
+            chunks[1],
+        );
+    }
 
     render_table(f, app, chunks[2]);
 
-    // Render search bar if in search mode or has query
-    if app.search_mode || !app.search_query.is_empty() {
+    // Render search bar if in search mode, has query, or is quick-filtered
+    if show_search_bar {
         render_search_bar(f, app, chunks[3]);
-        render_legend(f, chunks[4]);
+        render_legend(f, app, chunks[4]);
     } else {
-        render_legend(f, chunks[3]);
+        render_legend(f, app, chunks[3]);
     }
 
     // Render popups (order matters for layering)
     if app.show_help_popup {
-        render_help_popup(f);
+        render_help_popup(f, app);
     }
 
-    if app.show_checkout_popup {
-        if let Some(ref branch) = app.pending_checkout_branch {
-            render_checkout_popup(f, branch);
+    if let Some(ref confirmation) = app.confirmation {
+        render_confirm_popup(f, confirmation);
+    }
+
+    if app.show_merge_popup {
+        if let Some(pr) = app
+            .pending_merge_pr
+            .as_ref()
+            .and_then(|(owner, repo, number)| {
+                app.current_prs().iter().find(|pr| {
+                    pr.number == *number && pr.repo_owner == *owner && pr.repo_name == *repo
+                })
+            })
+        {
+            render_merge_popup(f, pr, app.merge_method);
         }
     }
 
@@ -115,6 +163,48 @@ pub fn ui(f: &mut Frame, app: &App) {
         render_add_label_popup(f, app);
     }
 
+    if app.show_saved_searches_popup {
+        render_saved_searches_popup(f, app);
+    }
+
+    if app.show_add_saved_search_popup {
+        render_add_saved_search_popup(f, app);
+    }
+
+    if app.show_recent_checkouts_popup {
+        render_recent_checkouts_popup(f, app);
+    }
+
+    if app.show_checks_popup {
+        render_checks_popup(f, app);
+    }
+
+    if app.show_glyph_warning_popup {
+        render_glyph_warning_popup(f);
+    }
+
     // Render toast notification on top of everything
     render_toast(f, app);
 }
+
+/// Shown instead of the UI while the terminal is below `min_size`
+fn render_too_small(f: &mut Frame, min_size: &MinSizeConfig) {
+    let area = f.area();
+    let message = format!(
+        "Terminal too small (need at least {}x{})",
+        min_size.width, min_size.height
+    );
+    // Vertically centered; the text wraps on very narrow terminals
+    let top = Rect {
+        y: area.y + area.height.saturating_sub(1) / 2,
+        height: area.height - area.height.saturating_sub(1) / 2,
+        ..area
+    };
+    f.render_widget(
+        Paragraph::new(message)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        top,
+    );
+}
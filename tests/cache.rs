@@ -0,0 +1,402 @@
+mod common;
+
+use common::setup;
+use ghui::data::{CiStatus, Mergeable, RecentCheckout, ReviewDecision};
+use ghui::services::cache::{
+    cache_saved_at, clear_done_prs, delete_label_filter, get_cache_path, load_cache,
+    load_cache_for_display, load_done_prs, load_global_label_filters, load_label_filters,
+    load_recent_checkouts, load_saved_searches, save_cache, save_done_pr, save_label_filter,
+    save_recent_checkout, save_saved_search, MAX_CACHED_SEARCHES, MAX_RECENT_CHECKOUTS,
+};
+use ghui::{PrFilter, PullRequest};
+
+/// A PR with every cached field filled in
+fn pr(number: u64, owner: &str, repo: &str, ci_status: CiStatus) -> PullRequest {
+    PullRequest {
+        title: format!("PR {} with 'quotes' and ünïcode", number),
+        repo_owner: owner.to_string(),
+        repo_name: repo.to_string(),
+        ci_status,
+        head_sha: Some("abc123".to_string()),
+        review_decision: ReviewDecision::Approved,
+        mergeable: Mergeable::Mergeable,
+        milestone: number.is_multiple_of(2).then(|| "v1.0".to_string()),
+        updated_at: Some(1_700_000_000 + number as i64),
+        created_at: Some(1_600_000_000 + number as i64),
+        is_draft: number.is_multiple_of(3),
+        ..common::pr(number)
+    }
+}
+
+fn sorted(mut prs: Vec<PullRequest>) -> Vec<PullRequest> {
+    prs.sort_by_key(|pr| pr.number);
+    prs
+}
+
+#[test]
+fn prs_round_trip_field_for_field() {
+    let _guard = setup();
+    let prs = vec![
+        pr(1, "round", "trip", CiStatus::Success),
+        pr(2, "round", "trip", CiStatus::Failure),
+        pr(3, "round", "trip", CiStatus::Pending),
+        pr(4, "round", "trip", CiStatus::Unknown),
+    ];
+
+    save_cache(&prs, "round", "trip", PrFilter::MyPrs).unwrap();
+    let loaded = sorted(load_cache("round", "trip", PrFilter::MyPrs).unwrap());
+
+    assert_eq!(loaded.len(), prs.len());
+    for (saved, loaded) in prs.iter().zip(&loaded) {
+        assert_eq!(loaded.number, saved.number);
+        assert_eq!(loaded.title, saved.title);
+        assert_eq!(loaded.branch, saved.branch);
+        assert_eq!(loaded.repo_owner, saved.repo_owner);
+        assert_eq!(loaded.repo_name, saved.repo_name);
+        assert_eq!(loaded.ci_status, saved.ci_status);
+        assert_eq!(loaded.author, saved.author);
+        assert_eq!(loaded.milestone, saved.milestone);
+        assert_eq!(loaded.updated_at, saved.updated_at);
+        assert_eq!(loaded.created_at, saved.created_at);
+        assert_eq!(loaded.is_draft, saved.is_draft);
+        // The head SHA and merge state aren't cached; they're filled in by the next fetch
+        assert_eq!(loaded.head_sha, None);
+        assert_eq!(loaded.review_decision, ReviewDecision::None);
+        assert_eq!(loaded.mergeable, Mergeable::Unknown);
+    }
+}
+
+#[test]
+fn ci_status_round_trips_through_str() {
+    for status in [
+        CiStatus::Unknown,
+        CiStatus::Pending,
+        CiStatus::Success,
+        CiStatus::Failure,
+    ] {
+        assert_eq!(status.to_str().parse::<CiStatus>().unwrap(), status);
+    }
+}
+
+#[test]
+fn filters_are_cached_independently() {
+    let _guard = setup();
+    // The same PR can be in several lists; the filter is part of the primary key
+    let shared = pr(10, "multi", "filter", CiStatus::Success);
+    save_cache(
+        std::slice::from_ref(&shared),
+        "multi",
+        "filter",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+    save_cache(
+        &[shared.clone(), pr(11, "multi", "filter", CiStatus::Pending)],
+        "multi",
+        "filter",
+        PrFilter::ReviewRequested,
+    )
+    .unwrap();
+    let search = PrFilter::Search("label:bug".to_string());
+    save_cache(
+        &[pr(12, "multi", "filter", CiStatus::Failure)],
+        "multi",
+        "filter",
+        search.clone(),
+    )
+    .unwrap();
+
+    let numbers = |filter| -> Vec<u64> {
+        sorted(load_cache("multi", "filter", filter).unwrap())
+            .iter()
+            .map(|pr| pr.number)
+            .collect()
+    };
+    assert_eq!(numbers(PrFilter::MyPrs), vec![10]);
+    assert_eq!(numbers(PrFilter::ReviewRequested), vec![10, 11]);
+    assert_eq!(numbers(search), vec![12]);
+    assert!(numbers(PrFilter::Search("label:other".to_string())).is_empty());
+}
+
+#[test]
+fn only_the_newest_searches_stay_cached() {
+    let _guard = setup();
+    let search = |n: usize| PrFilter::Search(format!("label:bug-{}", n));
+    let save = |n: usize| {
+        save_cache(
+            &[pr(n as u64, "prune", "search", CiStatus::Success)],
+            "prune",
+            "search",
+            search(n),
+        )
+        .unwrap()
+    };
+    for n in 0..MAX_CACHED_SEARCHES + 2 {
+        save(n);
+        // Running the first query again keeps it fresh
+        if n == MAX_CACHED_SEARCHES / 2 {
+            save(0);
+        }
+    }
+    // Other filters and repos are left alone
+    save_cache(
+        &[pr(99, "prune", "search", CiStatus::Success)],
+        "prune",
+        "search",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+
+    let cached = |filter| !load_cache("prune", "search", filter).unwrap().is_empty();
+    assert!(cached(search(0)));
+    assert!(!cached(search(1)) && !cached(search(2)));
+    assert!((3..MAX_CACHED_SEARCHES + 2).all(|n| cached(search(n))));
+    assert!(cached(PrFilter::MyPrs));
+}
+
+#[test]
+fn saving_replaces_the_previous_list() {
+    let _guard = setup();
+    save_cache(
+        &[
+            pr(20, "replace", "list", CiStatus::Pending),
+            pr(21, "replace", "list", CiStatus::Pending),
+        ],
+        "replace",
+        "list",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+    save_cache(
+        &[pr(21, "replace", "list", CiStatus::Success)],
+        "replace",
+        "list",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+
+    let loaded = load_cache("replace", "list", PrFilter::MyPrs).unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].number, 21);
+    assert_eq!(loaded[0].ci_status, CiStatus::Success);
+}
+
+#[test]
+fn prs_cached_without_details_only_count() {
+    let _guard = setup();
+    // As saved with cache_pr_details = false
+    let bare = PullRequest {
+        title: String::new(),
+        branch: String::new(),
+        author: String::new(),
+        ..pr(30, "bare", "rows", CiStatus::Failure)
+    };
+    save_cache(&[bare], "bare", "rows", PrFilter::MyPrs).unwrap();
+
+    assert_eq!(
+        load_cache("bare", "rows", PrFilter::MyPrs).unwrap().len(),
+        1
+    );
+    assert!(load_cache_for_display("bare", "rows", PrFilter::MyPrs)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn global_label_filters_load_alongside_repo_labels() {
+    let _guard = setup();
+    save_label_filter("needs-review", Some("labels"), Some("repo")).unwrap();
+    save_label_filter("urgent", None, None).unwrap();
+    save_label_filter("elsewhere", Some("labels"), Some("other")).unwrap();
+
+    let labels = load_label_filters("labels", "repo").unwrap();
+    let names: Vec<&str> = labels.iter().map(|l| l.label_name.as_str()).collect();
+    // Repo-specific labels sort before global ones; other repos' labels are excluded
+    assert_eq!(names, vec!["needs-review", "urgent"]);
+    assert!(!labels[0].is_global());
+    assert_eq!(labels[0].repo_owner.as_deref(), Some("labels"));
+    assert_eq!(labels[0].repo_name.as_deref(), Some("repo"));
+    assert!(labels[1].is_global());
+
+    // Global labels show up in every repo
+    let other: Vec<String> = load_label_filters("labels", "other")
+        .unwrap()
+        .into_iter()
+        .map(|l| l.label_name)
+        .collect();
+    assert_eq!(other, vec!["elsewhere", "urgent"]);
+
+    // Outside a repo, only global labels load
+    let global: Vec<String> = load_global_label_filters()
+        .unwrap()
+        .into_iter()
+        .map(|l| l.label_name)
+        .collect();
+    assert_eq!(global, vec!["urgent"]);
+
+    delete_label_filter(labels[1].id).unwrap();
+    let names: Vec<String> = load_label_filters("labels", "repo")
+        .unwrap()
+        .into_iter()
+        .map(|l| l.label_name)
+        .collect();
+    assert_eq!(names, vec!["needs-review"]);
+}
+
+#[test]
+fn recent_checkouts_keep_the_newest_branches() {
+    let _guard = setup();
+    let checkout = |n: u64, at: i64| RecentCheckout {
+        branch: format!("feature/{}", n),
+        pr_number: n,
+        repo_owner: "recent".to_string(),
+        repo_name: "repo".to_string(),
+        checked_out_at: at,
+    };
+    for n in 0..12 {
+        save_recent_checkout(&checkout(n, 1_000 + n as i64)).unwrap();
+    }
+    // Checking out an old branch again moves it to the top
+    save_recent_checkout(&checkout(3, 2_000)).unwrap();
+
+    let numbers: Vec<u64> = load_recent_checkouts("recent", "repo")
+        .unwrap()
+        .into_iter()
+        .map(|c| c.pr_number)
+        .collect();
+    assert_eq!(numbers.len(), MAX_RECENT_CHECKOUTS);
+    assert_eq!(numbers[..3], [3, 11, 10]);
+    // The two oldest were dropped when the list overflowed
+    assert!(!numbers.contains(&0) && !numbers.contains(&1));
+
+    assert!(load_recent_checkouts("recent", "other").unwrap().is_empty());
+}
+
+#[test]
+fn done_marks_are_kept_per_repo() {
+    let _guard = setup();
+    save_done_pr("done", "repo", 1, true).unwrap();
+    save_done_pr("done", "repo", 2, true).unwrap();
+    // Marking twice is harmless
+    save_done_pr("done", "repo", 2, true).unwrap();
+    save_done_pr("done", "other", 3, true).unwrap();
+    save_done_pr("done", "repo", 1, false).unwrap();
+
+    let done = load_done_prs("done", "repo").unwrap();
+    assert_eq!(done.into_iter().collect::<Vec<_>>(), [2]);
+
+    clear_done_prs("done", "repo").unwrap();
+    assert!(load_done_prs("done", "repo").unwrap().is_empty());
+    assert_eq!(load_done_prs("done", "other").unwrap().len(), 1);
+}
+
+#[test]
+fn an_empty_list_is_told_apart_from_a_missing_one() {
+    let _guard = setup();
+    assert_eq!(
+        cache_saved_at("empty", "repo", &PrFilter::MyPrs).unwrap(),
+        None
+    );
+
+    save_cache(&[], "empty", "repo", PrFilter::MyPrs).unwrap();
+    assert!(cache_saved_at("empty", "repo", &PrFilter::MyPrs)
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        cache_saved_at("empty", "repo", &PrFilter::ReviewRequested).unwrap(),
+        None
+    );
+}
+
+/// Make the next cache access see cache.db as written by an older version
+fn downgrade_cache_version() {
+    let conn = rusqlite::Connection::open(get_cache_path().unwrap()).unwrap();
+    conn.execute("UPDATE cache_meta SET value = 0 WHERE key = 'version'", [])
+        .unwrap();
+}
+
+#[test]
+fn user_data_survives_a_cache_version_bump() {
+    let _guard = setup();
+    save_cache(
+        &[pr(1, "bump", "repo", CiStatus::Success)],
+        "bump",
+        "repo",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+    save_label_filter("bug", Some("bump"), Some("repo")).unwrap();
+    save_saved_search("mine", "author:@me", "bump", "repo").unwrap();
+    save_recent_checkout(&RecentCheckout {
+        branch: "feature/bump".to_string(),
+        pr_number: 1,
+        repo_owner: "bump".to_string(),
+        repo_name: "repo".to_string(),
+        checked_out_at: 1_700_000_000,
+    })
+    .unwrap();
+    save_done_pr("bump", "repo", 1, true).unwrap();
+
+    downgrade_cache_version();
+
+    // Cached PRs are disposable and cleared
+    assert!(load_cache("bump", "repo", PrFilter::MyPrs)
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        cache_saved_at("bump", "repo", &PrFilter::MyPrs).unwrap(),
+        None
+    );
+    let labels = load_label_filters("bump", "repo").unwrap();
+    assert!(labels.iter().any(|label| label.label_name == "bug"));
+    let searches = load_saved_searches("bump", "repo").unwrap();
+    assert_eq!(searches.len(), 1);
+    assert_eq!(searches[0].query, "author:@me");
+    let checkouts = load_recent_checkouts("bump", "repo").unwrap();
+    assert_eq!(checkouts.len(), 1);
+    assert_eq!(checkouts[0].branch, "feature/bump");
+    assert!(load_done_prs("bump", "repo").unwrap().contains(&1));
+}
+
+#[test]
+fn no_cache_keeps_prs_off_disk() {
+    // GHUI_NO_CACHE is process-wide: setting it is only safe because every
+    // test here holds setup's lock while it touches the cache
+    let _guard = setup();
+    std::env::set_var("GHUI_NO_CACHE", "1");
+    save_cache(
+        &[pr(1, "private", "repo", CiStatus::Success)],
+        "private",
+        "repo",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+    assert!(load_cache("private", "repo", PrFilter::MyPrs)
+        .unwrap()
+        .is_empty());
+
+    // Nothing was written while it was set
+    std::env::set_var("GHUI_NO_CACHE", "0");
+    assert!(load_cache("private", "repo", PrFilter::MyPrs)
+        .unwrap()
+        .is_empty());
+    std::env::remove_var("GHUI_NO_CACHE");
+}
+
+#[cfg(unix)]
+#[test]
+fn the_cache_is_private_to_its_owner() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let _guard = setup();
+    save_cache(
+        &[pr(1, "perms", "repo", CiStatus::Success)],
+        "perms",
+        "repo",
+        PrFilter::MyPrs,
+    )
+    .unwrap();
+    let path = get_cache_path().unwrap();
+    let mode = std::fs::metadata(path).unwrap().permissions().mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
@@ -0,0 +1,64 @@
+use ghui::utils::CodeOwners;
+
+const CODEOWNERS: &str = "\
+# Default owners
+*           @org/core
+*.md        @docs-writer
+/src/api/   @alice @org/api   # API team
+docs/**     @docs-writer
+build.rs
+";
+
+fn me(owners: &[&str]) -> Vec<String> {
+    owners.iter().map(|o| o.to_string()).collect()
+}
+
+#[test]
+fn the_last_matching_rule_wins() {
+    let codeowners = CodeOwners::parse(CODEOWNERS);
+
+    assert_eq!(codeowners.owners_of("src/main.rs"), ["@org/core"]);
+    assert_eq!(
+        codeowners.owners_of("src/api/users.rs"),
+        ["@alice", "@org/api"]
+    );
+    assert_eq!(
+        codeowners.owners_of("src/api/v2/users.rs"),
+        ["@alice", "@org/api"]
+    );
+    // Unanchored patterns match at any depth
+    assert_eq!(codeowners.owners_of("src/README.md"), ["@docs-writer"]);
+    assert_eq!(
+        codeowners.owners_of("src/api/README.md"),
+        ["@alice", "@org/api"]
+    );
+    assert_eq!(
+        codeowners.owners_of("docs/guide/intro.txt"),
+        ["@docs-writer"]
+    );
+    // A rule without owners leaves the path unowned
+    assert!(codeowners.owners_of("build.rs").is_empty());
+    // Anchored patterns don't match deeper copies of the path
+    assert_eq!(codeowners.owners_of("vendor/src/api/x.rs"), ["@org/core"]);
+}
+
+#[test]
+fn trailing_wildcards_only_cover_direct_children() {
+    let children = CodeOwners::parse("docs/* @docs-writer");
+    assert_eq!(children.owners_of("docs/intro.md"), ["@docs-writer"]);
+    assert!(children.owners_of("docs/a/b.md").is_empty());
+
+    // A directory pattern still covers everything below it
+    let directory = CodeOwners::parse("docs/ @docs-writer");
+    assert_eq!(directory.owners_of("docs/a/b.md"), ["@docs-writer"]);
+}
+
+#[test]
+fn owns_any_matches_my_login_or_teams() {
+    let codeowners = CodeOwners::parse(CODEOWNERS);
+
+    assert!(codeowners.owns_any(["README.rs", "src/api/lib.rs"], &me(&["@Alice"])));
+    assert!(codeowners.owns_any(["src/api/lib.rs"], &me(&["@bob", "@org/api"])));
+    assert!(!codeowners.owns_any(["src/main.rs", "build.rs"], &me(&["@alice"])));
+    assert!(!CodeOwners::default().owns_any(["src/main.rs"], &me(&["@alice"])));
+}
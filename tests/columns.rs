@@ -0,0 +1,34 @@
+mod common;
+
+use common::setup_app;
+use ghui::data::TableColumn;
+use ghui::services::load_state;
+use ghui::{update, Message};
+
+#[test]
+fn resized_columns_are_remembered() {
+    let (guard, mut app) = setup_app();
+    assert_eq!(app.resize_column, TableColumn::Branch);
+    let branch = app.column_widths.branch;
+    update(&mut app, Message::GrowColumn);
+    assert_eq!(app.column_widths.branch, branch + 2);
+
+    update(&mut app, Message::CycleResizeColumn);
+    assert_eq!(app.resize_column, TableColumn::Author);
+    let author = app.column_widths.author;
+    update(&mut app, Message::ShrinkColumn);
+    assert_eq!(app.column_widths.author, author - 2);
+    // Only the resized column changed
+    assert_eq!(app.column_widths.branch, branch + 2);
+
+    let state = load_state();
+    assert_eq!(state.columns.branch, Some(branch + 2));
+    assert_eq!(state.columns.author, Some(author - 2));
+    assert_eq!(state.columns.milestone, None);
+
+    // A new session starts from the remembered widths
+    drop(guard);
+    let (_guard, app) = setup_app();
+    assert_eq!(app.column_widths.branch, branch + 2);
+    assert_eq!(app.column_widths.author, author - 2);
+}
@@ -0,0 +1,57 @@
+//! Helpers shared by the integration tests. Each test binary pulls in only
+//! what it needs, so some go unused in any one of them.
+#![allow(dead_code)]
+
+use std::sync::{Mutex, MutexGuard, Once};
+
+use ghui::data::{CiStatus, Mergeable, ReviewDecision, ReviewState};
+use ghui::{App, PullRequest};
+
+/// Point the config dir (and so the cache) at a fresh directory for this test
+/// binary. Tests share it, and the process environment, so each holds a lock.
+pub fn setup() -> MutexGuard<'static, ()> {
+    static INIT: Once = Once::new();
+    static LOCK: Mutex<()> = Mutex::new(());
+    INIT.call_once(|| {
+        let dir = std::env::temp_dir().join(format!("ghui-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::env::set_var("GHUI_CONFIG_DIR", &dir);
+    });
+    LOCK.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Build an app against the test config dir, holding the lock
+pub fn setup_app() -> (MutexGuard<'static, ()>, App) {
+    let guard = setup();
+    (guard, App::new().unwrap())
+}
+
+pub fn pr(number: u64) -> PullRequest {
+    PullRequest {
+        number,
+        title: format!("PR {}", number),
+        branch: format!("feature/{}", number),
+        repo_owner: "owner".to_string(),
+        repo_name: "repo".to_string(),
+        ci_status: CiStatus::Success,
+        author: "octocat".to_string(),
+        head_sha: None,
+        review_decision: ReviewDecision::None,
+        mergeable: Mergeable::Unknown,
+        milestone: None,
+        updated_at: None,
+        created_at: None,
+        my_review_state: ReviewState::None,
+        is_draft: false,
+        touches_my_code: false,
+        blocking_on_me: false,
+    }
+}
+
+/// Show three PRs with the given one selected
+pub fn with_prs(app: &mut App, selected: usize, wrap: bool) {
+    app.my_prs = vec![pr(1), pr(2), pr(3)];
+    app.filtered_indices = vec![0, 1, 2];
+    app.table_state.select(Some(selected));
+    app.config.wrap_navigation = wrap;
+}
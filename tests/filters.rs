@@ -0,0 +1,47 @@
+mod common;
+
+use common::{setup_app, with_prs};
+use ghui::data::ReviewState;
+use ghui::{update, Message};
+
+#[test]
+fn hiding_approved_prs_counts_what_was_hidden() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    app.my_prs[1].my_review_state = ReviewState::Approved;
+
+    update(&mut app, Message::ToggleHideApproved);
+    assert_eq!(app.filtered_indices, vec![0, 2]);
+    assert_eq!(app.hidden_approved, 1);
+
+    update(&mut app, Message::ToggleHideApproved);
+    assert_eq!(app.filtered_indices, vec![0, 1, 2]);
+    assert_eq!(app.hidden_approved, 0);
+}
+
+#[test]
+fn done_prs_can_be_hidden_and_cleared() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 1, false);
+    app.repo_owner = Some("owner".to_string());
+    app.repo_name = Some("repo".to_string());
+    app.done_prs.clear();
+
+    update(&mut app, Message::ToggleDone);
+    assert!(app.done_prs.contains(&2));
+
+    update(&mut app, Message::ToggleHideDone);
+    assert_eq!(app.filtered_indices, vec![0, 2]);
+    assert_eq!(app.hidden_done, 1);
+
+    // Marking another done while hidden keeps a row selected
+    update(&mut app, Message::GoToBottom);
+    update(&mut app, Message::ToggleDone);
+    assert_eq!(app.filtered_indices, vec![0]);
+    assert_eq!(app.table_state.selected(), Some(0));
+
+    update(&mut app, Message::PromptClearDone);
+    update(&mut app, Message::Confirm);
+    assert!(app.done_prs.is_empty());
+    assert_eq!(app.filtered_indices, vec![0, 1, 2]);
+}
@@ -0,0 +1,440 @@
+mod common;
+
+use std::sync::Mutex;
+
+use common::setup;
+use ghui::data::{CiStatus, SearchNode, WorkflowConclusion, WorkflowStatus};
+use ghui::error::GhuiError;
+use ghui::services::{
+    check_graphql_errors, explain_sso_error, get_config_dir, is_network_error, is_sso_required,
+    parse_actions_response, parse_check_contexts, parse_search_response, with_github_token,
+};
+use serde_json::json;
+
+#[test]
+fn graphql_errors_are_reported_with_their_messages() {
+    // GitHub answers with a 200 and null data
+    let response = json!({
+        "data": null,
+        "errors": [
+            {
+                "type": "FORBIDDEN",
+                "path": ["search", "nodes", 0, "files"],
+                "message": "Resource not accessible by integration"
+            },
+            { "message": "Field 'bogus' doesn't exist on type 'PullRequest'" }
+        ]
+    });
+
+    let err = parse_search_response(response).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "GitHub API: Resource not accessible by integration; \
+         Field 'bogus' doesn't exist on type 'PullRequest'"
+    );
+}
+
+#[test]
+fn responses_without_errors_pass() {
+    assert!(check_graphql_errors(&json!({ "data": {} })).is_ok());
+    assert!(check_graphql_errors(&json!({ "data": {}, "errors": [] })).is_ok());
+
+    let response = json!({
+        "data": {
+            "search": {
+                "nodes": [],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }
+        }
+    });
+    let search = parse_search_response(response).unwrap();
+    assert!(search.nodes.is_empty());
+}
+
+#[test]
+fn errors_next_to_results_are_warnings() {
+    let response = json!({
+        "data": {
+            "search": {
+                "nodes": [],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }
+        },
+        "errors": [{ "message": "Resource not accessible by integration" }]
+    });
+
+    let search = parse_search_response(response).unwrap();
+    assert_eq!(search.warnings, ["Resource not accessible by integration"]);
+}
+
+#[test]
+fn a_pr_the_token_cant_see_is_skipped_with_a_warning() {
+    // GitHub nulls the node it couldn't resolve and says why in `errors`
+    let response = json!({
+        "data": {
+            "search": {
+                "nodes": [
+                    {
+                        "__typename": "PullRequest",
+                        "number": 1,
+                        "title": "Visible",
+                        "headRefName": "visible",
+                        "author": { "login": "octocat" },
+                        "commits": { "nodes": [] }
+                    },
+                    null
+                ],
+                "pageInfo": { "hasNextPage": false, "endCursor": null }
+            }
+        },
+        "errors": [
+            {
+                "type": "FORBIDDEN",
+                "path": ["search", "nodes", 1],
+                "message": "Resource not accessible by integration"
+            }
+        ]
+    });
+
+    let search = parse_search_response(response).unwrap();
+    assert_eq!(search.nodes.len(), 1);
+    assert!(matches!(&search.nodes[0], SearchNode::PullRequest(pr) if pr.number == 1));
+    assert_eq!(search.warnings, ["Resource not accessible by integration"]);
+}
+
+#[test]
+fn odd_nodes_are_skipped_instead_of_failing_the_page() {
+    let response = json!({
+        "data": {
+            "search": {
+                "nodes": [
+                    {
+                        "__typename": "PullRequest",
+                        "number": 1,
+                        "title": "Normal",
+                        "headRefName": "normal",
+                        "author": { "login": "octocat" },
+                        "commits": { "nodes": [] }
+                    },
+                    null,
+                    {
+                        // A ghost author and no commit data
+                        "__typename": "PullRequest",
+                        "number": 2,
+                        "title": "Ghost",
+                        "headRefName": "ghost",
+                        "author": null,
+                        "commits": null,
+                        "milestone": null,
+                        "viewerLatestReview": null
+                    },
+                    { "__typename": "PullRequest", "title": "No number" },
+                    { "__typename": "Issue" }
+                ]
+                // No pageInfo
+            }
+        }
+    });
+
+    let search = parse_search_response(response).unwrap();
+    let numbers: Vec<u64> = search
+        .nodes
+        .iter()
+        .filter_map(|node| match node {
+            SearchNode::PullRequest(pr) => Some(pr.number),
+            SearchNode::Other => None,
+        })
+        .collect();
+    assert_eq!(numbers, [1, 2]);
+    assert!(!search.page_info.has_next_page);
+}
+
+#[test]
+fn a_pr_without_commits_is_distinct_from_one_without_ci() {
+    let response = json!({
+        "data": { "search": { "nodes": [
+            {
+                // Head branch reset to the base branch
+                "__typename": "PullRequest",
+                "number": 1,
+                "title": "Empty",
+                "headRefName": "empty",
+                "commits": { "nodes": [] }
+            },
+            {
+                "__typename": "PullRequest",
+                "number": 2,
+                "title": "No checks",
+                "headRefName": "no-checks",
+                "commits": { "nodes": [{ "commit": { "oid": "abc123", "statusCheckRollup": null } }] }
+            },
+            {
+                "__typename": "PullRequest",
+                "number": 3,
+                "title": "Unknown",
+                "headRefName": "unknown",
+                "commits": null
+            }
+        ] } }
+    });
+
+    let search = parse_search_response(response).unwrap();
+    let prs: Vec<_> = search
+        .nodes
+        .iter()
+        .filter_map(|node| match node {
+            SearchNode::PullRequest(pr) => Some((pr.ci_status(), pr.head_sha())),
+            SearchNode::Other => None,
+        })
+        .collect();
+    assert_eq!(
+        prs,
+        [
+            (CiStatus::NoCommits, None),
+            (CiStatus::Unknown, Some("abc123")),
+            (CiStatus::Unknown, None),
+        ]
+    );
+    assert_ne!(
+        CiStatus::NoCommits.display().0,
+        CiStatus::Unknown.display().0
+    );
+}
+
+#[test]
+fn prs_waiting_only_on_me_are_blocking() {
+    let pr = |requested: serde_json::Value, reviewed: serde_json::Value| {
+        json!({
+            "__typename": "PullRequest",
+            "number": 1,
+            "title": "Review me",
+            "headRefName": "review-me",
+            "reviewRequests": { "nodes": requested },
+            "latestReviews": { "nodes": reviewed }
+        })
+    };
+    let response = json!({
+        "data": { "search": { "nodes": [
+            // Everyone else has reviewed
+            pr(json!([{ "requestedReviewer": { "login": "Me" } }]), json!([{ "author": { "login": "alice" } }])),
+            // Bob hasn't reviewed yet either
+            pr(
+                json!([{ "requestedReviewer": { "login": "me" } }, { "requestedReviewer": { "login": "bob" } }]),
+                json!([{ "author": { "login": "alice" } }])
+            ),
+            // A team is still requested, which has no login
+            pr(
+                json!([{ "requestedReviewer": { "login": "me" } }, { "requestedReviewer": {} }]),
+                json!([{ "author": { "login": "alice" } }])
+            ),
+            // Nobody has reviewed yet
+            pr(json!([{ "requestedReviewer": { "login": "me" } }]), json!([])),
+            {
+                // Reviewer details weren't requested
+                "__typename": "PullRequest",
+                "number": 2,
+                "title": "Mine",
+                "headRefName": "mine"
+            }
+        ] } }
+    });
+
+    let search = parse_search_response(response).unwrap();
+    let blocking: Vec<_> = search
+        .nodes
+        .iter()
+        .filter_map(|node| match node {
+            SearchNode::PullRequest(pr) => Some(pr.blocked_only_on("me")),
+            SearchNode::Other => None,
+        })
+        .collect();
+    assert_eq!(blocking, [true, false, false, false, false]);
+}
+
+#[test]
+fn a_null_search_reads_as_no_results() {
+    for response in [
+        json!({ "data": null }),
+        json!({ "data": { "search": null } }),
+    ] {
+        let search = parse_search_response(response).unwrap();
+        assert!(search.nodes.is_empty());
+    }
+}
+
+#[test]
+fn check_contexts_cover_check_runs_and_commit_statuses() {
+    let response = json!({
+        "data": { "repository": { "pullRequest": { "commits": { "nodes": [{ "commit": {
+            "statusCheckRollup": { "contexts": { "nodes": [
+                {
+                    "__typename": "CheckRun",
+                    "name": "build",
+                    "status": "COMPLETED",
+                    "conclusion": "FAILURE",
+                    "detailsUrl": "https://github.com/o/r/actions/runs/1/job/2"
+                },
+                {
+                    "__typename": "CheckRun",
+                    "name": "lint",
+                    "status": "IN_PROGRESS",
+                    "conclusion": null,
+                    "detailsUrl": null
+                },
+                {
+                    "__typename": "StatusContext",
+                    "context": "ci/circleci: test",
+                    "state": "SUCCESS",
+                    "targetUrl": "https://circleci.com/gh/o/r/3"
+                }
+            ] } }
+        } }] } } } }
+    });
+
+    let checks = parse_check_contexts(&response);
+    let summary: Vec<_> = checks
+        .iter()
+        .map(|c| (c.name.as_str(), c.state.as_str(), c.url.is_some()))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("build", "failure", true),
+            ("lint", "in_progress", false),
+            ("ci/circleci: test", "success", true),
+        ]
+    );
+    assert_eq!(checks[0].conclusion, Some(WorkflowConclusion::Failure));
+    assert_eq!(checks[1].status, WorkflowStatus::InProgress);
+    assert_eq!(checks[2].conclusion, Some(WorkflowConclusion::Success));
+
+    // A commit without any checks has no rollup
+    let no_rollup = json!({
+        "data": { "repository": { "pullRequest": { "commits": { "nodes": [{ "commit": {
+            "statusCheckRollup": null
+        } }] } } } }
+    });
+    assert!(parse_check_contexts(&no_rollup).is_empty());
+}
+
+fn actions_response(head: &str, suite_sha: &str) -> serde_json::Value {
+    json!({
+        "data": { "repository": { "pullRequest": {
+            "headRefOid": head,
+            "commits": { "nodes": [{ "commit": {
+                "oid": suite_sha,
+                "checkSuites": { "nodes": [{
+                    "app": { "name": "GitHub Actions" },
+                    "commit": { "oid": suite_sha },
+                    "status": "COMPLETED",
+                    "conclusion": "FAILURE",
+                    "url": "",
+                    "workflowRun": { "databaseId": 1 },
+                    "checkRuns": { "nodes": [{
+                        "databaseId": 2,
+                        "name": "test",
+                        "status": "COMPLETED",
+                        "conclusion": "FAILURE"
+                    }] }
+                }] },
+                "status": null
+            } }] }
+        } } }
+    })
+}
+
+#[test]
+fn runs_for_an_older_commit_are_flagged() {
+    let data = parse_actions_response(&actions_response("1111111aaaa", "0000000bbbb"), 7).unwrap();
+    assert_eq!(
+        data.workflow_runs[0].head_sha.as_deref(),
+        Some("0000000bbbb")
+    );
+    assert_eq!(data.stale_sha(), Some("0000000"));
+}
+
+#[test]
+fn runs_for_the_current_head_are_not_flagged() {
+    let data = parse_actions_response(&actions_response("1111111aaaa", "1111111aaaa"), 7).unwrap();
+    assert_eq!(data.pr_number, 7);
+    assert_eq!(data.stale_sha(), None);
+}
+
+#[test]
+fn sso_errors_explain_how_to_authorize_the_token() {
+    let response = json!({
+        "data": { "search": null },
+        "errors": [{
+            "type": "FORBIDDEN",
+            "path": ["search"],
+            "message": "Resource protected by organization SAML enforcement. You must grant your OAuth token access to this organization."
+        }]
+    });
+    let err = check_graphql_errors(&response).unwrap_err();
+    assert!(is_sso_required(&err));
+
+    let explained = explain_sso_error(err, "acme").to_string();
+    assert!(explained.contains("SSO authorization for acme"));
+    assert!(explained.contains("gh auth refresh"));
+
+    // Anything else is left alone
+    let other = explain_sso_error(anyhow::anyhow!("Bad credentials"), "acme");
+    assert_eq!(other.to_string(), "Bad credentials");
+}
+
+#[test]
+fn only_connection_failures_count_as_offline() {
+    use std::io::{Error, ErrorKind};
+
+    let io = |kind| anyhow::Error::new(Error::new(kind, "io")).context("request failed");
+    assert!(is_network_error(&io(ErrorKind::ConnectionRefused)));
+    assert!(is_network_error(&io(ErrorKind::TimedOut)));
+    let dns = anyhow::Error::new(Error::other("failed to lookup address information"))
+        .context("dns error");
+    assert!(is_network_error(&dns));
+
+    // Reaching GitHub and failing some other way isn't being offline
+    assert!(!is_network_error(&io(ErrorKind::PermissionDenied)));
+    assert!(!is_network_error(&io(ErrorKind::InvalidData)));
+    assert!(!is_network_error(&anyhow::anyhow!("Bad credentials")));
+}
+
+#[cfg(unix)]
+#[test]
+fn a_rejected_command_token_is_renewed_and_the_request_retried() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let _guard = setup();
+    let dir = get_config_dir().unwrap();
+    std::fs::create_dir_all(&dir).unwrap();
+    // Prints how many times it has run, so each token differs
+    let script = dir.join("token.sh");
+    std::fs::write(
+        &script,
+        "#!/bin/sh\necho run >> \"$0.runs\"\nwc -l < \"$0.runs\" | tr -d ' '\n",
+    )
+    .unwrap();
+    std::fs::set_permissions(&script, std::fs::Permissions::from_mode(0o755)).unwrap();
+    let config = dir.join("config.toml");
+    std::fs::write(&config, format!("token_command = \"{}\"", script.display())).unwrap();
+
+    let tokens = Mutex::new(Vec::new());
+    let result = tokio::runtime::Runtime::new()
+        .unwrap()
+        .block_on(with_github_token(|token| {
+            let mut tokens = tokens.lock().unwrap();
+            tokens.push(token.clone());
+            let first = tokens.len() == 1;
+            async move {
+                if first {
+                    Err(GhuiError::Auth("401 Unauthorized".to_string()).into())
+                } else {
+                    Ok(token)
+                }
+            }
+        }));
+    std::fs::remove_file(&config).unwrap();
+
+    assert_eq!(result.unwrap(), "2");
+    assert_eq!(*tokens.lock().unwrap(), ["1", "2"]);
+}
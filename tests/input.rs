@@ -0,0 +1,89 @@
+use ghui::app::{TextEdit, TextInput};
+
+fn typed(text: &str) -> TextInput {
+    let mut input = TextInput::new();
+    for c in text.chars() {
+        input.apply(TextEdit::Insert(c));
+    }
+    input
+}
+
+#[test]
+fn edits_at_the_cursor() {
+    let mut input = typed("helo");
+    input.apply(TextEdit::Left);
+    input.apply(TextEdit::Insert('l'));
+    assert_eq!(input.value(), "hello");
+    assert_eq!(input.cursor(), 4);
+
+    input.apply(TextEdit::Home);
+    input.apply(TextEdit::Delete);
+    input.apply(TextEdit::Insert('j'));
+    assert_eq!(input.value(), "jello");
+
+    input.apply(TextEdit::End);
+    input.apply(TextEdit::Backspace);
+    assert_eq!(input.value(), "jell");
+    assert_eq!(input.cursor(), 4);
+}
+
+#[test]
+fn cursor_counts_chars_not_bytes() {
+    let mut input = typed("ünï");
+    input.apply(TextEdit::Left);
+    input.apply(TextEdit::Backspace);
+    assert_eq!(input.value(), "üï");
+    input.apply(TextEdit::Right);
+    input.apply(TextEdit::Right);
+    assert_eq!(input.cursor(), 2);
+}
+
+#[test]
+fn delete_word_removes_the_word_before_the_cursor() {
+    let mut input = typed("needs review  ");
+    input.apply(TextEdit::DeleteWord);
+    assert_eq!(input.value(), "needs ");
+
+    let mut input = typed("good first issue");
+    input.apply(TextEdit::Left);
+    input.apply(TextEdit::Left);
+    input.apply(TextEdit::DeleteWord);
+    assert_eq!(input.value(), "good first ue");
+    assert_eq!(input.cursor(), 11);
+}
+
+#[test]
+fn history_recalls_submitted_values() {
+    let mut input = TextInput::new();
+    input.push_history("bug");
+    input.push_history("urgent");
+    input.apply(TextEdit::Insert('x'));
+
+    input.apply(TextEdit::HistoryPrevious);
+    assert_eq!(input.value(), "urgent");
+    input.apply(TextEdit::HistoryPrevious);
+    input.apply(TextEdit::HistoryPrevious);
+    assert_eq!(input.value(), "bug");
+    input.apply(TextEdit::HistoryNext);
+    assert_eq!(input.value(), "urgent");
+    input.apply(TextEdit::HistoryNext);
+    assert!(input.is_empty());
+}
+
+#[test]
+fn clear_line_empties_the_buffer() {
+    let mut input = typed("label:bug");
+    input.apply(TextEdit::Home);
+    input.apply(TextEdit::ClearLine);
+    assert!(input.is_empty());
+    assert_eq!(input.cursor(), 0);
+}
+
+#[test]
+fn insert_str_pastes_at_the_cursor() {
+    let mut input = typed("label:");
+    input.apply(TextEdit::Home);
+    input.insert_str("ünï ");
+    assert_eq!(input.value(), "ünï label:");
+    assert_eq!(input.cursor(), 4);
+}
@@ -0,0 +1,91 @@
+mod common;
+
+use crossterm::event::KeyCode;
+
+use common::setup;
+use ghui::app::keymap::get_keymap_path;
+use ghui::app::{Action, KeyMap};
+use ghui::{App, PrFilter};
+
+#[test]
+fn defaults_match_the_built_in_keys() {
+    let keymap = KeyMap::default();
+    assert_eq!(keymap.action(KeyCode::Char('j')), Some(Action::NextItem));
+    assert_eq!(keymap.action(KeyCode::Down), Some(Action::NextItem));
+    assert_eq!(keymap.action(KeyCode::Char(' ')), Some(Action::ToggleMark));
+    assert_eq!(keymap.action(KeyCode::Enter), Some(Action::Preview));
+    assert_eq!(keymap.action(KeyCode::Char('6')), Some(Action::TabRecent));
+    assert_eq!(
+        keymap.action(KeyCode::Char('m')),
+        Some(Action::ToggleReadyToMerge)
+    );
+    assert_eq!(keymap.action(KeyCode::Char('M')), Some(Action::Merge));
+    assert_eq!(keymap.action(KeyCode::Char('z')), None);
+}
+
+#[test]
+fn listed_actions_replace_their_default_keys() {
+    let keymap = KeyMap::parse(
+        r#"
+        next_item = ["n", "Down"]
+        previous_item = ["e", "Up"]
+        refresh = "F5"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(keymap.action(KeyCode::Char('n')), Some(Action::NextItem));
+    assert_eq!(keymap.action(KeyCode::Down), Some(Action::NextItem));
+    assert_eq!(keymap.action(KeyCode::Char('j')), None);
+    assert_eq!(keymap.action(KeyCode::F(5)), Some(Action::Refresh));
+    assert_eq!(keymap.action(KeyCode::Char('r')), None);
+    // Unlisted actions keep their defaults
+    assert_eq!(keymap.action(KeyCode::Char('c')), Some(Action::Checkout));
+}
+
+#[test]
+fn bad_bindings_are_errors() {
+    let error = |content: &str| KeyMap::parse(content).unwrap_err().to_string();
+    assert!(error("jump = \"J\"").contains("Unknown action \"jump\""));
+    assert!(error("refresh = \"Ctrl-R\"").contains("Unknown key \"Ctrl-R\""));
+    assert!(error("refresh = 5").contains("must be a string"));
+    // "c" still checks out
+    assert!(error("refresh = \"c\"").contains("bound to both"));
+    assert!(error("refresh = [\"r\", \"r\"]").contains("bound to both"));
+}
+
+#[test]
+fn help_rows_list_the_keys_in_effect() {
+    let keymap = KeyMap::parse(
+        r#"
+        next_item = ["n", "Down"]
+        labels = []
+        "#,
+    )
+    .unwrap();
+    let rows = keymap.help_rows();
+    assert!(rows.contains(&("n/Down".to_string(), "Move down")));
+    assert!(rows.contains(&("k/Up".to_string(), "Move up")));
+    // Unbound actions aren't listed
+    assert!(!rows
+        .iter()
+        .any(|(_, description)| *description == "Manage labels"));
+}
+
+#[test]
+fn a_broken_keymap_stays_on_screen_after_the_startup_fetch() {
+    let _guard = setup();
+    let path = get_keymap_path().unwrap();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    std::fs::write(&path, "jump = \"J\"").unwrap();
+    let app = App::new();
+    std::fs::remove_file(&path).unwrap();
+    let mut app = app.unwrap();
+
+    app.start_fetch(PrFilter::MyPrs);
+    assert!(app.show_error_popup);
+    assert!(app
+        .error
+        .as_deref()
+        .unwrap()
+        .contains("Unknown action \"jump\""));
+}
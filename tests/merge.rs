@@ -0,0 +1,74 @@
+mod common;
+
+use common::{setup_app, with_prs};
+use ghui::data::{Mergeable, ReviewDecision};
+use ghui::{update, Command, Message};
+
+fn pending(number: u64) -> (String, String, u64) {
+    ("owner".to_string(), "repo".to_string(), number)
+}
+
+#[test]
+fn only_prs_ready_to_merge_can_be_merged() {
+    let (_guard, mut app) = setup_app();
+    app.config.merge.enabled = true;
+    with_prs(&mut app, 0, false);
+    app.repo_owner = Some("owner".to_string());
+    app.repo_name = Some("repo".to_string());
+
+    // Approved and green, but with conflicts
+    app.my_prs[0].review_decision = ReviewDecision::Approved;
+    app.my_prs[0].mergeable = Mergeable::Conflicting;
+    assert!(update(&mut app, Message::MergePr).is_none());
+    assert!(!app.show_merge_popup);
+    assert!(app.pending_merge_pr.is_none());
+
+    app.my_prs[0].mergeable = Mergeable::Mergeable;
+    update(&mut app, Message::MergePr);
+    assert!(app.show_merge_popup);
+    assert_eq!(app.pending_merge_pr, Some(pending(1)));
+}
+
+#[test]
+fn prs_without_required_reviews_can_be_merged() {
+    let (_guard, mut app) = setup_app();
+    app.config.merge.enabled = true;
+    with_prs(&mut app, 0, false);
+    app.repo_owner = Some("owner".to_string());
+    app.repo_name = Some("repo".to_string());
+
+    // GitHub returns a null reviewDecision when the branch requires no reviews
+    app.my_prs[0].review_decision = ReviewDecision::None;
+    app.my_prs[0].mergeable = Mergeable::Mergeable;
+    update(&mut app, Message::MergePr);
+    assert!(app.show_merge_popup);
+    assert_eq!(app.pending_merge_pr, Some(pending(1)));
+
+    // A review that's still required keeps it blocked
+    app.show_merge_popup = false;
+    app.pending_merge_pr = None;
+    app.my_prs[0].review_decision = ReviewDecision::ReviewRequired;
+    assert!(update(&mut app, Message::MergePr).is_none());
+    assert!(!app.show_merge_popup);
+}
+
+#[test]
+fn merges_go_to_the_prs_own_repository() {
+    let (_guard, mut app) = setup_app();
+    app.config.merge.enabled = true;
+    app.config.confirm_destructive.merge = false;
+    with_prs(&mut app, 0, false);
+    app.repo_owner = Some("owner".to_string());
+    app.repo_name = Some("repo".to_string());
+    // As listed on a multi-repo tab
+    app.my_prs[0].repo_owner = "other".to_string();
+    app.my_prs[0].repo_name = "fork".to_string();
+    app.my_prs[0].review_decision = ReviewDecision::Approved;
+    app.my_prs[0].mergeable = Mergeable::Mergeable;
+
+    let command = update(&mut app, Message::MergePr);
+    assert!(matches!(
+        command,
+        Some(Command::StartMerge(owner, repo, 1, _)) if owner == "other" && repo == "fork"
+    ));
+}
@@ -0,0 +1,79 @@
+mod common;
+
+use common::{pr, setup_app, with_prs};
+use ghui::{update, Message};
+
+#[test]
+fn navigation_clamps_at_the_ends_by_default() {
+    let (_guard, mut app) = setup_app();
+    assert!(!app.config.wrap_navigation);
+
+    with_prs(&mut app, 2, false);
+    update(&mut app, Message::NextItem);
+    assert_eq!(app.table_state.selected(), Some(2));
+
+    with_prs(&mut app, 0, false);
+    update(&mut app, Message::PreviousItem);
+    assert_eq!(app.table_state.selected(), Some(0));
+}
+
+#[test]
+fn navigation_wraps_when_enabled() {
+    let (_guard, mut app) = setup_app();
+
+    with_prs(&mut app, 2, true);
+    update(&mut app, Message::NextItem);
+    assert_eq!(app.table_state.selected(), Some(0));
+
+    update(&mut app, Message::PreviousItem);
+    assert_eq!(app.table_state.selected(), Some(2));
+
+    // Away from the ends, wrapping changes nothing
+    update(&mut app, Message::PreviousItem);
+    assert_eq!(app.table_state.selected(), Some(1));
+}
+
+#[test]
+fn navigation_ignores_an_empty_list() {
+    let (_guard, mut app) = setup_app();
+    app.my_prs.clear();
+    app.filtered_indices.clear();
+    app.table_state.select(None);
+    app.config.wrap_navigation = true;
+
+    update(&mut app, Message::NextItem);
+    update(&mut app, Message::PreviousItem);
+    assert_eq!(app.table_state.selected(), None);
+}
+
+#[test]
+fn type_ahead_narrows_on_a_repeated_letter_before_cycling() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    app.my_prs.push(pr(4));
+    app.filtered_indices.push(3);
+    for (pr, title) in app
+        .my_prs
+        .iter_mut()
+        .zip(["Bump deps", "Edit docs", "Eels", "Enable cache"])
+    {
+        pr.title = title.to_string();
+    }
+
+    update(&mut app, Message::StartTypeAhead);
+    update(&mut app, Message::TypeAheadInput('e'));
+    assert_eq!(app.table_state.selected(), Some(1));
+    update(&mut app, Message::TypeAheadInput('e'));
+    assert_eq!(app.table_state.selected(), Some(2));
+    assert_eq!(app.type_ahead.as_deref(), Some("ee"));
+
+    // With no title starting "ee", the repeat moves on to the next "e"
+    app.my_prs.remove(2);
+    app.filtered_indices.pop();
+    app.table_state.select(Some(1));
+    update(&mut app, Message::StartTypeAhead);
+    update(&mut app, Message::TypeAheadInput('e'));
+    update(&mut app, Message::TypeAheadInput('e'));
+    assert_eq!(app.table_state.selected(), Some(2));
+    assert_eq!(app.type_ahead.as_deref(), Some("e"));
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use ghui::data::LabelFilter;
+use ghui::services::{filter_labels, filter_prs, MatchPositions, SearchField};
+use ghui::PullRequest;
+
+fn pr(number: u64, title: &str, branch: &str) -> PullRequest {
+    PullRequest {
+        title: title.to_string(),
+        branch: branch.to_string(),
+        ..common::pr(number)
+    }
+}
+
+#[test]
+fn an_empty_query_keeps_every_pr_without_highlights() {
+    let prs = vec![pr(1, "Fix login", "fix-login"), pr(2, "Add docs", "docs")];
+    assert_eq!(
+        filter_prs(&prs, "", &[]),
+        [
+            (0, MatchPositions::default()),
+            (1, MatchPositions::default())
+        ]
+    );
+}
+
+#[test]
+fn match_positions_are_chars_within_each_field() {
+    let prs = vec![
+        pr(1, "Add docs", "docs"),
+        pr(2, "Ünïcode login fix", "fix-login"),
+    ];
+    let matches = filter_prs(&prs, "login", &[SearchField::Title]);
+    assert_eq!(matches.len(), 1);
+    let (idx, positions) = &matches[0];
+    assert_eq!(*idx, 1);
+    // Counted in chars, so the accented letters before it don't shift the match
+    assert_eq!(positions.title, [8, 9, 10, 11, 12]);
+    // The branch wasn't searched
+    assert!(positions.branch.is_empty());
+
+    let matches = filter_prs(&prs, "fixlogin", &[SearchField::Branch]);
+    assert_eq!(matches[0].1.branch, [0, 1, 2, 4, 5, 6, 7, 8]);
+    assert!(matches[0].1.title.is_empty());
+}
+
+#[test]
+fn matches_in_other_fields_have_no_positions() {
+    let prs = vec![pr(42, "Add docs", "docs")];
+    let matches = filter_prs(&prs, "#42", &[]);
+    assert_eq!(matches, [(0, MatchPositions::default())]);
+}
+
+#[test]
+fn labels_sharing_a_name_each_match() {
+    let label = |id: i64, name: &str, repo: Option<&str>| LabelFilter {
+        id,
+        label_name: name.to_string(),
+        repo_owner: repo.map(|_| "owner".to_string()),
+        repo_name: repo.map(str::to_string),
+    };
+    // A repo label and a global label with the same name
+    let labels = vec![
+        label(1, "bug", Some("repo")),
+        label(2, "docs", Some("repo")),
+        label(3, "bug", None),
+    ];
+    assert_eq!(filter_labels(&labels, "bug"), [0, 2]);
+}
@@ -0,0 +1,64 @@
+mod common;
+
+use common::{pr, setup_app, with_prs};
+use ghui::app::TextEdit;
+use ghui::data::PrSort;
+use ghui::{update, Message, PrFilter};
+
+#[test]
+fn opened_age_sort_puts_the_oldest_pr_first() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    app.my_prs[0].created_at = Some(300);
+    app.my_prs[1].created_at = None;
+    app.my_prs[2].created_at = Some(100);
+
+    app.pr_sort = PrSort::Default;
+    update(&mut app, Message::CyclePrSort);
+    assert_eq!(app.filtered_indices, vec![2, 0, 1]);
+    assert_eq!(app.table_state.selected(), Some(0));
+}
+
+#[test]
+fn each_tab_starts_in_its_configured_sort() {
+    let (_guard, mut app) = setup_app();
+    assert_eq!(app.pr_sort, PrSort::Newest);
+    app.my_prs = vec![pr(1), pr(2), pr(3)];
+    app.review_prs = vec![pr(4), pr(5), pr(6)];
+    for (i, updated_at) in [200, 300, 100].into_iter().enumerate() {
+        app.my_prs[i].updated_at = Some(updated_at);
+        app.review_prs[i].updated_at = Some(updated_at);
+    }
+
+    // Oldest requests first for reviewing
+    update(&mut app, Message::SwitchTab(PrFilter::ReviewRequested));
+    assert_eq!(app.pr_sort, PrSort::Updated);
+    assert_eq!(app.filtered_indices, vec![2, 0, 1]);
+
+    // A sort picked with S doesn't follow you to another tab
+    update(&mut app, Message::CyclePrSort);
+    update(&mut app, Message::SwitchTab(PrFilter::MyPrs));
+    assert_eq!(app.pr_sort, PrSort::Newest);
+    assert_eq!(app.filtered_indices, vec![1, 0, 2]);
+}
+
+#[test]
+fn searching_ranks_by_match_instead_of_the_tab_sort() {
+    let (_guard, mut app) = setup_app();
+    assert_eq!(app.pr_sort, PrSort::Newest);
+    app.my_prs = vec![pr(1), pr(2)];
+    app.my_prs[0].title = "Log in with a passkey".to_string();
+    app.my_prs[0].updated_at = Some(200);
+    app.my_prs[1].title = "login".to_string();
+    app.my_prs[1].updated_at = Some(100);
+
+    update(&mut app, Message::EnterSearchMode);
+    for c in "login".chars() {
+        update(&mut app, Message::SearchEdit(TextEdit::Insert(c)));
+    }
+    // The closer match ranks first even though it was updated longer ago
+    assert_eq!(app.filtered_indices, vec![1, 0]);
+
+    update(&mut app, Message::ExitSearchMode { clear: true });
+    assert_eq!(app.filtered_indices, vec![0, 1]);
+}
@@ -0,0 +1,17 @@
+mod common;
+
+use common::setup;
+use ghui::get_cache_path;
+use ghui::services::{load_state, update_state};
+
+#[test]
+fn state_outlives_the_cache() {
+    let _guard = setup();
+    assert!(!load_state().glyph_warning_acknowledged);
+
+    update_state(|state| state.glyph_warning_acknowledged = true).unwrap();
+    // What --clear-cache does
+    let _ = std::fs::remove_file(get_cache_path().unwrap());
+
+    assert!(load_state().glyph_warning_acknowledged);
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use ghui::utils::{format_age, jitter, parse_timestamp};
+
+#[test]
+fn parses_github_timestamps() {
+    assert_eq!(parse_timestamp("1970-01-01T00:00:00Z"), Some(0));
+    assert_eq!(parse_timestamp("2024-02-29T12:34:56Z"), Some(1_709_210_096));
+    // Fractional seconds are dropped
+    assert_eq!(
+        parse_timestamp("2024-02-29T12:34:56.789Z"),
+        Some(1_709_210_096)
+    );
+}
+
+#[test]
+fn rejects_malformed_timestamps() {
+    assert_eq!(parse_timestamp(""), None);
+    assert_eq!(parse_timestamp("2024-02-29"), None);
+    assert_eq!(parse_timestamp("2024-13-01T00:00:00Z"), None);
+    assert_eq!(parse_timestamp("2024-02-29T12:34:56+02:00"), None);
+}
+
+#[test]
+fn formats_ages_in_the_largest_whole_unit() {
+    assert_eq!(format_age(-5), "0s");
+    assert_eq!(format_age(59), "59s");
+    assert_eq!(format_age(60 * 45), "45m");
+    assert_eq!(format_age(3_600 * 5), "5h");
+    assert_eq!(format_age(86_400 * 13), "13d");
+    assert_eq!(format_age(86_400 * 14), "2w");
+    assert_eq!(format_age(86_400 * 800), "2y");
+}
+
+#[test]
+fn jitter_stays_within_its_bound() {
+    assert_eq!(jitter(Duration::ZERO), Duration::ZERO);
+    for _ in 0..100 {
+        assert!(jitter(Duration::from_secs(5)) <= Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,101 @@
+mod common;
+
+use common::setup_app;
+use ratatui::{backend::TestBackend, Terminal};
+
+use ghui::{ui, update, App, Message};
+
+fn render(app: &App, width: u16, height: u16) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| ui(f, app)).unwrap();
+    let buffer = terminal.backend().buffer();
+    buffer
+        .content()
+        .chunks(width as usize)
+        .map(|row| row.iter().map(|cell| cell.symbol()).collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn small_terminals_get_a_message_instead_of_the_ui() {
+    let (_guard, app) = setup_app();
+    let screen = render(&app, 50, 10);
+    assert!(screen.contains("Terminal too small (need at least 70x18)"));
+
+    let screen = render(&app, 80, 24);
+    assert!(!screen.contains("Terminal too small"));
+}
+
+#[test]
+fn popups_fit_tiny_terminals_without_a_minimum() {
+    let (_guard, mut app) = setup_app();
+    app.config.min_size.width = 0;
+    app.config.min_size.height = 0;
+    app.error = Some("Something went wrong".to_string());
+    app.show_error_popup = true;
+    for (width, height) in [(1, 1), (3, 2), (20, 5)] {
+        render(&app, width, height);
+    }
+    app.show_error_popup = false;
+    app.show_help_popup = true;
+    render(&app, 10, 4);
+}
+
+#[test]
+fn help_scrolls_when_it_does_not_fit() {
+    let (_guard, mut app) = setup_app();
+    update(&mut app, Message::ToggleHelp);
+    let (width, height) = (app.config.min_size.width, app.config.min_size.height);
+    app.terminal_height = height;
+
+    let screen = render(&app, width, height);
+    assert!(screen.contains("j/k scroll"));
+    assert!(!screen.contains("Quit"));
+
+    for _ in 0..100 {
+        update(&mut app, Message::HelpScrollDown);
+    }
+    let screen = render(&app, width, height);
+    assert!(screen.contains("Quit"));
+    // Scrolling back up starts moving at once, however far down it went
+    update(&mut app, Message::HelpScrollUp);
+    assert!(!render(&app, width, height).contains("Quit"));
+
+    app.terminal_height = 50;
+    let screen = render(&app, 80, 50);
+    assert!(screen.contains("Press any key to close"));
+    assert!(screen.contains("Fuzzy search") && screen.contains("Quit"));
+}
+
+#[test]
+fn ascii_mode_draws_no_unicode_punctuation() {
+    let (_guard, mut app) = setup_app();
+    app.offline = true;
+    app.config.offline_start = true;
+    // ASCII mode is process-wide; the lock keeps other tests from seeing it
+    ghui::icons::set_ascii(true);
+    let screen = render(&app, 120, 30);
+    ghui::icons::set_ascii(false);
+    assert!(screen.contains("offline - showing cached data"));
+    assert!(screen.contains("cache only - r to fetch"));
+    assert!(!screen.contains(['—', '│', '·']));
+}
+
+#[test]
+fn the_scrollbar_counts_the_expanded_row_as_several_lines() {
+    let (_guard, mut app) = setup_app();
+    // Ten one-line rows fit, but the selected row wraps its long title
+    app.my_prs = (1..=10).map(common::pr).collect();
+    app.my_prs[0].title = "A very long title ".repeat(10);
+    app.filtered_indices = (0..10).collect();
+    app.table_state.select(Some(0));
+    ghui::icons::set_ascii(true);
+    let screen = render(&app, 80, 19);
+    ghui::icons::set_ascii(false);
+    let ends: String = screen
+        .lines()
+        .filter_map(|line| line.chars().last())
+        .collect();
+    assert!(ends.contains('^') && ends.contains('v'));
+}
@@ -0,0 +1,221 @@
+mod common;
+
+use common::{setup_app, with_prs};
+use ghui::data::{
+    ActionsData, CiProvider, JobLogs, WorkflowConclusion, WorkflowJob, WorkflowRun, WorkflowStatus,
+};
+use ghui::{update, Command, FetchResult, Message};
+
+fn workflow_run(id: Option<u64>) -> WorkflowRun {
+    WorkflowRun {
+        id,
+        name: "CI".to_string(),
+        provider: CiProvider::GitHubActions,
+        status: WorkflowStatus::Completed,
+        conclusion: Some(WorkflowConclusion::Failure),
+        html_url: String::new(),
+        jobs: vec![WorkflowJob {
+            id: id.unwrap_or(0),
+            name: "test".to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Failure),
+            started_at: None,
+            completed_at: None,
+            details_url: None,
+            summary: None,
+            text: None,
+            annotations: Vec::new(),
+            annotations_count: 0,
+        }],
+        created_at: String::new(),
+        updated_at: String::new(),
+        head_sha: None,
+    }
+}
+
+#[test]
+fn rerunning_a_run_only_touches_that_run() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    app.actions_data = Some(ActionsData {
+        pr_number: 1,
+        head_sha: None,
+        // Same workflow name, e.g. triggered by both push and pull_request
+        workflow_runs: vec![workflow_run(Some(10)), workflow_run(Some(20))],
+        error: None,
+    });
+    app.selected_job_index = 1;
+
+    update(&mut app, Message::PromptRerun);
+    update(&mut app, Message::ConfirmRerun { failed_only: false });
+    let runs = &app.actions_data.as_ref().unwrap().workflow_runs;
+    assert_eq!(runs[0].status, WorkflowStatus::Completed);
+    assert_eq!(runs[1].status, WorkflowStatus::Queued);
+}
+
+#[test]
+fn rerunning_without_the_prompt_only_reruns_failed_jobs() {
+    let (_guard, mut app) = setup_app();
+    app.config.confirm_destructive.rerun = false;
+    with_prs(&mut app, 0, false);
+    let mut run = workflow_run(Some(10));
+    let mut passed = run.jobs[0].clone();
+    passed.conclusion = Some(WorkflowConclusion::Success);
+    run.jobs.push(passed);
+    app.actions_data = Some(ActionsData {
+        pr_number: 1,
+        head_sha: None,
+        workflow_runs: vec![run],
+        error: None,
+    });
+    app.selected_job_index = 0;
+
+    update(&mut app, Message::PromptRerun);
+    assert!(app.pending_rerun.is_none());
+    let jobs = &app.actions_data.as_ref().unwrap().workflow_runs[0].jobs;
+    assert_eq!(jobs[0].status, WorkflowStatus::Queued);
+    assert_eq!(jobs[1].conclusion, Some(WorkflowConclusion::Success));
+}
+
+#[test]
+fn runs_without_an_id_cant_be_rerun() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    app.actions_data = Some(ActionsData {
+        pr_number: 1,
+        head_sha: None,
+        workflow_runs: vec![workflow_run(None)],
+        error: None,
+    });
+    app.selected_job_index = 0;
+
+    update(&mut app, Message::PromptRerun);
+    assert!(app.pending_rerun.is_none());
+    assert!(app.clipboard_feedback.is_some());
+}
+
+#[test]
+fn dismissing_a_confirmation_drops_its_pending_action() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    let mut run = workflow_run(Some(10));
+    run.status = WorkflowStatus::InProgress;
+    run.conclusion = None;
+    app.actions_data = Some(ActionsData {
+        pr_number: 1,
+        head_sha: None,
+        workflow_runs: vec![run],
+        error: None,
+    });
+    app.selected_job_index = 0;
+
+    update(&mut app, Message::PromptCancelRun);
+    assert!(app.confirmation.is_some() && app.pending_cancel.is_some());
+
+    update(&mut app, Message::DismissConfirmation);
+    assert!(app.confirmation.is_none() && app.pending_cancel.is_none());
+    assert!(update(&mut app, Message::ConfirmCancelRun).is_none());
+    let runs = &app.actions_data.as_ref().unwrap().workflow_runs;
+    assert_eq!(runs[0].status, WorkflowStatus::InProgress);
+}
+
+#[test]
+fn annotation_errors_only_land_on_the_job_they_were_for() {
+    let (_guard, mut app) = setup_app();
+    with_prs(&mut app, 0, false);
+    app.actions_data = Some(ActionsData {
+        pr_number: 1,
+        head_sha: None,
+        workflow_runs: vec![workflow_run(Some(10)), workflow_run(Some(20))],
+        error: None,
+    });
+    app.selected_job_index = 1;
+    app.show_job_logs = true;
+    app.job_logs_loading = true;
+
+    // The user moved on from job 10 before its request failed
+    update(
+        &mut app,
+        Message::AnnotationsReceived(FetchResult::AnnotationsError(10, "boom".to_string())),
+    );
+    assert!(app.job_logs.is_none());
+    assert!(app.job_logs_loading);
+
+    update(
+        &mut app,
+        Message::AnnotationsReceived(FetchResult::AnnotationsError(20, "boom".to_string())),
+    );
+    let logs = app.job_logs.as_ref().unwrap();
+    assert_eq!(logs.job_id, 20);
+    assert!(logs.content.contains("Failed to load annotations: boom"));
+    assert!(!app.job_logs_loading);
+}
+
+#[test]
+fn job_logs_scroll_stops_at_the_last_page() {
+    let (_guard, mut app) = setup_app();
+    app.job_logs = Some(JobLogs {
+        job_id: 1,
+        job_name: "test".to_string(),
+        content: (1..=100).map(|n| format!("line {}\n", n)).collect(),
+        steps: None,
+        test_results: None,
+    });
+    app.job_logs_line_count = 100;
+    app.show_job_logs = true;
+    // Borders and the footer leave 20 rows for the log
+    app.terminal_height = 24;
+
+    for _ in 0..50 {
+        update(&mut app, Message::JobLogsScrollDown);
+    }
+    assert_eq!(app.job_logs_scroll, 80);
+}
+
+#[test]
+fn saved_job_logs_never_overwrite_an_existing_file() {
+    let (_guard, mut app) = setup_app();
+    app.job_logs = Some(JobLogs {
+        job_id: 7,
+        job_name: "build / linux".to_string(),
+        content: "log output\n".to_string(),
+        steps: None,
+        test_results: None,
+    });
+
+    let mut save = || match update(&mut app, Message::SaveJobLogs) {
+        Some(Command::OpenInPager(path)) => std::path::PathBuf::from(path),
+        _ => panic!("expected the saved logs to open in the pager"),
+    };
+    let first = save();
+    let second = save();
+
+    let logs_dir = ghui::get_cache_path().unwrap().with_file_name("logs");
+    assert_eq!(first, logs_dir.join("build___linux_7.log"));
+    assert_eq!(second, logs_dir.join("build___linux_7_1.log"));
+    assert_eq!(std::fs::read_to_string(&second).unwrap(), "log output\n");
+}
+
+#[test]
+fn a_late_checks_error_for_another_pr_is_dropped() {
+    let (_guard, mut app) = setup_app();
+    app.error = None;
+    app.show_error_popup = false;
+    app.show_checks_popup = true;
+    app.checks_pr = Some(2);
+    app.checks_loading = true;
+
+    update(
+        &mut app,
+        Message::ChecksReceived(FetchResult::ChecksError(1, "boom".to_string())),
+    );
+    assert!(app.show_checks_popup && app.checks_loading);
+    assert!(!app.show_error_popup);
+
+    update(
+        &mut app,
+        Message::ChecksReceived(FetchResult::ChecksError(2, "boom".to_string())),
+    );
+    assert!(!app.show_checks_popup && !app.checks_loading);
+    assert!(app.show_error_popup);
+}